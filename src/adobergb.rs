@@ -0,0 +1,231 @@
+use crate::common::{
+    a98_to_linear, linear_rgb_to_xyz, linear_to_a98, oklab_to_linear_rgb, parse_fn_args,
+    parse_number, A98_TO_XYZ, XYZ_TO_A98,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Lab, Oklab, CMYK, HSL, HSLA, HSV, HSVA, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// AdobeRGB is the Adobe RGB (1998) space used by photographers, it has a wider green gamut than sRGB and a plain 2.2 gamma.
+/// Colors outside of the sRGB gamut are clipped when converted into `RGB` and the types built on it(HSL, HSV, CMYK),
+/// `Lab` and `Oklab` go through XYZ and keep them.
+/// AdobeRGB can be parsed from a string in the format "color(a98-rgb r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red(0~1), can also be written as a percentage in strings
+/// * g:f32 - green(0~1), can also be written as a percentage in strings
+/// * b:f32 - blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{AdobeRGB, Lab, Oklab, HSL, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let adobe:AdobeRGB = rgb.into();
+/// assert_eq!(adobe.to_string(), "color(a98-rgb 0.4521 0.7632 0.5480)");
+///
+/// let adobe:AdobeRGB = "color(a98-rgb 0.4521 0.7632 0.5480)".try_into().unwrap();
+/// let rgb:RGB = adobe.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// let hsl:HSL = adobe.into();
+/// assert_eq!(hsl.to_string(), "hsl(157,64%,47%)");
+/// let green:AdobeRGB = (0.0, 1.0, 0.0).try_into().unwrap();
+/// let lab:Lab = green.into();
+/// assert_eq!(lab.to_string(), "lab(83.30 -137.97 90.83)");
+/// let oklab:Oklab = green.into();
+/// assert_eq!(AdobeRGB::from(oklab).to_string(), "color(a98-rgb 0.0000 1.0000 0.0000)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct AdobeRGB {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for AdobeRGB {
+    type Error = ColorError;
+    fn try_from(adobe_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(adobe_str, "color") {
+            if args.len() == 4 && args[0] == "a98-rgb" {
+                let r = parse_number(&args[1], 1.0);
+                let g = parse_number(&args[2], 1.0);
+                let b = parse_number(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "AdobeRGB: {} format error!",
+            adobe_str
+        )))
+    }
+}
+
+impl FromStr for AdobeRGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for AdobeRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "AdobeRGB: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for AdobeRGB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for AdobeRGB {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for AdobeRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for AdobeRGB {
+    fn from(xyz: XYZ) -> Self {
+        let (r, g, b) = xyz.to_linear_rgb(&XYZ_TO_A98);
+        Self {
+            r: linear_to_a98(r).clamp(0.0, 1.0),
+            g: linear_to_a98(g).clamp(0.0, 1.0),
+            b: linear_to_a98(b).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<HSL> for AdobeRGB {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for AdobeRGB {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for AdobeRGB {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<HSVA> for AdobeRGB {
+    fn from(hsva: HSVA) -> Self {
+        let rgb: RGB = hsva.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for AdobeRGB {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for AdobeRGB {
+    fn from(lab: Lab) -> Self {
+        let xyz: XYZ = lab.into();
+        xyz.into()
+    }
+}
+
+impl From<Oklab> for AdobeRGB {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { l, a, b } = oklab;
+        let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+        let (x, y, z) = linear_rgb_to_xyz(r, g, b);
+        XYZ { x, y, z }.into()
+    }
+}
+
+impl Display for AdobeRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color(a98-rgb {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl AdobeRGB {
+    /// The matrix converting linear Adobe RGB into XYZ.
+    pub const TO_XYZ: [[f32; 3]; 3] = A98_TO_XYZ;
+
+    /// The matrix converting XYZ into linear Adobe RGB.
+    pub const FROM_XYZ: [[f32; 3]; 3] = XYZ_TO_A98;
+
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The encoded channels(0~1) as a tuple, `TryFrom<(f32, f32, f32)>` goes the other way.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the channels without the transfer function applied.
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        (
+            a98_to_linear(self.r),
+            a98_to_linear(self.g),
+            a98_to_linear(self.b),
+        )
+    }
+}
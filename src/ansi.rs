@@ -0,0 +1,86 @@
+use crate::common::rgb_to_oklab;
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+
+/// The escape sequence that resets the colors and styles of a terminal, to end the text colored with
+/// `Ansi::ansi_fg` or `Ansi::ansi_bg`.
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// The 16 standard and bright terminal colors, with the default values of xterm.
+pub const ANSI16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Returns the index of the palette entry closest to `(r, g, b)` in Oklab, the first entry wins a tie.
+fn nearest(palette: impl Iterator<Item = (u8, u8, u8)>, r: u8, g: u8, b: u8) -> usize {
+    let (l, a, b) = rgb_to_oklab(r, g, b);
+    palette
+        .map(|(pr, pg, pb)| {
+            let (pl, pa, pb) = rgb_to_oklab(pr, pg, pb);
+            (l - pl).powi(2) + (a - pa).powi(2) + (b - pb).powi(2)
+        })
+        .enumerate()
+        .fold(
+            (0, f32::INFINITY),
+            |best, cur| if cur.1 < best.1 { cur } else { best },
+        )
+        .0
+}
+
+/// Returns the index(0~15) of the terminal color closest to `(r, g, b)`.
+pub fn nearest_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    nearest(ANSI16.iter().copied(), r, g, b) as u8
+}
+
+/// The levels of a channel in the 6x6x6 color cube of the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The color of an index of the 256 color palette: 0~15 are `ANSI16`, 16~231 are the 6x6x6 color cube
+/// and 232~255 are a grayscale ramp from 8 to 238.
+pub fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let v = 8 + 10 * (index - 232);
+            (v, v, v)
+        }
+    }
+}
+
+/// Returns the index(16~255) of the 256 color palette closest to `(r, g, b)`, the 16 system colors are skipped
+/// since terminals let users change them. The closest cube color and the closest gray are compared in Oklab.
+pub fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+    let candidates = [cube, gray];
+    candidates[nearest(candidates.iter().map(|i| ansi256_to_rgb(*i)), r, g, b)]
+}
@@ -0,0 +1,152 @@
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+use crate::RGBA;
+
+/// The blend modes of the W3C Compositing and Blending spec, the same as in CSS `mix-blend-mode` and design tools.
+/// `Hue`, `Saturation`, `Color` and `Luminosity` are non-separable, they work on the whole color instead of each channel.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+type Channels = [f32; 3];
+
+fn lum(c: Channels) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: Channels) -> Channels {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    c.map(|v| {
+        let mut v = v;
+        if n < 0.0 {
+            v = l + (v - l) * l / (l - n);
+        }
+        if x > 1.0 {
+            v = l + (v - l) * (1.0 - l) / (x - l);
+        }
+        v
+    })
+}
+
+fn set_lum(c: Channels, l: f32) -> Channels {
+    let d = l - lum(c);
+    clip_color(c.map(|v| v + d))
+}
+
+fn sat(c: Channels) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: Channels, s: f32) -> Channels {
+    let mut idx = [0, 1, 2];
+    idx.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = idx;
+    let mut out = [0.0; 3];
+    if c[max] > c[min] {
+        out[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        out[max] = s;
+    }
+    out
+}
+
+impl BlendMode {
+    fn separable(&self, cb: f32, cs: f32) -> f32 {
+        let multiply = |a: f32, b: f32| a * b;
+        let screen = |a: f32, b: f32| a + b - a * b;
+        let hard_light = |cb: f32, cs: f32| {
+            if cs <= 0.5 {
+                multiply(cb, 2.0 * cs)
+            } else {
+                screen(cb, 2.0 * cs - 1.0)
+            }
+        };
+        match self {
+            Self::Multiply => multiply(cb, cs),
+            Self::Screen => screen(cb, cs),
+            Self::Overlay => hard_light(cs, cb),
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+            Self::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            Self::HardLight => hard_light(cb, cs),
+            Self::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 {
+                        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+                    } else {
+                        cb.sqrt()
+                    };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            Self::Difference => (cb - cs).abs(),
+            Self::Exclusion => cb + cs - 2.0 * cb * cs,
+            _ => cs,
+        }
+    }
+
+    /// B(Cb, Cs) of the spec, the blended color before compositing.
+    fn mix(&self, cb: Channels, cs: Channels) -> Channels {
+        match self {
+            Self::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+            Self::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+            Self::Color => set_lum(cs, lum(cb)),
+            Self::Luminosity => set_lum(cb, lum(cs)),
+            _ => [0, 1, 2].map(|i| self.separable(cb[i], cs[i])),
+        }
+    }
+
+    /// Blends `source` onto `backdrop` and composites the result with source-over.
+    pub(crate) fn blend(&self, backdrop: RGBA, source: RGBA) -> RGBA {
+        let channels = |c: RGBA| [c.r, c.g, c.b].map(|v| v as f32 / 255.0);
+        let (cb, cs) = (channels(backdrop), channels(source));
+        let (ab, a_s) = (backdrop.a, source.a);
+        let mixed = self.mix(cb, cs);
+        let a = a_s + ab * (1.0 - a_s);
+        if a <= 0.0 {
+            return RGBA::new(0, 0, 0, 0.0);
+        }
+        let c = [0, 1, 2].map(|i| {
+            let cs = (1.0 - ab) * cs[i] + ab * mixed[i];
+            let v = (a_s * cs + ab * cb[i] * (1.0 - a_s)) / a;
+            (v.clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+        RGBA::new(c[0], c[1], c[2], a)
+    }
+}
@@ -0,0 +1,378 @@
+use crate::common::{lab_to_xyz, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::f32::consts::PI;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+const CAT16: [[f32; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+const CAT16_INV: [[f32; 3]; 3] = [
+    [1.8620679, -1.0112547, 0.14918678],
+    [0.38752654, 0.62144744, -0.00897398],
+    [-0.0158415, -0.03412294, 1.0499644],
+];
+
+/// The environment a `Cam16` color is seen in.
+/// The default conditions are the ones used by Material Design: a D65 white point, an adapting luminance
+/// of 200/π times the luminance of L\*=50, a background of L\*=50, an average surround and no discounting.
+/// ### example
+/// ```rust
+/// use easy_color::{Cam16, ViewingConditions, XYZ};
+/// let dim = ViewingConditions::new(XYZ::D65, 16.0, 20.0, 1.0, false);
+/// let cam = Cam16::from_xyz(XYZ::D65, dim);
+/// assert_eq!(cam.to_string(), "cam16(100.00 4.44 209.43)");
+/// ```
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ViewingConditions {
+    n: f32,
+    aw: f32,
+    nbb: f32,
+    ncb: f32,
+    c: f32,
+    nc: f32,
+    rgb_d: [f32; 3],
+    fl: f32,
+    fl_root: f32,
+    z: f32,
+}
+
+impl ViewingConditions {
+    /// Creates viewing conditions.
+    /// * white_point - the reference white
+    /// * adapting_luminance - the luminance of the adapting field in cd/m²
+    /// * background_lstar - the L\* lightness of the background
+    /// * surround - 0 is dark, 1 is dim and 2 is average
+    /// * discounting_illuminant - whether the eye fully adapts to the illuminant
+    pub fn new(
+        white_point: XYZ,
+        adapting_luminance: f32,
+        background_lstar: f32,
+        surround: f32,
+        discounting_illuminant: bool,
+    ) -> Self {
+        let white = [
+            white_point.x * 100.0,
+            white_point.y * 100.0,
+            white_point.z * 100.0,
+        ];
+        let rgb_w = mul(&CAT16, white);
+        let f = 0.8 + surround.clamp(0.0, 2.0) / 10.0;
+        let c = if f >= 0.9 {
+            0.59 + (0.69 - 0.59) * ((f - 0.9) * 10.0)
+        } else {
+            0.525 + (0.59 - 0.525) * ((f - 0.8) * 10.0)
+        };
+        let d = if discounting_illuminant {
+            1.0
+        } else {
+            (f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())).clamp(0.0, 1.0)
+        };
+        let rgb_d = rgb_w.map(|v| d * (100.0 / v) + 1.0 - d);
+        let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+        let k4 = k.powi(4);
+        let k4f = 1.0 - k4;
+        let fl = k4 * adapting_luminance + 0.1 * k4f * k4f * (5.0 * adapting_luminance).cbrt();
+        let background_y = lab_to_xyz(background_lstar.max(0.1), 0.0, 0.0).1 * 100.0;
+        let n = background_y / white[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+        let rgb_a = [0, 1, 2].map(|i| {
+            let af = (fl * rgb_d[i] * rgb_w[i] / 100.0).powf(0.42);
+            400.0 * af / (af + 27.13)
+        });
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+        Self {
+            n,
+            aw,
+            nbb,
+            ncb: nbb,
+            c,
+            nc: f,
+            rgb_d,
+            fl,
+            fl_root: fl.powf(0.25),
+            z,
+        }
+    }
+}
+
+impl Default for ViewingConditions {
+    fn default() -> Self {
+        let background_y = lab_to_xyz(50.0, 0.0, 0.0).1 * 100.0;
+        Self::new(
+            XYZ::D65,
+            200.0 / PI * background_y / 100.0,
+            50.0,
+            2.0,
+            false,
+        )
+    }
+}
+
+fn mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [0, 1, 2].map(|i| m[i][0] * v[0] + m[i][1] * v[1] + m[i][2] * v[2])
+}
+
+/// Cam16 is the CAM16 color appearance model, which describes how a color looks under the given `ViewingConditions`.
+/// It is the base of the Material Design 3 tonal palettes, and its uniform color space(CAM16-UCS) can be obtained by `ucs`.
+/// Cam16 can be parsed from a string in the format "cam16(j c h)" or from a tuple (j,c,h), both of them use the default viewing conditions.
+/// * j:f32 - lightness(0~100)
+/// * c:f32 - chroma(0~about 150)
+/// * h:f32 - hue angle(0~360)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Cam16, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let cam:Cam16 = rgb.into();
+/// assert_eq!(cam.to_string(), "cam16(60.07 56.71 164.31)");
+///
+/// let cam:Cam16 = "cam16(60.07 56.71 164.31)".try_into().unwrap();
+/// let rgb:RGB = cam.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cam16 {
+    pub(crate) j: f32,
+    pub(crate) c: f32,
+    pub(crate) h: f32,
+    pub(crate) q: f32,
+    pub(crate) m: f32,
+    pub(crate) s: f32,
+    pub(crate) conditions: ViewingConditions,
+}
+
+impl TryFrom<&str> for Cam16 {
+    type Error = ColorError;
+    fn try_from(cam16_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(cam16_str, "cam16") {
+            if args.len() == 3 {
+                let j = parse_number(&args[0], 100.0);
+                let c = parse_number(&args[1], 100.0);
+                let h = parse_number(&args[2], 360.0);
+                if let (Some(j), Some(c), Some(h)) = (j, c, h) {
+                    return (j, c, h).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Cam16: {} format error!",
+            cam16_str
+        )))
+    }
+}
+
+impl FromStr for Cam16 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Cam16 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0)
+            || !(0.0..).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("Cam16: args ({},{},{}) value error, the lightness must between 0~100, the chroma must not be negative and the hue must between 0~360!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self::from_jch(
+                value.0,
+                value.1,
+                value.2,
+                ViewingConditions::default(),
+            ))
+        }
+    }
+}
+
+impl From<Hex> for Cam16 {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for Cam16 {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for Cam16 {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for Cam16 {
+    fn from(xyz: XYZ) -> Self {
+        Self::from_xyz(xyz, ViewingConditions::default())
+    }
+}
+
+impl Display for Cam16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "cam16({:.2} {:.2} {:.2})", self.j, self.c, self.h)
+    }
+}
+
+impl Cam16 {
+    /// Returns the appearance of a XYZ color under the given viewing conditions.
+    pub fn from_xyz(xyz: XYZ, conditions: ViewingConditions) -> Self {
+        let vc = conditions;
+        let rgb_c = mul(&CAT16, [xyz.x * 100.0, xyz.y * 100.0, xyz.z * 100.0]);
+        let [r_a, g_a, b_a] = [0, 1, 2].map(|i| {
+            let d = vc.rgb_d[i] * rgb_c[i];
+            let af = (vc.fl * d.abs() / 100.0).powf(0.42);
+            d.signum() * 400.0 * af / (af + 27.13)
+        });
+        let a = (11.0 * r_a - 12.0 * g_a + b_a) / 11.0;
+        let b = (r_a + g_a - 2.0 * b_a) / 9.0;
+        let u = (20.0 * r_a + 20.0 * g_a + 21.0 * b_a) / 20.0;
+        let p2 = (40.0 * r_a + 20.0 * g_a + b_a) / 20.0;
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let ac = p2 * vc.nbb;
+        let j = 100.0 * (ac / vc.aw).max(0.0).powf(vc.c * vc.z);
+        let q = 4.0 / vc.c * (j / 100.0).sqrt() * (vc.aw + 4.0) * vc.fl_root;
+        let hue_prime = if h < 20.14 { h + 360.0 } else { h };
+        let e_hue = 0.25 * ((hue_prime.to_radians() + 2.0).cos() + 3.8);
+        let p1 = 50000.0 / 13.0 * e_hue * vc.nc * vc.ncb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f32.powf(vc.n)).powf(0.73);
+        let c = alpha * (j / 100.0).sqrt();
+        let m = c * vc.fl_root;
+        let s = 50.0 * (alpha * vc.c / (vc.aw + 4.0)).sqrt();
+        Self {
+            j,
+            c,
+            h,
+            q,
+            m,
+            s,
+            conditions,
+        }
+    }
+
+    /// Creates a color from its lightness, chroma and hue under the given viewing conditions.
+    pub fn from_jch(j: f32, c: f32, h: f32, conditions: ViewingConditions) -> Self {
+        let vc = conditions;
+        let q = 4.0 / vc.c * (j / 100.0).sqrt() * (vc.aw + 4.0) * vc.fl_root;
+        let m = c * vc.fl_root;
+        let alpha = if j == 0.0 {
+            0.0
+        } else {
+            c / (j / 100.0).sqrt()
+        };
+        let s = 50.0 * (alpha * vc.c / (vc.aw + 4.0)).sqrt();
+        Self {
+            j,
+            c,
+            h,
+            q,
+            m,
+            s,
+            conditions,
+        }
+    }
+
+    /// Creates a color from its CAM16-UCS coordinates under the given viewing conditions.
+    /// ```rust
+    /// use easy_color::{Cam16, RGB, ViewingConditions};
+    /// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+    /// let (j, a, b) = Cam16::from(rgb).ucs();
+    /// let cam = Cam16::from_ucs(j, a, b, ViewingConditions::default());
+    /// assert_eq!(RGB::from(cam).to_string(), "rgb(43,196,138)");
+    /// ```
+    pub fn from_ucs(jstar: f32, astar: f32, bstar: f32, conditions: ViewingConditions) -> Self {
+        let m = (astar.hypot(bstar) * 0.0228).exp_m1() / 0.0228;
+        let c = m / conditions.fl_root;
+        let h = bstar.atan2(astar).to_degrees().rem_euclid(360.0);
+        let j = jstar / (1.0 - (jstar - 100.0) * 0.007);
+        Self::from_jch(j, c, h, conditions)
+    }
+
+    /// Returns the XYZ color that has this appearance under the viewing conditions of the color.
+    pub fn to_xyz(&self) -> XYZ {
+        let vc = self.conditions;
+        let alpha = if self.c == 0.0 || self.j == 0.0 {
+            0.0
+        } else {
+            self.c / (self.j / 100.0).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29_f32.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9);
+        let h_rad = self.h.to_radians();
+        let e_hue = 0.25 * ((h_rad + 2.0).cos() + 3.8);
+        let ac = vc.aw * (self.j / 100.0).powf(1.0 / vc.c / vc.z);
+        let p1 = e_hue * (50000.0 / 13.0) * vc.nc * vc.ncb;
+        let p2 = ac / vc.nbb;
+        let (h_sin, h_cos) = h_rad.sin_cos();
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * h_cos + 108.0 * t * h_sin);
+        let a = gamma * h_cos;
+        let b = gamma * h_sin;
+        let rgb_a = [
+            (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        ];
+        let rgb_f = [0, 1, 2].map(|i| {
+            let v = rgb_a[i];
+            let base = (27.13 * v.abs() / (400.0 - v.abs())).max(0.0);
+            v.signum() * (100.0 / vc.fl) * base.powf(1.0 / 0.42) / vc.rgb_d[i]
+        });
+        let [x, y, z] = mul(&CAT16_INV, rgb_f);
+        XYZ {
+            x: (x / 100.0).max(0.0),
+            y: (y / 100.0).max(0.0),
+            z: (z / 100.0).max(0.0),
+        }
+    }
+
+    /// Returns the CAM16-UCS coordinates (J\*, a\*, b\*) of the color,
+    /// the euclidean distance between two colors in it is a good color difference.
+    pub fn ucs(&self) -> (f32, f32, f32) {
+        let jstar = (1.0 + 100.0 * 0.007) * self.j / (1.0 + 0.007 * self.j);
+        let mstar = (0.0228 * self.m).ln_1p() / 0.0228;
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        (jstar, mstar * cos, mstar * sin)
+    }
+
+    pub fn lightness(&self) -> f32 {
+        self.j
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn brightness(&self) -> f32 {
+        self.q
+    }
+
+    pub fn colorfulness(&self) -> f32 {
+        self.m
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.s
+    }
+
+    pub fn viewing_conditions(&self) -> ViewingConditions {
+        self.conditions
+    }
+}
@@ -1,13 +1,18 @@
-use crate::common::{calc_rgb_with_alpha, rgb_to_cmyk};
-use crate::{ColorError, Hex, HSL, HSLA, HSV, RGB, RGBA};
+use crate::common::{calc_rgb_with_alpha, parse_fn_args, rgb_to_cmyk};
+use crate::{AdobeRGB, ColorError, Hex, LCh, Lab, Okhsl, Okhsv, Oklab, Oklch, ProPhotoRGB, HSI, HSL, HSLA, HSV, HWB, RGB, RGBA, XYZ};
 // use rand::Rng;
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 
 /// CMYK can be parsed from a string in the format "cmyk(c,m,y,k)" or from a tuple (c,m,y,k).
-/// * c:u8 - cyan value(0~100)
-/// * m:u8 - magenta value(0~100)
-/// * y:u8 - yellow value(0~100)
-/// * k:u8 - black value(0~100)
+/// * c:f32 - cyan value(0~100)
+/// * m:f32 - magenta value(0~100)
+/// * y:f32 - yellow value(0~100)
+/// * k:f32 - black value(0~100)
+///
+/// Like `HSL` the channels are stored as floats, the accessors and `Display` round them.
 /// ### example
 /// ```rust
 /// use easy_color::{Hex, CMYK};
@@ -15,33 +20,31 @@ use std::fmt::{Display, Formatter};
 /// cmyk.set_cyan(100);
 /// assert_eq!(cmyk.to_string(), "cmyk(100,34,53,38)");
 ///
+/// let cmyk:CMYK = "cmyk(77 34 53 38)".try_into().unwrap();
+/// assert_eq!(cmyk.to_string(), "cmyk(77,34,53,38)");
+///
 /// let cmyk:CMYK = (100,34,53,38).try_into().unwrap();
 /// let hex:Hex = cmyk.into();
 /// assert_eq!(hex.to_string(), "#00684A");
 /// ```
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct CMYK {
-  pub(crate) c: u8,
-  pub(crate) m: u8,
-  pub(crate) y: u8,
-  pub(crate) k: u8,
+  pub(crate) c: f32,
+  pub(crate) m: f32,
+  pub(crate) y: f32,
+  pub(crate) k: f32,
 }
 
 impl TryFrom<&str> for CMYK {
   type Error = ColorError;
   fn try_from(cmyk_str: &str) -> Result<Self, Self::Error> {
-    let mut color = cmyk_str.trim().to_lowercase();
-    if color.starts_with("cmyk(") && color.ends_with(')') {
-      color = color.replace("cmyk(", "").replace(')', "");
-      let tmp = color.split(',').collect::<Vec<_>>();
-      if tmp.len() == 4 {
-        let val = tmp
-          .iter()
-          .filter_map(|s| s.parse::<u8>().ok())
-          .collect::<Vec<_>>();
-        if val.len() == 4 {
-          return (val[0], val[1], val[2], val[3]).try_into();
-        }
+    if let Some(args) = parse_fn_args(cmyk_str, "cmyk") {
+      let val = args
+        .iter()
+        .filter_map(|s| s.parse::<u8>().ok())
+        .collect::<Vec<_>>();
+      if args.len() == 4 && val.len() == 4 {
+        return (val[0], val[1], val[2], val[3]).try_into();
       }
     }
     Err(ColorError::FormatErr(format!(
@@ -51,6 +54,13 @@ impl TryFrom<&str> for CMYK {
   }
 }
 
+impl FromStr for CMYK {
+  type Err = ColorError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s)
+  }
+}
+
 impl TryFrom<(u8, u8, u8, u8)> for CMYK {
   type Error = ColorError;
   fn try_from(value: (u8, u8, u8, u8)) -> Result<Self, Self::Error> {
@@ -64,12 +74,7 @@ impl TryFrom<(u8, u8, u8, u8)> for CMYK {
         value.0, value.1, value.2, value.3
       )))
     } else {
-      Ok(Self {
-        c: value.0,
-        m: value.1,
-        y: value.2,
-        k: value.3,
-      })
+      Ok(Self::new(value.0, value.1, value.2, value.3))
     }
   }
 }
@@ -83,9 +88,7 @@ impl From<Hex> for CMYK {
 
 impl From<RGB> for CMYK {
   fn from(rgb: RGB) -> Self {
-    let RGB { r, g, b } = rgb;
-    let (c, m, y, k) = rgb_to_cmyk(r, g, b);
-    Self { c, m, y, k }
+    Self::from_rgb(rgb)
   }
 }
 
@@ -122,45 +125,138 @@ impl From<HSV> for CMYK {
   }
 }
 
+impl From<Oklab> for CMYK {
+  fn from(oklab: Oklab) -> Self {
+    let rgb: RGB = oklab.into();
+    rgb.into()
+  }
+}
+
+impl From<Oklch> for CMYK {
+  fn from(oklch: Oklch) -> Self {
+    let rgb: RGB = oklch.into();
+    rgb.into()
+  }
+}
+
+impl From<Lab> for CMYK {
+  fn from(lab: Lab) -> Self {
+    let rgb: RGB = lab.into();
+    rgb.into()
+  }
+}
+
+impl From<LCh> for CMYK {
+  fn from(lch: LCh) -> Self {
+    let rgb: RGB = lch.into();
+    rgb.into()
+  }
+}
+
+impl From<XYZ> for CMYK {
+  fn from(xyz: XYZ) -> Self {
+    let rgb: RGB = xyz.into();
+    rgb.into()
+  }
+}
+
+impl From<HWB> for CMYK {
+  fn from(hwb: HWB) -> Self {
+    let rgb: RGB = hwb.into();
+    rgb.into()
+  }
+}
+
+impl From<HSI> for CMYK {
+  fn from(hsi: HSI) -> Self {
+    let rgb: RGB = hsi.into();
+    rgb.into()
+  }
+}
+
+impl From<Okhsl> for CMYK {
+  fn from(okhsl: Okhsl) -> Self {
+    let rgb: RGB = okhsl.into();
+    rgb.into()
+  }
+}
+
+impl From<Okhsv> for CMYK {
+  fn from(okhsv: Okhsv) -> Self {
+    let rgb: RGB = okhsv.into();
+    rgb.into()
+  }
+}
+
+impl From<AdobeRGB> for CMYK {
+  fn from(adobe: AdobeRGB) -> Self {
+    let rgb: RGB = adobe.into();
+    rgb.into()
+  }
+}
+
+impl From<ProPhotoRGB> for CMYK {
+  fn from(prophoto: ProPhotoRGB) -> Self {
+    let rgb: RGB = prophoto.into();
+    rgb.into()
+  }
+}
+
 impl Display for CMYK {
-  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-    write!(f, "cmyk({},{},{},{})", self.c, self.m, self.y, self.k)
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "cmyk({},{},{},{})", self.cyan(), self.magenta(), self.yellow(), self.black())
   }
 }
 
 impl CMYK {
+  /// Creates a color in a const context, the values are clamped to 0~100 like the setters do.
+  pub const fn new(cyan: u8, magenta: u8, yellow: u8, black: u8) -> Self {
+    Self {
+      c: if cyan > 100 { 100 } else { cyan } as f32,
+      m: if magenta > 100 { 100 } else { magenta } as f32,
+      y: if yellow > 100 { 100 } else { yellow } as f32,
+      k: if black > 100 { 100 } else { black } as f32,
+    }
+  }
+
+  /// The const version of `From<RGB>`.
+  pub const fn from_rgb(rgb: RGB) -> Self {
+    let (c, m, y, k) = rgb_to_cmyk(rgb.r, rgb.g, rgb.b);
+    Self { c, m, y, k }
+  }
+
   pub fn cyan(&self) -> u8 {
-    self.c
+    self.c.round() as u8
   }
   pub fn set_cyan(&mut self, cyan: u8) -> &mut Self {
-    self.c = cyan.min(100);
+    self.c = cyan.min(100) as f32;
     self
   }
 
   pub fn magenta(&self) -> u8 {
-    self.m
+    self.m.round() as u8
   }
 
   pub fn set_magenta(&mut self, magenta: u8) -> &mut Self {
-    self.m = magenta.min(100);
+    self.m = magenta.min(100) as f32;
     self
   }
 
   pub fn yellow(&self) -> u8 {
-    self.m
+    self.y.round() as u8
   }
 
   pub fn set_yellow(&mut self, yellow: u8) -> &mut Self {
-    self.y = yellow.min(100);
+    self.y = yellow.min(100) as f32;
     self
   }
 
   pub fn black(&self) -> u8 {
-    self.m
+    self.k.round() as u8
   }
 
   pub fn set_black(&mut self, black: u8) -> &mut Self {
-    self.m = black.min(100);
+    self.k = black.min(100) as f32;
     self
   }
 
@@ -0,0 +1,234 @@
+use crate::common::parse_fn_args_alpha;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, Cam16, ColorError, ColorFunction, Din99, DisplayP3, Gray, HPLuv, HSLuv, Hex,
+    HunterLab, ICtCp, Jzazbz, Kelvin, LCHuv, LCh, Lab, LinearRGB, Luv, Okhsl, Okhsv, Oklab, Oklch,
+    ProPhotoRGB, Rec2020, XyY, YCbCr, YCoCg, CMYK, HSI, HSL, HSLA, HSV, HSVA, HWB, RGB, RGBA, XYZ,
+    YIQ, YUV,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+macro_rules! color_enum {
+    ($($variant:ident),* $(,)?) => {
+        /// AnyColor wraps every concrete color type, so code can accept any color without knowing its type up front.
+        /// `AnyColor::parse` detects the format of a string and returns the matching variant.
+        ///
+        /// ### example
+        /// ```rust
+        /// use easy_color::{AnyColor, Hex, RGB, RGBA};
+        /// let color = AnyColor::parse("#2bc48a").unwrap();
+        /// assert!(matches!(color, AnyColor::Hex(_)));
+        ///
+        /// let color = AnyColor::parse("hsl(157 64% 47% / 0.5)").unwrap();
+        /// assert_eq!(color.to_string(), "hsla(157,64%,47%,0.50)");
+        /// let rgba:RGBA = color.into();
+        /// assert_eq!(rgba.to_string(), "rgba(43,197,138,0.50)");
+        ///
+        /// let color = AnyColor::parse("tomato").unwrap();
+        /// assert_eq!(color, AnyColor::RGB((255, 99, 71).try_into().unwrap()));
+        ///
+        /// let color:AnyColor = Hex::try_from("#FF0000").unwrap().into();
+        /// let rgb:RGB = color.into();
+        /// assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+        ///
+        /// let color:AnyColor = "oklch(0.7 0.15 145)".parse().unwrap();
+        /// assert!(matches!(color, AnyColor::Oklch(_)));
+        /// ```
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+        pub enum AnyColor {
+            $($variant($variant),)*
+        }
+
+        /// Deserializes the tagged form written by `Serialize`, like `{"HSL":{"h":157.0,"s":64.0,"l":47.0}}`,
+        /// or any string that `AnyColor::parse` accepts.
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for AnyColor {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(::serde::Deserialize)]
+                #[allow(clippy::upper_case_acronyms)]
+                enum Tagged {
+                    $($variant($variant),)*
+                }
+                #[derive(::serde::Deserialize)]
+                #[serde(untagged)]
+                enum Repr {
+                    Str(String),
+                    Tagged(Tagged),
+                }
+                match Repr::deserialize(deserializer)? {
+                    Repr::Str(s) => Self::parse(&s).map_err(::serde::de::Error::custom),
+                    Repr::Tagged(color) => Ok(match color {
+                        $(Tagged::$variant(c) => Self::$variant(c),)*
+                    }),
+                }
+            }
+        }
+
+        $(
+            impl From<$variant> for AnyColor {
+                fn from(color: $variant) -> Self {
+                    Self::$variant(color)
+                }
+            }
+        )*
+
+        impl From<AnyColor> for RGBA {
+            fn from(color: AnyColor) -> Self {
+                match color {
+                    $(AnyColor::$variant(c) => c.into(),)*
+                }
+            }
+        }
+
+        impl Display for AnyColor {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(Self::$variant(c) => c.fmt(f),)*
+                }
+            }
+        }
+    };
+}
+
+color_enum!(
+    Hex,
+    RGB,
+    RGBA,
+    HSL,
+    HSLA,
+    HSV,
+    HSVA,
+    CMYK,
+    HWB,
+    HSI,
+    Lab,
+    LCh,
+    Oklab,
+    Oklch,
+    Okhsl,
+    Okhsv,
+    XYZ,
+    XyY,
+    Luv,
+    LCHuv,
+    HSLuv,
+    HPLuv,
+    YCbCr,
+    YUV,
+    YIQ,
+    YCoCg,
+    Cam16,
+    Jzazbz,
+    ICtCp,
+    Din99,
+    HunterLab,
+    LinearRGB,
+    DisplayP3,
+    AdobeRGB,
+    Rec2020,
+    ProPhotoRGB,
+    ColorFunction,
+    Gray,
+    Kelvin,
+);
+
+impl TryFrom<&str> for AnyColor {
+    type Error = ColorError;
+    fn try_from(color_str: &str) -> Result<Self, Self::Error> {
+        Self::parse(color_str)
+    }
+}
+
+impl FromStr for AnyColor {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl From<AnyColor> for RGB {
+    fn from(color: AnyColor) -> Self {
+        let rgba: RGBA = color.into();
+        rgba.into()
+    }
+}
+
+impl AnyColor {
+    /// Parses any supported color string: hex, css functions such as `rgb()`, `hsl()` and `oklch()`,
+    /// named colors, `transparent` and kelvin temperatures like `3200K`.
+    /// `rgb()`, `hsl()` and `hsv()` return `RGBA`, `HSLA` and `HSVA` variants when the string carries an alpha,
+    /// `rgba()` always returns a `RGBA` variant, which is opaque when the alpha is left out.
+    /// ```rust
+    /// use easy_color::{AnyColor, RGBA};
+    /// let color = AnyColor::parse("rgba(255 0 0)").unwrap();
+    /// assert_eq!(color, AnyColor::RGBA(RGBA::new(255, 0, 0, 1.0)));
+    /// ```
+    pub fn parse(color_str: &str) -> Result<Self, ColorError> {
+        let color = color_str.trim().to_lowercase();
+        if color.starts_with('#') {
+            return Hex::try_from(color_str).map(Self::Hex);
+        }
+        let name = match color.find('(') {
+            Some(i) => color[..i].trim_end(),
+            None => {
+                if color == "transparent" {
+                    return RGBA::try_from(color_str).map(Self::RGBA);
+                }
+                // only digits before the unit, so keywords like "black" stay named colors
+                let is_kelvin = color
+                    .strip_suffix('k')
+                    .map(|v| v.trim_end())
+                    .map(|v| !v.is_empty() && v.bytes().all(|c| c.is_ascii_digit()))
+                    .unwrap_or(false);
+                if is_kelvin {
+                    return Kelvin::try_from(color_str).map(Self::Kelvin);
+                }
+                return RGB::try_from(color_str).map(Self::RGB);
+            }
+        };
+        let has_alpha = parse_fn_args_alpha(&color, name)
+            .map(|(args, alpha)| alpha.is_some() || args.len() == 4)
+            .unwrap_or(false);
+        match name {
+            "rgb" if !has_alpha => RGB::try_from(color_str).map(Self::RGB),
+            "rgb" | "rgba" => RGBA::try_from(color_str).map(Self::RGBA),
+            "hsl" if !has_alpha => HSL::try_from(color_str).map(Self::HSL),
+            "hsl" | "hsla" => HSLA::try_from(color_str).map(Self::HSLA),
+            "hsv" if !has_alpha => HSV::try_from(color_str).map(Self::HSV),
+            "hsv" | "hsva" => HSVA::try_from(color_str).map(Self::HSVA),
+            "cmyk" => CMYK::try_from(color_str).map(Self::CMYK),
+            "hwb" => HWB::try_from(color_str).map(Self::HWB),
+            "hsi" => HSI::try_from(color_str).map(Self::HSI),
+            "lab" => Lab::try_from(color_str).map(Self::Lab),
+            "lch" => LCh::try_from(color_str).map(Self::LCh),
+            "oklab" => Oklab::try_from(color_str).map(Self::Oklab),
+            "oklch" => Oklch::try_from(color_str).map(Self::Oklch),
+            "okhsl" => Okhsl::try_from(color_str).map(Self::Okhsl),
+            "okhsv" => Okhsv::try_from(color_str).map(Self::Okhsv),
+            "xyz" => XYZ::try_from(color_str).map(Self::XYZ),
+            "xyy" => XyY::try_from(color_str).map(Self::XyY),
+            "luv" => Luv::try_from(color_str).map(Self::Luv),
+            "lchuv" => LCHuv::try_from(color_str).map(Self::LCHuv),
+            "hsluv" => HSLuv::try_from(color_str).map(Self::HSLuv),
+            "hpluv" => HPLuv::try_from(color_str).map(Self::HPLuv),
+            "ycbcr" => YCbCr::try_from(color_str).map(Self::YCbCr),
+            "yuv" => YUV::try_from(color_str).map(Self::YUV),
+            "yiq" => YIQ::try_from(color_str).map(Self::YIQ),
+            "ycocg" => YCoCg::try_from(color_str).map(Self::YCoCg),
+            "cam16" => Cam16::try_from(color_str).map(Self::Cam16),
+            "jzazbz" => Jzazbz::try_from(color_str).map(Self::Jzazbz),
+            "ictcp" => ICtCp::try_from(color_str).map(Self::ICtCp),
+            "din99" => Din99::try_from(color_str).map(Self::Din99),
+            "hunterlab" => HunterLab::try_from(color_str).map(Self::HunterLab),
+            "gray" => Gray::try_from(color_str).map(Self::Gray),
+            "color" => ColorFunction::try_from(color_str).map(Self::ColorFunction),
+            _ => Err(ColorError::FormatErr(format!(
+                "AnyColor: {} format error!",
+                color_str
+            ))),
+        }
+    }
+}
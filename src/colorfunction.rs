@@ -0,0 +1,154 @@
+use crate::common::{mul_matrix3, parse_fn_args_alpha, parse_number, D50_TO_D65};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{AdobeRGB, ColorError, DisplayP3, LinearRGB, ProPhotoRGB, Rec2020, RGB, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// ColorFunction is a color written with the CSS `color()` function, which names its color space before the channels,
+/// e.g. "color(display-p3 1 0 0 / 0.5)". Parsing dispatches to the type of that color space and keeps the alpha(0~1).
+/// * srgb - `RGB`
+/// * srgb-linear - `LinearRGB`
+/// * display-p3 - `DisplayP3`
+/// * a98-rgb - `AdobeRGB`
+/// * prophoto-rgb - `ProPhotoRGB`
+/// * rec2020 - `Rec2020`
+/// * xyz, xyz-d65 and xyz-d50 - `XYZ`, D50 values are adapted to D65 with the Bradford transform
+///
+/// ### example
+/// ```rust
+/// use easy_color::{ColorFunction, RGBA};
+/// let color:ColorFunction = "color(display-p3 1 0 0 / .5)".try_into().unwrap();
+/// if let ColorFunction::DisplayP3(p3, alpha) = color {
+///     assert_eq!(p3.to_string(), "color(display-p3 1.0000 0.0000 0.0000)");
+///     assert_eq!(alpha, 0.5);
+/// }
+/// assert_eq!(color.to_string(), "color(display-p3 1.0000 0.0000 0.0000 / 0.50)");
+///
+/// let rgba:RGBA = color.into();
+/// assert_eq!(rgba.to_string(), "rgba(255,0,0,0.50)");
+///
+/// let color:ColorFunction = "color(srgb 0.1686 0.7686 0.5412)".try_into().unwrap();
+/// assert_eq!(color, ColorFunction::Srgb((43, 196, 138).try_into().unwrap(), 1.0));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ColorFunction {
+    Srgb(RGB, f32),
+    SrgbLinear(LinearRGB, f32),
+    DisplayP3(DisplayP3, f32),
+    A98Rgb(AdobeRGB, f32),
+    ProPhotoRgb(ProPhotoRGB, f32),
+    Rec2020(Rec2020, f32),
+    Xyz(XYZ, f32),
+}
+
+impl TryFrom<&str> for ColorFunction {
+    type Error = ColorError;
+    fn try_from(color_str: &str) -> Result<Self, Self::Error> {
+        if let Some((args, alpha)) = parse_fn_args_alpha(color_str, "color") {
+            let alpha = alpha.unwrap_or(1.0);
+            if !(0.0..=1.0).contains(&alpha) {
+                return Err(ColorError::ValueErr(format!(
+                    "ColorFunction: the alpha value must between 0~1, but got {}.",
+                    alpha
+                )));
+            }
+            let channels = args
+                .iter()
+                .skip(1)
+                .map(|s| parse_number(s, 1.0))
+                .collect::<Option<Vec<_>>>()
+                .filter(|c| c.len() == 3);
+            if let Some(c) = channels {
+                let (r, g, b) = (c[0], c[1], c[2]);
+                match args[0].as_str() {
+                    "srgb" => {
+                        if ![r, g, b].iter().all(|v| (0.0..=1.0).contains(v)) {
+                            return Err(ColorError::ValueErr(format!(
+                                "ColorFunction: args ({},{},{}) value error, srgb values must between 0~1!",
+                                r, g, b
+                            )));
+                        }
+                        let to_u8 = |v: f32| (v * 255.0).round() as u8;
+                        let rgb = RGB::try_from((to_u8(r), to_u8(g), to_u8(b)))?;
+                        return Ok(Self::Srgb(rgb, alpha));
+                    }
+                    "srgb-linear" => {
+                        return LinearRGB::try_from(color_str).map(|c| Self::SrgbLinear(c, alpha))
+                    }
+                    "display-p3" => {
+                        return DisplayP3::try_from(color_str).map(|c| Self::DisplayP3(c, alpha))
+                    }
+                    "a98-rgb" => {
+                        return AdobeRGB::try_from(color_str).map(|c| Self::A98Rgb(c, alpha))
+                    }
+                    "prophoto-rgb" => {
+                        return ProPhotoRGB::try_from(color_str)
+                            .map(|c| Self::ProPhotoRgb(c, alpha))
+                    }
+                    "rec2020" => {
+                        return Rec2020::try_from(color_str).map(|c| Self::Rec2020(c, alpha))
+                    }
+                    "xyz" | "xyz-d65" => {
+                        return XYZ::try_from((r, g, b)).map(|c| Self::Xyz(c, alpha));
+                    }
+                    "xyz-d50" => {
+                        let xyz = XYZ::try_from(mul_matrix3(&D50_TO_D65, (r, g, b)))?;
+                        return Ok(Self::Xyz(xyz, alpha));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "ColorFunction: {} format error!",
+            color_str
+        )))
+    }
+}
+
+impl FromStr for ColorFunction {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for ColorFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let color = match self {
+            Self::Srgb(rgb, _) => format!(
+                "color(srgb {:.4} {:.4} {:.4})",
+                rgb.r as f32 / 255.0,
+                rgb.g as f32 / 255.0,
+                rgb.b as f32 / 255.0
+            ),
+            Self::SrgbLinear(c, _) => c.to_string(),
+            Self::DisplayP3(c, _) => c.to_string(),
+            Self::A98Rgb(c, _) => c.to_string(),
+            Self::ProPhotoRgb(c, _) => c.to_string(),
+            Self::Rec2020(c, _) => c.to_string(),
+            Self::Xyz(c, _) => format!("color(xyz-d65 {:.4} {:.4} {:.4})", c.x, c.y, c.z),
+        };
+        let alpha = self.alpha();
+        if alpha != 1.0 {
+            write!(f, "{} / {:.2})", color.trim_end_matches(')'), alpha)
+        } else {
+            write!(f, "{}", color)
+        }
+    }
+}
+
+impl ColorFunction {
+    pub fn alpha(&self) -> f32 {
+        match *self {
+            Self::Srgb(_, a)
+            | Self::SrgbLinear(_, a)
+            | Self::DisplayP3(_, a)
+            | Self::A98Rgb(_, a)
+            | Self::ProPhotoRgb(_, a)
+            | Self::Rec2020(_, a)
+            | Self::Xyz(_, a) => a,
+        }
+    }
+}
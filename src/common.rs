@@ -1,22 +1,53 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
 #[derive(Debug, Clone)]
 pub enum ColorError {
     FormatErr(String),
     ValueErr(String),
 }
 
+impl core::fmt::Display for ColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ColorError::FormatErr(msg) | ColorError::ValueErr(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl core::error::Error for ColorError {}
+
 pub fn calc_rgb_with_alpha(v: u8, alpha: f32) -> f32 {
     v as f32 * alpha + 255.0 * (1.0 - alpha)
 }
 
-pub fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+/// `f32::round` for the const conversions, core has no float rounding so it is done by hand without std.
+const fn round(v: f32) -> f32 {
+    #[cfg(feature = "std")]
+    {
+        v.round()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let t = v as i64 as f32;
+        if (v - t).abs() >= 0.5 {
+            t + v.signum()
+        } else {
+            t
+        }
+    }
+}
+
+/// The hue, saturation and lightness of a color, saturation and lightness are 0~100 and not rounded.
+pub const fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
     calc_rgb_to_hsl(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
 }
-pub fn rgba_to_hsla(r: u8, g: u8, b: u8, a: f32) -> (u32, u32, u32, f32) {
+pub fn rgba_to_hsla(r: u8, g: u8, b: u8, a: f32) -> (f32, f32, f32, f32) {
     let (h, s, l) = rgb_to_hsl(r, g, b);
     (h, s, l, a)
 }
 
-pub fn calc_rgb_to_hsl(r: f32, g: f32, b: f32) -> (u32, u32, u32) {
+pub const fn calc_rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
     let c_max = r.max(g).max(b);
     let c_min = r.min(g).min(b);
     let delta = c_max - c_min;
@@ -38,35 +69,38 @@ pub fn calc_rgb_to_hsl(r: f32, g: f32, b: f32) -> (u32, u32, u32) {
     } else {
         delta / (1.0 - (2.0 * l - 1.0).abs())
     };
-    (
-        h.round() as u32,
-        (s * 100.0).round() as u32,
-        (l * 100.0).round() as u32,
-    )
+    (h, s * 100.0, l * 100.0)
 }
 
-pub fn hsl_to_rgb(h: u32, s: u32, l: u32) -> (u8, u8, u8) {
-    let s = s as f32 / 100.0;
-    let l = l as f32 / 100.0;
+/// Splits the chroma of a hue over the r, g and b channels.
+const fn hue_to_channels(h: f32, c: f32, x: f32) -> (f32, f32, f32) {
+    match h {
+        n if n < 60.0 => (c, x, 0.0),
+        n if n < 120.0 => (x, c, 0.0),
+        n if n < 180.0 => (0.0, c, x),
+        n if n < 240.0 => (0.0, x, c),
+        n if n < 300.0 => (x, 0.0, c),
+        n if n < 360.0 => (c, 0.0, x),
+        _ => (c, x, 0.0),
+    }
+}
+
+pub const fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let s = s / 100.0;
+    let l = l / 100.0;
     let c = (1.0 - (l * 2.0 - 1.0).abs()) * s;
-    let x = c * (1.0 - ((h as f32 / 60.0) % 2.0 - 1.0).abs());
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
     let m = l - c / 2.0;
-    let (mut r, mut g, mut b) = match h {
-        n if n < 60 => (c, x, 0.0),
-        n if (60..120).contains(&n) => (x, c, 0.0),
-        n if (120..180).contains(&n) => (0.0, c, x),
-        n if (180..240).contains(&n) => (0.0, x, c),
-        n if (240..300).contains(&n) => (x, 0.0, c),
-        n if (300..360).contains(&n) => (c, 0.0, x),
-        _ => (0.0, 0.0, 0.0),
-    };
-    r = (r + m) * 255.0;
-    g = (g + m) * 255.0;
-    b = (b + m) * 255.0;
-    (r.round() as u8, g as u8, b.round() as u8)
-}
-
-pub fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+    let (r, g, b) = hue_to_channels(h, c, x);
+    (
+        round((r + m) * 255.0) as u8,
+        round((g + m) * 255.0) as u8,
+        round((b + m) * 255.0) as u8,
+    )
+}
+
+/// The hue, saturation and value of a color, saturation and value are 0~100 and not rounded.
+pub const fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
     let r = r as f32 / 255.0;
     let g = g as f32 / 255.0;
     let b = b as f32 / 255.0;
@@ -92,35 +126,25 @@ pub fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
     let s = if c_max == 0.0 { 0.0 } else { delta / c_max };
 
     let v = c_max;
-    (
-        h.round() as u32,
-        (s * 100.0).round() as u32,
-        (v * 100.0).round() as u32,
-    )
+    (h, s * 100.0, v * 100.0)
 }
 
-pub fn hsv_to_rgb(h: u32, s: u32, v: u32) -> (u8, u8, u8) {
-    let s = s as f32 / 100.0;
-    let v = v as f32 / 100.0;
+pub const fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
+    let s = s / 100.0;
+    let v = v / 100.0;
     let c = v * s;
-    let x = c * (1.0 - ((h as f32 / 60.0) % 2.0 - 1.0).abs());
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
     let m = v - c;
-    let (mut r, mut g, mut b) = match h {
-        n if n < 60 => (c, x, 0.0),
-        n if (60..120).contains(&n) => (x, c, 0.0),
-        n if (120..180).contains(&n) => (0.0, c, x),
-        n if (180..240).contains(&n) => (0.0, x, c),
-        n if (240..300).contains(&n) => (x, 0.0, c),
-        n if (300..360).contains(&n) => (c, 0.0, x),
-        _ => (0.0, 0.0, 0.0),
-    };
-    r = (r + m) * 255.0;
-    g = (g + m) * 255.0;
-    b = (b + m) * 255.0;
-    (r as u8, g as u8, b.round() as u8)
-}
-
-pub fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (u8, u8, u8, u8) {
+    let (r, g, b) = hue_to_channels(h, c, x);
+    (
+        round((r + m) * 255.0) as u8,
+        round((g + m) * 255.0) as u8,
+        round((b + m) * 255.0) as u8,
+    )
+}
+
+/// The cyan, magenta, yellow and black of a color, all 0~100 and not rounded.
+pub const fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (f32, f32, f32, f32) {
     let r = r as f32 / 255.0;
     let g = g as f32 / 255.0;
     let b = b as f32 / 255.0;
@@ -135,27 +159,28 @@ pub fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (u8, u8, u8, u8) {
             (1.0 - b - k) / (1.0 - k),
         )
     };
-    (
-        (c * 100.0).round() as u8,
-        (m * 100.0).round() as u8,
-        (y * 100.0).round() as u8,
-        (k * 100.0).round() as u8,
-    )
+    (c * 100.0, m * 100.0, y * 100.0, k * 100.0)
 }
 
-pub fn cmyk_to_rgb(c: u8, m: u8, y: u8, k: u8) -> (u8, u8, u8) {
-    let c = c as f32 / 100.0;
-    let m = m as f32 / 100.0;
-    let y = y as f32 / 100.0;
-    let k = k as f32 / 100.0;
+pub const fn cmyk_to_rgb(c: f32, m: f32, y: f32, k: f32) -> (u8, u8, u8) {
+    let c = c / 100.0;
+    let m = m / 100.0;
+    let y = y / 100.0;
+    let k = k / 100.0;
     let t = 1.0 - k;
-    let r = ((1.0 - c) * t * 255.0).round() as u8;
-    let g = ((1.0 - m) * t * 255.0).round() as u8;
-    let b = ((1.0 - y) * t * 255.0).round() as u8;
+    let r = round((1.0 - c) * t * 255.0) as u8;
+    let g = round((1.0 - m) * t * 255.0) as u8;
+    let b = round((1.0 - y) * t * 255.0) as u8;
     (r, g, b)
 }
 
+/// Splits the hex digits into channels of `chunk_size` digits, a single digit is doubled like `#FA0`.
+/// Returns no channel at all when the string has anything but ascii hex digits, so a multi-byte char
+/// can't split a chunk.
 pub fn process_hex(hex_str: &str, chunk_size: usize) -> Vec<u8> {
+    if !hex_str.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Vec::new();
+    }
     hex_str
         .chars()
         .collect::<Vec<_>>()
@@ -165,3 +190,1230 @@ pub fn process_hex(hex_str: &str, chunk_size: usize) -> Vec<u8> {
         .filter_map(|v| v.ok())
         .collect::<Vec<_>>()
 }
+
+/// Returns the arguments of a css-like function such as `oklab(0.5 0.1 0.1)`.
+/// Both comma and whitespace separators are accepted, an alpha after `/` is ignored.
+pub fn parse_fn_args(color_str: &str, name: &str) -> Option<Vec<String>> {
+    parse_fn_args_alpha(color_str, name).map(|(args, _)| args)
+}
+
+/// Like `parse_fn_args`, but also returns the alpha of the modern syntax `rgb(255 0 0 / 0.5)`,
+/// the alpha can be a number or a percentage.
+pub fn parse_fn_args_alpha(color_str: &str, name: &str) -> Option<(Vec<String>, Option<f32>)> {
+    let color = color_str.trim().to_lowercase();
+    let args = color
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    let (args, alpha) = match args.split_once('/') {
+        Some((args, alpha)) => (args, Some(parse_number(alpha.trim(), 1.0)?)),
+        None => (args, None),
+    };
+    Some((
+        args.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect(),
+        alpha,
+    ))
+}
+
+/// Parses a number that may be written as a percentage, `100%` maps to `percent_ref`.
+/// The CSS keyword `none` stands for a missing component, which is treated as zero.
+pub fn parse_number(s: &str, percent_ref: f32) -> Option<f32> {
+    if s == "none" {
+        return Some(0.0);
+    }
+    match s.strip_suffix('%') {
+        Some(p) => p.parse::<f32>().ok().map(|v| v / 100.0 * percent_ref),
+        None => s.parse::<f32>().ok(),
+    }
+    .filter(|v| v.is_finite())
+}
+
+/// Parses a rgb channel written either as a number(0~255) or as a percentage(0%~100%),
+/// fractional values like `10.5` are rounded.
+pub fn parse_rgb_channel(s: &str) -> Option<u8> {
+    parse_number(s, 255.0)
+        .filter(|v| (0.0..=255.0).contains(v))
+        .map(|v| v.round() as u8)
+}
+
+/// Parses a CSS hue, which is a number of degrees or an angle with one of the units
+/// `deg`, `rad`, `grad` and `turn`. Hues outside 0~360 are wrapped around like CSS does,
+/// and a `none` hue is treated as zero.
+pub fn parse_hue(s: &str) -> Option<f32> {
+    if s == "none" {
+        return Some(0.0);
+    }
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+    let hue = units
+        .iter()
+        .find_map(|(unit, scale)| s.strip_suffix(unit).map(|v| (v, *scale)))
+        .map_or_else(
+            || s.parse::<f32>().ok(),
+            |(v, scale)| v.parse::<f32>().ok().map(|v| v * scale),
+        )
+        .filter(|v| v.is_finite())?;
+    if (0.0..=360.0).contains(&hue) {
+        Some(hue)
+    } else {
+        Some(hue.rem_euclid(360.0))
+    }
+}
+
+/// Parses the `h, s%, l%` arguments shared by `hsl()`, `hsla()` and `hsv()`.
+/// The channels keep their fractions, e.g. "hsl(120.5, 50.5%, 40%)".
+pub fn parse_hsl_args(args: &[String]) -> Option<(f32, f32, f32)> {
+    if args.len() != 3 {
+        return None;
+    }
+    let h = parse_hue(&args[0])?;
+    let s = parse_number(&args[1], 100.0)?;
+    let l = parse_number(&args[2], 100.0)?;
+    Some((h, s, l))
+}
+
+/// Removes the sRGB gamma encoding of a channel(0~1), the result is proportional to the light intensity.
+/// Blending, luminance and resampling math is only correct on linear values.
+/// ```rust
+/// use easy_color::{linear_to_srgb, srgb_to_linear};
+/// assert_eq!(format!("{:.4}", srgb_to_linear(0.5)), "0.2140");
+/// assert_eq!(format!("{:.4}", linear_to_srgb(srgb_to_linear(0.5))), "0.5000");
+/// // the average of black and white in linear light
+/// assert_eq!(format!("{:.4}", linear_to_srgb(0.5)), "0.7354");
+/// ```
+pub fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applies the sRGB gamma encoding to a linear channel(0~1), the inverse of `srgb_to_linear`.
+pub fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub fn a98_to_linear(v: f32) -> f32 {
+    v.signum() * v.abs().powf(563.0 / 256.0)
+}
+
+pub fn linear_to_a98(v: f32) -> f32 {
+    v.signum() * v.abs().powf(256.0 / 563.0)
+}
+
+const REC2020_ALPHA: f32 = 1.0992968;
+const REC2020_BETA: f32 = 0.01805397;
+
+pub fn rec2020_to_linear(v: f32) -> f32 {
+    if v < REC2020_BETA * 4.5 {
+        v / 4.5
+    } else {
+        ((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+    }
+}
+
+pub fn linear_to_rec2020(v: f32) -> f32 {
+    if v < REC2020_BETA {
+        v * 4.5
+    } else {
+        REC2020_ALPHA * v.powf(0.45) - (REC2020_ALPHA - 1.0)
+    }
+}
+
+pub fn prophoto_to_linear(v: f32) -> f32 {
+    if v < 16.0 / 512.0 {
+        v / 16.0
+    } else {
+        v.powf(1.8)
+    }
+}
+
+pub fn linear_to_prophoto(v: f32) -> f32 {
+    if v < 1.0 / 512.0 {
+        v * 16.0
+    } else {
+        v.powf(1.0 / 1.8)
+    }
+}
+
+/// The WCAG relative luminance(0~1) of a sRGB color.
+pub fn relative_luminance(r: u8, g: u8, b: u8) -> f32 {
+    let (r, g, b) = linear_rgb(r, g, b);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// The APCA 0.0.98G screen luminance of a sRGB color, with the soft clamp of near black applied.
+fn apca_luminance(r: u8, g: u8, b: u8) -> f32 {
+    let y = 0.2126729 * (r as f32 / 255.0).powf(2.4)
+        + 0.7151522 * (g as f32 / 255.0).powf(2.4)
+        + 0.0721750 * (b as f32 / 255.0).powf(2.4);
+    if y > 0.022 {
+        y
+    } else {
+        y + (0.022 - y).powf(1.414)
+    }
+}
+
+/// The APCA 0.0.98G lightness contrast(Lc) of text on a background, positive for dark text on a light background
+/// and negative for light text on a dark background.
+pub fn apca_contrast(text: (u8, u8, u8), background: (u8, u8, u8)) -> f32 {
+    let txt = apca_luminance(text.0, text.1, text.2);
+    let bg = apca_luminance(background.0, background.1, background.2);
+    if (bg - txt).abs() < 0.0005 {
+        return 0.0;
+    }
+    let lc = if bg > txt {
+        let sapc = (bg.powf(0.56) - txt.powf(0.57)) * 1.14;
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        let sapc = (bg.powf(0.65) - txt.powf(0.62)) * 1.14;
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+    lc * 100.0
+}
+
+pub fn linear_rgb(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    (
+        srgb_to_linear(r as f32 / 255.0),
+        srgb_to_linear(g as f32 / 255.0),
+        srgb_to_linear(b as f32 / 255.0),
+    )
+}
+
+pub fn linear_to_rgb(r: f32, g: f32, b: f32) -> (u8, u8, u8) {
+    let to_u8 = |v: f32| (linear_to_srgb(v).clamp(0.0, 1.0) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+pub fn linear_rgb_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let l = (0.4122215 * r + 0.5363325 * g + 0.05144599 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.107397 * b).cbrt();
+    let s = (0.08830246 * r + 0.2817188 * g + 0.6299787 * b).cbrt();
+    (
+        0.2104543 * l + 0.7936178 * m - 0.004072047 * s,
+        1.977998 * l - 2.428592 * m + 0.4505937 * s,
+        0.02590404 * l + 0.7827718 * m - 0.8086758 * s,
+    )
+}
+
+pub fn oklab_to_linear_rgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l_ = (l + 0.3963378 * a + 0.2158038 * b).powi(3);
+    let m_ = (l - 0.1055613 * a - 0.06385417 * b).powi(3);
+    let s_ = (l - 0.08948418 * a - 1.291486 * b).powi(3);
+    (
+        4.076742 * l_ - 3.307712 * m_ + 0.2309699 * s_,
+        -1.268438 * l_ + 2.609757 * m_ - 0.3413194 * s_,
+        -0.004196086 * l_ - 0.7034186 * m_ + 1.707615 * s_,
+    )
+}
+
+pub fn rgb_to_oklab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = linear_rgb(r, g, b);
+    linear_rgb_to_oklab(r, g, b)
+}
+
+pub fn oklab_to_rgb(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+    linear_to_rgb(r, g, b)
+}
+
+/// Converts rectangular a/b coordinates of a Lab-like space into chroma and hue(0~360).
+pub fn lab_to_lch(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let c = (a * a + b * b).sqrt();
+    let mut h = b.atan2(a).to_degrees();
+    if h < 0.0 {
+        h += 360.0;
+    }
+    (l, c, h)
+}
+
+pub fn lch_to_lab(l: f32, c: f32, h: f32) -> (f32, f32, f32) {
+    let h = h.to_radians();
+    (l, c * h.cos(), c * h.sin())
+}
+
+/// CIE standard illuminant D65 reference white, with Y normalized to 1.
+pub const D65: (f32, f32, f32) = (0.95047, 1.0, 1.08883);
+
+/// CIE standard illuminant D50 reference white, with Y normalized to 1.
+pub const D50: (f32, f32, f32) = (0.96422, 1.0, 0.82521);
+
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+pub type Matrix3 = [[f32; 3]; 3];
+
+/// Linear sRGB to XYZ(D65) matrix.
+pub const SRGB_TO_XYZ: Matrix3 = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.072175],
+    [0.0193339, 0.119192, 0.9503041],
+];
+
+/// XYZ(D65) to linear sRGB matrix.
+pub const XYZ_TO_SRGB: Matrix3 = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.041556],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+/// Linear Display P3 to XYZ(D65) matrix.
+pub const P3_TO_XYZ: Matrix3 = [
+    [0.4865709, 0.2656677, 0.1982173],
+    [0.2289746, 0.6917385, 0.0792869],
+    [0.0, 0.04511338, 1.043944],
+];
+
+/// XYZ(D65) to linear Display P3 matrix.
+pub const XYZ_TO_P3: Matrix3 = [
+    [2.493497, -0.9313836, -0.4027108],
+    [-0.829489, 1.762664, 0.02362469],
+    [0.03584583, -0.07617239, 0.9568845],
+];
+
+/// Linear Adobe RGB (1998) to XYZ(D65) matrix.
+pub const A98_TO_XYZ: Matrix3 = [
+    [0.576669, 0.1855582, 0.1882286],
+    [0.297345, 0.6273636, 0.0752915],
+    [0.0270314, 0.0706889, 0.9913375],
+];
+
+/// XYZ(D65) to linear Adobe RGB (1998) matrix.
+pub const XYZ_TO_A98: Matrix3 = [
+    [2.041588, -0.565007, -0.3447313],
+    [-0.9692436, 1.875968, 0.04155506],
+    [0.01344428, -0.1183624, 1.015175],
+];
+
+/// Linear Rec.2020 to XYZ(D65) matrix.
+pub const REC2020_TO_XYZ: Matrix3 = [
+    [0.636958, 0.1446169, 0.168881],
+    [0.2627002, 0.6779981, 0.0593017],
+    [0.0, 0.0280727, 1.060985],
+];
+
+/// XYZ(D65) to linear Rec.2020 matrix.
+pub const XYZ_TO_REC2020: Matrix3 = [
+    [1.716651, -0.3556708, -0.2533663],
+    [-0.6666844, 1.616481, 0.01576855],
+    [0.01763986, -0.04277061, 0.9421031],
+];
+
+/// Linear ProPhoto RGB to XYZ(D50) matrix.
+pub const PROPHOTO_TO_XYZ: Matrix3 = [
+    [0.7977605, 0.1351858, 0.03134935],
+    [0.2880711, 0.7118432, 0.00008565396],
+    [0.0, 0.0, 0.8251046],
+];
+
+/// XYZ(D50) to linear ProPhoto RGB matrix.
+pub const XYZ_TO_PROPHOTO: Matrix3 = [
+    [1.345944, -0.2556075, -0.05111118],
+    [-0.5445989, 1.508167, 0.02053386],
+    [0.0, 0.0, 1.211968],
+];
+
+/// Bradford chromatic adaptation matrix from D65 to D50.
+pub const D65_TO_D50: Matrix3 = [
+    [1.04793, 0.02294687, -0.05019227],
+    [0.02962781, 0.9904344, -0.0170738],
+    [-0.009243058, 0.01505519, 0.7518742],
+];
+
+/// Bradford chromatic adaptation matrix from D50 to D65.
+pub const D50_TO_D65: Matrix3 = [
+    [0.9554734, -0.02309845, 0.06325931],
+    [-0.02836971, 1.009995, 0.02104144],
+    [0.01231401, -0.02050769, 1.330366],
+];
+
+pub fn mul_matrix3(m: &Matrix3, v: (f32, f32, f32)) -> (f32, f32, f32) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+pub fn linear_rgb_to_xyz(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    mul_matrix3(&SRGB_TO_XYZ, (r, g, b))
+}
+
+pub fn xyz_to_linear_rgb(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    mul_matrix3(&XYZ_TO_SRGB, (x, y, z))
+}
+
+pub fn rgb_to_xyz(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = linear_rgb(r, g, b);
+    linear_rgb_to_xyz(r, g, b)
+}
+
+pub fn xyz_to_rgb(x: f32, y: f32, z: f32) -> (u8, u8, u8) {
+    let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+    linear_to_rgb(r, g, b)
+}
+
+pub fn xyz_to_lab(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let f = |t: f32| {
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let fx = f(x / D65.0);
+    let fy = f(y / D65.1);
+    let fz = f(z / D65.2);
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+pub fn lab_to_xyz(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let f_inv = |t: f32| {
+        if t.powi(3) > LAB_EPSILON {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) / LAB_KAPPA
+        }
+    };
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        fy.powi(3)
+    } else {
+        l / LAB_KAPPA
+    };
+    (f_inv(fx) * D65.0, y * D65.1, f_inv(fz) * D65.2)
+}
+
+pub fn rgb_to_lab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (x, y, z) = rgb_to_xyz(r, g, b);
+    xyz_to_lab(x, y, z)
+}
+
+pub fn lab_to_rgb(l: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let (x, y, z) = lab_to_xyz(l, a, b);
+    xyz_to_rgb(x, y, z)
+}
+
+pub fn rgb_to_hwb(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+    let (h, _, _) = rgb_to_hsv(r, g, b);
+    let w = r.min(g).min(b) as f32 / 255.0;
+    let bl = 1.0 - r.max(g).max(b) as f32 / 255.0;
+    (
+        h.round() as u32,
+        (w * 100.0).round() as u32,
+        (bl * 100.0).round() as u32,
+    )
+}
+
+pub fn hwb_to_rgb(h: u32, w: u32, b: u32) -> (u8, u8, u8) {
+    let w = w as f32 / 100.0;
+    let b = b as f32 / 100.0;
+    if w + b >= 1.0 {
+        let gray = (w / (w + b) * 255.0).round() as u8;
+        return (gray, gray, gray);
+    }
+    let (r, g, bl) = hsv_to_rgb(h as f32, 100.0, 100.0);
+    let scale = |v: u8| ((v as f32 / 255.0 * (1.0 - w - b) + w) * 255.0).round() as u8;
+    (scale(r), scale(g), scale(bl))
+}
+
+/// Returns the (Kr, Kb) luma coefficients of a YCbCr standard.
+pub fn ycbcr_coefficients(bt709: bool) -> (f32, f32) {
+    if bt709 {
+        (0.2126, 0.0722)
+    } else {
+        (0.299, 0.114)
+    }
+}
+
+pub fn rgb_to_ycbcr(r: u8, g: u8, b: u8, bt709: bool) -> (u8, u8, u8) {
+    let (kr, kb) = ycbcr_coefficients(bt709);
+    let (r, g, b) = (r as f32, g as f32, b as f32);
+    let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+    let cb = 128.0 + (b - y) / (2.0 * (1.0 - kb));
+    let cr = 128.0 + (r - y) / (2.0 * (1.0 - kr));
+    let to_u8 = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+    (to_u8(y), to_u8(cb), to_u8(cr))
+}
+
+pub fn ycbcr_to_rgb(y: u8, cb: u8, cr: u8, bt709: bool) -> (u8, u8, u8) {
+    let (kr, kb) = ycbcr_coefficients(bt709);
+    let kg = 1.0 - kr - kb;
+    let (y, cb, cr) = (y as f32, cb as f32 - 128.0, cr as f32 - 128.0);
+    let r = y + 2.0 * (1.0 - kr) * cr;
+    let b = y + 2.0 * (1.0 - kb) * cb;
+    let g = (y - kr * r - kb * b) / kg;
+    let to_u8 = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+pub fn rgb_to_yuv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let y = 0.299 * r + 0.587 * g + 0.114 * b;
+    (y, 0.492 * (b - y), 0.877 * (r - y))
+}
+
+pub fn yuv_to_rgb(y: f32, u: f32, v: f32) -> (u8, u8, u8) {
+    let r = y + v / 0.877;
+    let b = y + u / 0.492;
+    let g = (y - 0.299 * r - 0.114 * b) / 0.587;
+    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+pub fn rgb_to_yiq(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    (
+        0.299 * r + 0.587 * g + 0.114 * b,
+        0.5959 * r - 0.2746 * g - 0.3213 * b,
+        0.2115 * r - 0.5227 * g + 0.3112 * b,
+    )
+}
+
+pub fn yiq_to_rgb(y: f32, i: f32, q: f32) -> (u8, u8, u8) {
+    let r = y + 0.956 * i + 0.619 * q;
+    let g = y - 0.272 * i - 0.647 * q;
+    let b = y - 1.106 * i + 1.703 * q;
+    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+pub fn rgb_to_hsi(r: u8, g: u8, b: u8) -> (u32, u32, u32) {
+    let r = r as f32 / 255.0;
+    let g = g as f32 / 255.0;
+    let b = b as f32 / 255.0;
+    let i = (r + g + b) / 3.0;
+    let s = if i == 0.0 {
+        0.0
+    } else {
+        1.0 - r.min(g).min(b) / i
+    };
+    let den = ((r - g).powi(2) + (r - b) * (g - b)).sqrt();
+    let mut h = if den == 0.0 {
+        0.0
+    } else {
+        (0.5 * ((r - g) + (r - b)) / den)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees()
+    };
+    if b > g {
+        h = 360.0 - h;
+    }
+    (
+        h.round() as u32 % 360,
+        (s * 100.0).round() as u32,
+        (i * 100.0).round() as u32,
+    )
+}
+
+pub fn hsi_to_rgb(h: u32, s: u32, i: u32) -> (u8, u8, u8) {
+    let s = s as f32 / 100.0;
+    let i = i as f32 / 100.0;
+    let h = (h % 360) as f32;
+    let sector = |h: f32| {
+        let low = i * (1.0 - s);
+        let high = i * (1.0 + s * h.to_radians().cos() / (60.0 - h).to_radians().cos());
+        (high, 3.0 * i - (low + high), low)
+    };
+    let (r, g, b) = if h < 120.0 {
+        sector(h)
+    } else if h < 240.0 {
+        let (g, b, r) = sector(h - 120.0);
+        (r, g, b)
+    } else {
+        let (b, r, g) = sector(h - 240.0);
+        (r, g, b)
+    };
+    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+fn xyz_to_uv_prime(x: f32, y: f32, z: f32) -> (f32, f32) {
+    let den = x + 15.0 * y + 3.0 * z;
+    if den == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (4.0 * x / den, 9.0 * y / den)
+    }
+}
+
+pub fn xyz_to_luv(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let (un, vn) = xyz_to_uv_prime(D65.0, D65.1, D65.2);
+    let yr = y / D65.1;
+    let l = if yr > LAB_EPSILON {
+        116.0 * yr.cbrt() - 16.0
+    } else {
+        LAB_KAPPA * yr
+    };
+    if l == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let (u_, v_) = xyz_to_uv_prime(x, y, z);
+    (l, 13.0 * l * (u_ - un), 13.0 * l * (v_ - vn))
+}
+
+pub fn luv_to_xyz(l: f32, u: f32, v: f32) -> (f32, f32, f32) {
+    if l <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let (un, vn) = xyz_to_uv_prime(D65.0, D65.1, D65.2);
+    let u_ = u / (13.0 * l) + un;
+    let v_ = v / (13.0 * l) + vn;
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / LAB_KAPPA
+    } * D65.1;
+    (
+        y * 9.0 * u_ / (4.0 * v_),
+        y,
+        y * (12.0 - 3.0 * u_ - 20.0 * v_) / (4.0 * v_),
+    )
+}
+
+/// Approximates the color of a blackbody at the given temperature, it is fitted for 1000K~40000K.
+pub fn kelvin_to_rgb(kelvin: f32) -> (u8, u8, u8) {
+    let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.6987 * (t - 60.0).powf(-0.1332048)
+    };
+    let g = if t <= 66.0 {
+        99.4708 * t.ln() - 161.1196
+    } else {
+        288.1222 * (t - 60.0).powf(-0.07551485)
+    };
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.5177 * (t - 10.0).ln() - 305.0448
+    };
+    let to_u8 = |v: f32| v.clamp(0.0, 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+const OK_TOE_K1: f32 = 0.206;
+const OK_TOE_K2: f32 = 0.03;
+const OK_TOE_K3: f32 = (1.0 + OK_TOE_K1) / (1.0 + OK_TOE_K2);
+
+/// Oklab lightness to the Okhsl/Okhsv lightness estimate.
+fn ok_toe(x: f32) -> f32 {
+    let y = OK_TOE_K3 * x - OK_TOE_K1;
+    0.5 * (y + (y * y + 4.0 * OK_TOE_K2 * OK_TOE_K3 * x).sqrt())
+}
+
+fn ok_toe_inv(x: f32) -> f32 {
+    (x * x + OK_TOE_K1 * x) / (OK_TOE_K3 * (x + OK_TOE_K2))
+}
+
+/// Coefficients turning the Oklab a/b direction into the l/m/s cone responses.
+fn ok_lms_direction(a: f32, b: f32) -> (f32, f32, f32) {
+    (
+        0.3963378 * a + 0.2158038 * b,
+        -0.1055613 * a - 0.06385417 * b,
+        -0.08948418 * a - 1.291486 * b,
+    )
+}
+
+/// Rows of the l/m/s to linear sRGB matrix.
+const OK_LMS_TO_RGB: Matrix3 = [
+    [4.076742, -3.307712, 0.2309699],
+    [-1.268438, 2.609757, -0.3413194],
+    [-0.004196086, -0.7034186, 1.707615],
+];
+
+/// Finds the maximum saturation(C/L) inside the sRGB gamut for a normalized Oklab hue direction.
+fn ok_max_saturation(a: f32, b: f32) -> f32 {
+    // the red and green regions meet at pure blue, where f32 rounding can put the hue on either side,
+    // only the green fit is accurate there so it is tested first with a small tolerance
+    let (k, w) = if 1.814441 * a - 1.194453 * b > 1.0 - 1e-5 {
+        (
+            [0.7395651, -0.459544, 0.08285427, 0.1254107, 0.145032],
+            OK_LMS_TO_RGB[1],
+        )
+    } else if -1.881703 * a - 0.8093649 * b > 1.0 {
+        (
+            [1.190863, 1.765767, 0.5966264, 0.755152, 0.5677124],
+            OK_LMS_TO_RGB[0],
+        )
+    } else {
+        (
+            [1.357337, -0.00915799, -1.151302, -0.5055961, 0.00692167],
+            OK_LMS_TO_RGB[2],
+        )
+    };
+    let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+    let (k_l, k_m, k_s) = ok_lms_direction(a, b);
+    let l_ = 1.0 + s * k_l;
+    let m_ = 1.0 + s * k_m;
+    let s_ = 1.0 + s * k_s;
+    let f = w[0] * l_.powi(3) + w[1] * m_.powi(3) + w[2] * s_.powi(3);
+    let f1 = 3.0 * (w[0] * k_l * l_ * l_ + w[1] * k_m * m_ * m_ + w[2] * k_s * s_ * s_);
+    let f2 = 6.0 * (w[0] * k_l * k_l * l_ + w[1] * k_m * k_m * m_ + w[2] * k_s * k_s * s_);
+    s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+/// Finds the lightness and chroma of the most saturated color of a hue inside the sRGB gamut.
+fn ok_find_cusp(a: f32, b: f32) -> (f32, f32) {
+    let s_cusp = ok_max_saturation(a, b);
+    let (r, g, b) = oklab_to_linear_rgb(1.0, s_cusp * a, s_cusp * b);
+    let l_cusp = (1.0 / r.max(g).max(b)).cbrt();
+    (l_cusp, l_cusp * s_cusp)
+}
+
+/// Finds where the line from (l0, 0) to (l1, c1) leaves the sRGB gamut, as the fraction t of the line.
+fn ok_gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32, cusp: (f32, f32)) -> f32 {
+    let (cusp_l, cusp_c) = cusp;
+    if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= 0.0 {
+        return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+    }
+    let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+    let (k_l, k_m, k_s) = ok_lms_direction(a, b);
+    let d_l = l1 - l0;
+    let (l_dt, m_dt, s_dt) = (d_l + c1 * k_l, d_l + c1 * k_m, d_l + c1 * k_s);
+    let l = l0 * (1.0 - t) + t * l1;
+    let c = t * c1;
+    let (l_, m_, s_) = (l + c * k_l, l + c * k_m, l + c * k_s);
+    let lms = [l_.powi(3), m_.powi(3), s_.powi(3)];
+    let lms_dt = [
+        3.0 * l_dt * l_ * l_,
+        3.0 * m_dt * m_ * m_,
+        3.0 * s_dt * s_ * s_,
+    ];
+    let lms_dt2 = [
+        6.0 * l_dt * l_dt * l_,
+        6.0 * m_dt * m_dt * m_,
+        6.0 * s_dt * s_dt * s_,
+    ];
+    let dot = |w: &[f32; 3], v: &[f32; 3]| w[0] * v[0] + w[1] * v[1] + w[2] * v[2];
+    let step = OK_LMS_TO_RGB
+        .iter()
+        .map(|w| {
+            let x = dot(w, &lms) - 1.0;
+            let x1 = dot(w, &lms_dt);
+            let x2 = dot(w, &lms_dt2);
+            let u = x1 / (x1 * x1 - 0.5 * x * x2);
+            if u >= 0.0 {
+                -x * u
+            } else {
+                f32::MAX
+            }
+        })
+        .fold(f32::MAX, f32::min);
+    t + step
+}
+
+fn ok_st(cusp: (f32, f32)) -> (f32, f32) {
+    (cusp.1 / cusp.0, cusp.1 / (1.0 - cusp.0))
+}
+
+/// A smooth approximation of the cusp saturation, used for the middle of the Okhsl saturation scale.
+fn ok_st_mid(a: f32, b: f32) -> (f32, f32) {
+    let s = 0.1151699
+        + 1.0
+            / (7.44779
+                + 4.159012 * b
+                + a * (-2.195573
+                    + 1.751984 * b
+                    + a * (-2.137049 - 10.02301 * b
+                        + a * (-4.248946 + 5.387708 * b + 4.69891 * a))));
+    let t = 0.1123964
+        + 1.0
+            / (1.613203 - 0.6812438 * b
+                + a * (0.4037061
+                    + 0.9014812 * b
+                    + a * (-0.2708794
+                        + 0.6122399 * b
+                        + a * (0.00299215 - 0.4539957 * b - 0.1466187 * a))));
+    (s, t)
+}
+
+/// Returns the chroma at saturation 0, 0.8 and 1 of the Okhsl scale for the given lightness and hue.
+fn ok_cs(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let cusp = ok_find_cusp(a, b);
+    let c_max = ok_gamut_intersection(a, b, l, 1.0, l, cusp);
+    let (s_max, t_max) = ok_st(cusp);
+    let k = c_max / (l * s_max).min((1.0 - l) * t_max);
+    let (s_mid, t_mid) = ok_st_mid(a, b);
+    let c_a = l * s_mid;
+    let c_b = (1.0 - l) * t_mid;
+    let c_mid = 0.9
+        * k
+        * (1.0 / (1.0 / c_a.powi(4) + 1.0 / c_b.powi(4)))
+            .sqrt()
+            .sqrt();
+    let c_a = l * 0.4;
+    let c_b = (1.0 - l) * 0.8;
+    let c_0 = (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt();
+    (c_0, c_mid, c_max)
+}
+
+/// Returns the hue(0~360) and the normalized a/b direction of an Oklab color, None for grays.
+fn ok_hue(a: f32, b: f32) -> Option<(f32, f32, f32)> {
+    let c = (a * a + b * b).sqrt();
+    if c < 1e-6 {
+        return None;
+    }
+    Some((b.atan2(a).to_degrees().rem_euclid(360.0), a / c, b / c))
+}
+
+pub fn rgb_to_okhsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (l, a, b) = rgb_to_oklab(r, g, b);
+    let Some((h, a_, b_)) = ok_hue(a, b) else {
+        return (0.0, 0.0, ok_toe(l).clamp(0.0, 1.0));
+    };
+    let c = (a * a + b * b).sqrt();
+    let (c_0, c_mid, c_max) = ok_cs(l, a_, b_);
+    let s = if c < c_mid {
+        let k_1 = 0.8 * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+        0.8 * c / (k_1 + k_2 * c)
+    } else {
+        let k_1 = 0.2 * c_mid * c_mid * 1.25 * 1.25 / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+        0.8 + 0.2 * (c - c_mid) / (k_1 + k_2 * (c - c_mid))
+    };
+    (h, s.clamp(0.0, 1.0), ok_toe(l).clamp(0.0, 1.0))
+}
+
+pub fn okhsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    if l >= 1.0 {
+        return (255, 255, 255);
+    } else if l <= 0.0 {
+        return (0, 0, 0);
+    }
+    let (b_, a_) = h.to_radians().sin_cos();
+    let l = ok_toe_inv(l);
+    let (c_0, c_mid, c_max) = ok_cs(l, a_, b_);
+    let c = if s < 0.8 {
+        let t = 1.25 * s;
+        let k_1 = 0.8 * c_0;
+        let k_2 = 1.0 - k_1 / c_mid;
+        t * k_1 / (1.0 - k_2 * t)
+    } else {
+        let t = (s - 0.8) / 0.2;
+        let k_1 = 0.2 * c_mid * c_mid * 1.25 * 1.25 / c_0;
+        let k_2 = 1.0 - k_1 / (c_max - c_mid);
+        c_mid + t * k_1 / (1.0 - k_2 * t)
+    };
+    oklab_to_rgb(l, c * a_, c * b_)
+}
+
+/// The lightness scale of the okhsv value for a hue, found by projecting the cusp triangle onto the gamut.
+fn ok_value_scale(l_v: f32, c_v: f32, a: f32, b: f32) -> f32 {
+    let l_vt = ok_toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+    let (r, g, b) = oklab_to_linear_rgb(l_vt, a * c_vt, b * c_vt);
+    (1.0 / r.max(g).max(b).max(0.0)).cbrt()
+}
+
+pub fn rgb_to_okhsv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (l, a, b) = rgb_to_oklab(r, g, b);
+    let Some((h, a_, b_)) = ok_hue(a, b) else {
+        return (0.0, 0.0, ok_toe(l).clamp(0.0, 1.0));
+    };
+    let c = (a * a + b * b).sqrt();
+    let (s_max, t_max) = ok_st(ok_find_cusp(a_, b_));
+    let s_0 = 0.5;
+    let k = 1.0 - s_0 / s_max;
+    let t = t_max / (c + l * t_max);
+    let l_v = t * l;
+    let c_v = t * c;
+    let scale = ok_value_scale(l_v, c_v, a_, b_);
+    let v = ok_toe(l / scale) / l_v;
+    let s = (s_0 + t_max) * c_v / (t_max * s_0 + t_max * k * c_v);
+    (h, s.clamp(0.0, 1.0), v.clamp(0.0, 1.0))
+}
+
+pub fn okhsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
+    if v <= 0.0 {
+        return (0, 0, 0);
+    }
+    let (b_, a_) = h.to_radians().sin_cos();
+    let (s_max, t_max) = ok_st(ok_find_cusp(a_, b_));
+    let s_0 = 0.5;
+    let k = 1.0 - s_0 / s_max;
+    let l_v = 1.0 - s * s_0 / (s_0 + t_max - t_max * k * s);
+    let c_v = s * t_max * s_0 / (s_0 + t_max - t_max * k * s);
+    let l = v * l_v;
+    let c = v * c_v;
+    let l_new = ok_toe_inv(l);
+    let c = c * l_new / l;
+    let scale = ok_value_scale(l_v, c_v, a_, b_);
+    oklab_to_rgb(l_new * scale, c * scale * a_, c * scale * b_)
+}
+
+/// Returns the lines (slope, intercept) bounding the sRGB gamut in the LCHuv chroma plane of the given lightness.
+fn hsluv_bounds(l: f32) -> [(f32, f32); 6] {
+    let sub1 = (l + 16.0).powi(3) / 1560896.0;
+    let sub2 = if sub1 > LAB_EPSILON {
+        sub1
+    } else {
+        l / LAB_KAPPA
+    };
+    let mut bounds = [(0.0, 0.0); 6];
+    for (c, m) in XYZ_TO_SRGB.iter().enumerate() {
+        for t in 0..2 {
+            let t = t as f32;
+            let top1 = (284517.0 * m[0] - 94839.0 * m[2]) * sub2;
+            let top2 =
+                (838422.0 * m[2] + 769860.0 * m[1] + 731718.0 * m[0]) * l * sub2 - 769860.0 * t * l;
+            let bottom = (632260.0 * m[2] - 126452.0 * m[1]) * sub2 + 126452.0 * t;
+            bounds[c * 2 + t as usize] = (top1 / bottom, top2 / bottom);
+        }
+    }
+    bounds
+}
+
+/// The largest chroma of the given lightness and hue inside the sRGB gamut.
+fn hsluv_max_chroma(l: f32, h: f32) -> f32 {
+    let (sin, cos) = h.to_radians().sin_cos();
+    hsluv_bounds(l)
+        .iter()
+        .map(|(slope, intercept)| intercept / (sin - slope * cos))
+        .filter(|length| *length >= 0.0)
+        .fold(f32::MAX, f32::min)
+}
+
+/// The largest chroma of the given lightness that is inside the sRGB gamut for every hue.
+fn hpluv_max_chroma(l: f32) -> f32 {
+    hsluv_bounds(l)
+        .iter()
+        .map(|(slope, intercept)| intercept.abs() / (slope * slope + 1.0).sqrt())
+        .fold(f32::MAX, f32::min)
+}
+
+/// Converts LCHuv into HSLuv (h, s, l), the saturation is the percentage of the largest chroma of the hue.
+pub fn lchuv_to_hsluv(l: f32, c: f32, h: f32) -> (f32, f32, f32) {
+    if !(1e-5..=99.99999).contains(&l) {
+        return (h, 0.0, l.clamp(0.0, 100.0));
+    }
+    (h, (c / hsluv_max_chroma(l, h) * 100.0).min(100.0), l)
+}
+
+pub fn hsluv_to_lchuv(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    if !(1e-5..=99.99999).contains(&l) {
+        return (l.clamp(0.0, 100.0), 0.0, h);
+    }
+    (l, hsluv_max_chroma(l, h) / 100.0 * s, h)
+}
+
+/// Converts LCHuv into HPLuv (h, p, l), the saturation is the percentage of the largest chroma shared by all hues.
+pub fn lchuv_to_hpluv(l: f32, c: f32, h: f32) -> (f32, f32, f32) {
+    if !(1e-5..=99.99999).contains(&l) {
+        return (h, 0.0, l.clamp(0.0, 100.0));
+    }
+    (h, c / hpluv_max_chroma(l) * 100.0, l)
+}
+
+pub fn hpluv_to_lchuv(h: f32, p: f32, l: f32) -> (f32, f32, f32) {
+    if !(1e-5..=99.99999).contains(&l) {
+        return (l.clamp(0.0, 100.0), 0.0, h);
+    }
+    (l, hpluv_max_chroma(l) / 100.0 * p, h)
+}
+
+/// The luminance in cd/m² that the white of SDR content(Y = 1) is mapped to by the HDR color spaces.
+pub const SDR_WHITE_LUMINANCE: f32 = 203.0;
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 128.0;
+const PQ_C3: f64 = 2392.0 / 128.0;
+
+/// The SMPTE ST 2084 perceptual quantizer, encodes a luminance relative to 10000 cd/m² with the given m2 exponent.
+pub fn pq_encode(v: f64, m2: f64) -> f64 {
+    let v = v.max(0.0).powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * v) / (1.0 + PQ_C3 * v)).powf(m2)
+}
+
+pub fn pq_decode(v: f64, m2: f64) -> f64 {
+    let v = v.max(0.0).powf(1.0 / m2);
+    ((v - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * v)).powf(1.0 / PQ_M1)
+}
+
+const JZ_B: f64 = 1.15;
+const JZ_G: f64 = 0.66;
+const JZ_P: f64 = 1.7 * 2523.0 / 32.0;
+const JZ_D: f64 = -0.56;
+const JZ_D0: f64 = 1.6295499532821566e-11;
+
+pub fn xyz_to_jzazbz(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let scale = SDR_WHITE_LUMINANCE as f64 / 10000.0;
+    let (x, y, z) = (x as f64 * scale, y as f64 * scale, z as f64 * scale);
+    let xm = JZ_B * x - (JZ_B - 1.0) * z;
+    let ym = JZ_G * y - (JZ_G - 1.0) * x;
+    let l = pq_encode(0.41478972 * xm + 0.579999 * ym + 0.014648 * z, JZ_P);
+    let m = pq_encode(-0.20151 * xm + 1.120649 * ym + 0.0531008 * z, JZ_P);
+    let s = pq_encode(-0.0166008 * xm + 0.2648 * ym + 0.6684799 * z, JZ_P);
+    let iz = 0.5 * l + 0.5 * m;
+    let az = 3.524 * l - 4.066708 * m + 0.542708 * s;
+    let bz = 0.199076 * l + 1.096799 * m - 1.295875 * s;
+    let jz = (1.0 + JZ_D) * iz / (1.0 + JZ_D * iz) - JZ_D0;
+    (jz as f32, az as f32, bz as f32)
+}
+
+pub fn jzazbz_to_xyz(jz: f32, az: f32, bz: f32) -> (f32, f32, f32) {
+    let (jz, az, bz) = (jz as f64 + JZ_D0, az as f64, bz as f64);
+    let iz = jz / (1.0 + JZ_D - JZ_D * jz);
+    let l = pq_decode(
+        iz + 0.1386050432715393 * az + 0.05804731615611886 * bz,
+        JZ_P,
+    );
+    let m = pq_decode(iz - 0.1386050432715393 * az - 0.0580473161561189 * bz, JZ_P);
+    let s = pq_decode(
+        iz - 0.09601924202631895 * az - 0.8118918960560388 * bz,
+        JZ_P,
+    );
+    let xm = 1.9242264357876067 * l - 1.0047923125953657 * m + 0.037651404030618 * s;
+    let ym = 0.35031676209499907 * l + 0.7264811939316552 * m - 0.06538442294808501 * s;
+    let z = -0.09098281098284752 * l - 0.3127282905230739 * m + 1.5227665613052603 * s;
+    let x = (xm + (JZ_B - 1.0) * z) / JZ_B;
+    let y = (ym + (JZ_G - 1.0) * x) / JZ_G;
+    let scale = 10000.0 / SDR_WHITE_LUMINANCE as f64;
+    ((x * scale) as f32, (y * scale) as f32, (z * scale) as f32)
+}
+
+const ICTCP_M2: f64 = 2523.0 / 4096.0 * 128.0;
+
+/// Converts linear Rec.2020 rgb, where 1 is the SDR white, into BT.2100 ICtCp with the PQ transfer function.
+pub fn linear_rec2020_to_ictcp(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let scale = SDR_WHITE_LUMINANCE as f64 / 10000.0;
+    let (r, g, b) = (r as f64 * scale, g as f64 * scale, b as f64 * scale);
+    let l = pq_encode((1688.0 * r + 2146.0 * g + 262.0 * b) / 4096.0, ICTCP_M2);
+    let m = pq_encode((683.0 * r + 2951.0 * g + 462.0 * b) / 4096.0, ICTCP_M2);
+    let s = pq_encode((99.0 * r + 309.0 * g + 3688.0 * b) / 4096.0, ICTCP_M2);
+    (
+        (0.5 * l + 0.5 * m) as f32,
+        ((6610.0 * l - 13613.0 * m + 7003.0 * s) / 4096.0) as f32,
+        ((17933.0 * l - 17390.0 * m - 543.0 * s) / 4096.0) as f32,
+    )
+}
+
+pub fn ictcp_to_linear_rec2020(i: f32, ct: f32, cp: f32) -> (f32, f32, f32) {
+    let (i, ct, cp) = (i as f64, ct as f64, cp as f64);
+    let l = pq_decode(
+        i + 0.008609037037932761 * ct + 0.11102962500302593 * cp,
+        ICTCP_M2,
+    );
+    let m = pq_decode(
+        i - 0.008609037037932761 * ct - 0.11102962500302593 * cp,
+        ICTCP_M2,
+    );
+    let s = pq_decode(
+        i + 0.5600313357106791 * ct - 0.32062717498731885 * cp,
+        ICTCP_M2,
+    );
+    let scale = 10000.0 / SDR_WHITE_LUMINANCE as f64;
+    (
+        ((3.4366066943330784 * l - 2.5064521186562705 * m + 0.06984542432319149 * s) * scale)
+            as f32,
+        ((-0.7913295555989289 * l + 1.983600451792291 * m - 0.192270896193362 * s) * scale) as f32,
+        ((-0.025949899690592665 * l - 0.09891371471172647 * m + 1.1248636144023192 * s) * scale)
+            as f32,
+    )
+}
+
+/// Converts CIE Lab into DIN99 (DIN 6176) with the default weights kE = kCH = 1.
+pub fn lab_to_din99(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let (sin16, cos16) = 16f32.to_radians().sin_cos();
+    let l99 = 105.51 * (0.0158 * l).ln_1p();
+    let e = a * cos16 + b * sin16;
+    let f = 0.7 * (b * cos16 - a * sin16);
+    let g = e.hypot(f);
+    if g == 0.0 {
+        return (l99, 0.0, 0.0);
+    }
+    let c99 = (0.045 * g).ln_1p() / 0.045;
+    (l99, c99 * e / g, c99 * f / g)
+}
+
+pub fn din99_to_lab(l99: f32, a99: f32, b99: f32) -> (f32, f32, f32) {
+    let (sin16, cos16) = 16f32.to_radians().sin_cos();
+    let l = (l99 / 105.51).exp_m1() / 0.0158;
+    let c99 = a99.hypot(b99);
+    if c99 == 0.0 {
+        return (l, 0.0, 0.0);
+    }
+    let g = (0.045 * c99).exp_m1() / 0.045;
+    let e = g * a99 / c99;
+    let f = g * b99 / c99 / 0.7;
+    (l, e * cos16 - f * sin16, e * sin16 + f * cos16)
+}
+
+fn hunter_lab_k() -> (f32, f32) {
+    let (xn, yn, zn) = D65;
+    (
+        175.0 / 198.04 * (xn + yn) * 100.0,
+        70.0 / 218.11 * (yn + zn) * 100.0,
+    )
+}
+
+/// Converts XYZ(D65) into Hunter Lab.
+pub fn xyz_to_hunter_lab(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+    let (xn, yn, zn) = D65;
+    let (ka, kb) = hunter_lab_k();
+    let yr = y / yn;
+    if yr <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+    let sqrt_yr = yr.sqrt();
+    (
+        100.0 * sqrt_yr,
+        ka * (x / xn - yr) / sqrt_yr,
+        kb * (yr - z / zn) / sqrt_yr,
+    )
+}
+
+pub fn hunter_lab_to_xyz(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let (xn, yn, zn) = D65;
+    let (ka, kb) = hunter_lab_k();
+    let sqrt_yr = l / 100.0;
+    let yr = sqrt_yr * sqrt_yr;
+    (
+        (a / ka * sqrt_yr + yr) * xn,
+        yr * yn,
+        (yr - b / kb * sqrt_yr) * zn,
+    )
+}
+
+/// The reversible YCoCg-R transform, it is lossless on integer rgb values.
+pub fn rgb_to_ycocg(r: u8, g: u8, b: u8) -> (u8, i16, i16) {
+    let (r, g, b) = (r as i16, g as i16, b as i16);
+    let co = r - b;
+    let t = b + (co >> 1);
+    let cg = g - t;
+    let y = t + (cg >> 1);
+    (y as u8, co, cg)
+}
+
+/// The inverse of the YCoCg-R transform, the values are out of 0~255 when the input is not a valid rgb color.
+pub fn ycocg_to_rgb(y: u8, co: i16, cg: i16) -> (i16, i16, i16) {
+    let t = y as i16 - (cg >> 1);
+    let g = cg + t;
+    let b = t - (co >> 1);
+    let r = b + co;
+    (r, g, b)
+}
+
+pub fn parse_number_f64(s: &str, percent_ref: f64) -> Option<f64> {
+    if s == "none" {
+        return Some(0.0);
+    }
+    match s.strip_suffix('%') {
+        Some(p) => p.parse::<f64>().ok().map(|v| v / 100.0 * percent_ref),
+        None => s.parse::<f64>().ok(),
+    }
+    .filter(|v| v.is_finite())
+}
+
+pub type Matrix3F64 = [[f64; 3]; 3];
+
+/// Linear sRGB to XYZ(D65) matrix, written as the exact fractions of CSS Color 4.
+pub const SRGB_TO_XYZ_F64: Matrix3F64 = [
+    [506752.0 / 1228815.0, 87881.0 / 245763.0, 12673.0 / 70218.0],
+    [87098.0 / 409605.0, 175762.0 / 245763.0, 12673.0 / 175545.0],
+    [7918.0 / 409605.0, 87881.0 / 737289.0, 1001167.0 / 1053270.0],
+];
+
+/// XYZ(D65) to linear sRGB matrix, the inverse of `SRGB_TO_XYZ_F64`.
+pub const XYZ_TO_SRGB_F64: Matrix3F64 = [
+    [12831.0 / 3959.0, -329.0 / 214.0, -1974.0 / 3959.0],
+    [
+        -851781.0 / 878810.0,
+        1648619.0 / 878810.0,
+        36519.0 / 878810.0,
+    ],
+    [705.0 / 12673.0, -2585.0 / 12673.0, 705.0 / 667.0],
+];
+
+/// D65 reference white from its chromaticity(0.3127, 0.3290), it is the white of `SRGB_TO_XYZ_F64`.
+pub const D65_F64: (f64, f64, f64) = (0.3127 / 0.329, 1.0, (1.0 - 0.3127 - 0.329) / 0.329);
+
+pub fn mul_matrix3_f64(m: &Matrix3F64, v: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+pub fn srgb_to_linear_f64(v: f64) -> f64 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb_f64(v: f64) -> f64 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub fn xyz_to_lab_f64(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+    let f = |t: f64| {
+        if t > epsilon {
+            t.cbrt()
+        } else {
+            (kappa * t + 16.0) / 116.0
+        }
+    };
+    let fx = f(x / D65_F64.0);
+    let fy = f(y / D65_F64.1);
+    let fz = f(z / D65_F64.2);
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+pub fn lab_to_xyz_f64(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let epsilon = 216.0 / 24389.0;
+    let kappa = 24389.0 / 27.0;
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let f_inv = |t: f64| {
+        if t.powi(3) > epsilon {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) / kappa
+        }
+    };
+    let y = if l > kappa * epsilon {
+        fy.powi(3)
+    } else {
+        l / kappa
+    };
+    (f_inv(fx) * D65_F64.0, y * D65_F64.1, f_inv(fz) * D65_F64.2)
+}
@@ -0,0 +1,78 @@
+use crate::common::{linear_rgb, linear_to_rgb, mul_matrix3, Matrix3};
+use crate::RGBA;
+
+/// The types of color vision deficiency that can be simulated with `ColorVision::simulate_cvd`.
+/// * Protanopia - no working long-wavelength(red) cones
+/// * Deuteranopia - no working medium-wavelength(green) cones
+/// * Tritanopia - no working short-wavelength(blue) cones
+///
+/// The simulation uses the matrices of Machado, Oliveira and Fernandes(2009) in linear sRGB,
+/// a severity below 1 blends the matrix with the identity for anomalous trichromacy.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+const PROTANOPIA: Matrix3 = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+
+const DEUTERANOPIA: Matrix3 = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+
+const TRITANOPIA: Matrix3 = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+impl Deficiency {
+    /// Every simulated deficiency.
+    pub const ALL: [Deficiency; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    fn matrix(&self) -> &'static Matrix3 {
+        match self {
+            Self::Protanopia => &PROTANOPIA,
+            Self::Deuteranopia => &DEUTERANOPIA,
+            Self::Tritanopia => &TRITANOPIA,
+        }
+    }
+
+    /// Returns the color as perceived with the deficiency, `severity` is clamped to 0~1. The alpha is kept.
+    pub(crate) fn simulate(&self, rgba: RGBA, severity: f32) -> RGBA {
+        let severity = severity.clamp(0.0, 1.0);
+        let linear = linear_rgb(rgba.r, rgba.g, rgba.b);
+        let (r, g, b) = mul_matrix3(self.matrix(), linear);
+        let mix = |v: f32, s: f32| s + (v - s) * severity;
+        let (r, g, b) = linear_to_rgb(mix(r, linear.0), mix(g, linear.1), mix(b, linear.2));
+        RGBA::new(r, g, b, rgba.a)
+    }
+
+    /// Daltonizes the color: the information lost in the simulation is shifted to the channels the deficiency
+    /// can still see, as in Fidaner, Lin and Ozguven(2005). The alpha is kept.
+    pub(crate) fn daltonize(&self, rgba: RGBA) -> RGBA {
+        let linear = linear_rgb(rgba.r, rgba.g, rgba.b);
+        let simulated = mul_matrix3(self.matrix(), linear);
+        let error = (
+            linear.0 - simulated.0,
+            linear.1 - simulated.1,
+            linear.2 - simulated.2,
+        );
+        let shift: Matrix3 = match self {
+            Self::Protanopia | Self::Deuteranopia => {
+                [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]]
+            }
+            Self::Tritanopia => [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]],
+        };
+        let (r, g, b) = mul_matrix3(&shift, error);
+        let (r, g, b) = linear_to_rgb(linear.0 + r, linear.1 + g, linear.2 + b);
+        RGBA::new(r, g, b, rgba.a)
+    }
+}
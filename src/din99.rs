@@ -0,0 +1,139 @@
+use crate::common::{lab_to_din99, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Lab, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Din99 is the DIN 6176 color space, a logarithmic reshaping of Lab in which the euclidean distance
+/// is a good color difference, it is used by European industrial color tolerancing specifications.
+/// Din99 can be parsed from a string in the format "din99(l a b)" or from a tuple (l,a,b).
+/// * l:f32 - lightness(0~100)
+/// * a:f32 - green/red axis(about -50~50)
+/// * b:f32 - blue/yellow axis(about -50~50)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Din99, Lab, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let din99:Din99 = rgb.into();
+/// assert_eq!(din99.to_string(), "din99(79.18 -23.57 11.69)");
+///
+/// let din99:Din99 = "din99(79.18 -23.57 11.69)".try_into().unwrap();
+/// let lab:Lab = din99.into();
+/// assert_eq!(lab.to_string(), "lab(70.76 -52.20 18.30)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Din99 {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for Din99 {
+    type Error = ColorError;
+    fn try_from(din99_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(din99_str, "din99") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let a = parse_number(&args[1], 50.0);
+                let b = parse_number(&args[2], 50.0);
+                if let (Some(l), Some(a), Some(b)) = (l, a, b) {
+                    return (l, a, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Din99: {} format error!",
+            din99_str
+        )))
+    }
+}
+
+impl FromStr for Din99 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Din99 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "Din99: args ({},{},{}) value error, the lightness must between 0~100!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Din99 {
+    fn from(hex: Hex) -> Self {
+        let lab: Lab = hex.into();
+        lab.into()
+    }
+}
+
+impl From<RGB> for Din99 {
+    fn from(rgb: RGB) -> Self {
+        let lab: Lab = rgb.into();
+        lab.into()
+    }
+}
+
+impl From<RGBA> for Din99 {
+    fn from(rgba: RGBA) -> Self {
+        let lab: Lab = rgba.into();
+        lab.into()
+    }
+}
+
+impl From<Lab> for Din99 {
+    fn from(lab: Lab) -> Self {
+        let Lab { l, a, b } = lab;
+        let (l, a, b) = lab_to_din99(l, a, b);
+        Self { l, a, b }
+    }
+}
+
+impl Display for Din99 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "din99({:.2} {:.2} {:.2})", self.l, self.a, self.b)
+    }
+}
+
+impl Din99 {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Returns the ΔE99 color difference between two colors, the euclidean distance in DIN99.
+    /// ```rust
+    /// use easy_color::{Din99, RGB};
+    /// let a:RGB = (43, 196, 138).try_into().unwrap();
+    /// let b:RGB = (43, 190, 138).try_into().unwrap();
+    /// let din99:Din99 = a.into();
+    /// assert_eq!(format!("{:.2}", din99.delta_e(b)), "1.80");
+    /// ```
+    pub fn delta_e(&self, other: impl Into<Self>) -> f32 {
+        let other: Din99 = other.into();
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
@@ -0,0 +1,170 @@
+use crate::common::{
+    linear_to_srgb, parse_fn_args, parse_number, srgb_to_linear, P3_TO_XYZ, XYZ_TO_P3,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// DisplayP3 is the wide gamut RGB space of Apple devices, it shares the sRGB transfer function and white point but has wider primaries.
+/// Colors outside of the sRGB gamut are clipped when converted into `RGB`.
+/// DisplayP3 can be parsed from a string in the format "color(display-p3 r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red(0~1), can also be written as a percentage in strings
+/// * g:f32 - green(0~1), can also be written as a percentage in strings
+/// * b:f32 - blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{DisplayP3, RGB};
+/// let rgb:RGB = (255, 0, 0).try_into().unwrap();
+/// let p3:DisplayP3 = rgb.into();
+/// assert_eq!(p3.to_string(), "color(display-p3 0.9176 0.2003 0.1386)");
+///
+/// let p3:DisplayP3 = "color(display-p3 0.3779 0.7576 0.5580)".try_into().unwrap();
+/// let rgb:RGB = p3.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct DisplayP3 {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for DisplayP3 {
+    type Error = ColorError;
+    fn try_from(p3_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(p3_str, "color") {
+            if args.len() == 4 && args[0] == "display-p3" {
+                let r = parse_number(&args[1], 1.0);
+                let g = parse_number(&args[2], 1.0);
+                let b = parse_number(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "DisplayP3: {} format error!",
+            p3_str
+        )))
+    }
+}
+
+impl FromStr for DisplayP3 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for DisplayP3 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "DisplayP3: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for DisplayP3 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for DisplayP3 {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for DisplayP3 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for DisplayP3 {
+    fn from(xyz: XYZ) -> Self {
+        let (r, g, b) = xyz.to_linear_rgb(&XYZ_TO_P3);
+        Self {
+            r: linear_to_srgb(r).clamp(0.0, 1.0),
+            g: linear_to_srgb(g).clamp(0.0, 1.0),
+            b: linear_to_srgb(b).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Display for DisplayP3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color(display-p3 {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl DisplayP3 {
+    /// The matrix converting linear Display P3 into XYZ.
+    pub const TO_XYZ: [[f32; 3]; 3] = P3_TO_XYZ;
+
+    /// The matrix converting XYZ into linear Display P3.
+    pub const FROM_XYZ: [[f32; 3]; 3] = XYZ_TO_P3;
+
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The encoded channels(0~1) as a tuple, `TryFrom<(f32, f32, f32)>` goes the other way.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the channels without the transfer function applied.
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        (
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+use crate::{Cie94Application, DeltaE, Distance, Oklab, RGBA};
+
+/// The way the distance between two colors is measured, see `Palette::nearest`.
+/// * Redmean - the cheap "redmean" approximation in sRGB, see `Distance::distance`
+/// * DeltaE76, DeltaE94 and DeltaE2000 - the CIE color differences in Lab, see `DeltaE`, CIE94 uses the graphic arts weights
+/// * Oklab - the euclidean distance in Oklab
+///
+/// ### example
+/// ```rust
+/// use easy_color::{DistanceMetric, RGB};
+/// let rgb1:RGB = (43, 196, 138).try_into().unwrap();
+/// let rgb2:RGB = (45, 196, 138).try_into().unwrap();
+/// assert_eq!(format!("{:.2}", DistanceMetric::Redmean.distance(rgb1, rgb2)), "2.95");
+/// assert_eq!(format!("{:.2}", DistanceMetric::DeltaE2000.distance(rgb1, rgb2)), "0.09");
+/// assert_eq!(format!("{:.4}", DistanceMetric::Oklab.distance(rgb1, rgb2)), "0.0007");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DistanceMetric {
+    Redmean,
+    DeltaE76,
+    DeltaE94,
+    #[default]
+    DeltaE2000,
+    Oklab,
+}
+
+impl DistanceMetric {
+    /// The distance between two colors. Every metric composites a translucent color onto white first.
+    /// ```rust
+    /// use easy_color::{DistanceMetric, RGB, RGBA};
+    /// let clear:RGBA = (0, 0, 0, 0.0).try_into().unwrap();
+    /// let white:RGB = (255, 255, 255).try_into().unwrap();
+    /// assert_eq!(DistanceMetric::Oklab.distance(clear, white), 0.0);
+    /// ```
+    pub fn distance(&self, color1: impl Into<RGBA>, color2: impl Into<RGBA>) -> f32 {
+        let (color1, color2): (RGBA, RGBA) = (color1.into(), color2.into());
+        match self {
+            Self::Redmean => color1.distance(color2),
+            Self::DeltaE76 => color1.delta_e76(color2),
+            Self::DeltaE94 => color1.delta_e94(color2, Cie94Application::GraphicArts),
+            Self::DeltaE2000 => color1.delta_e2000(color2),
+            Self::Oklab => {
+                let (lab1, lab2): (Oklab, Oklab) = (color1.into(), color2.into());
+                ((lab1.l - lab2.l).powi(2) + (lab1.a - lab2.a).powi(2) + (lab1.b - lab2.b).powi(2))
+                    .sqrt()
+            }
+        }
+    }
+}
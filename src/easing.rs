@@ -0,0 +1,85 @@
+/// Easing maps a linear progress(0~1) to an eased one, like the CSS `transition-timing-function`.
+/// Use it with `Gradient::set_easing` or `ColorMix::mix_eased`, or apply it to `t` before calling `lerp_in`.
+/// `Custom` takes any function or closure that captures nothing.
+/// ### example
+/// ```rust
+/// use easy_color::{Easing, Interpolate, InterpolationSpace, RGB};
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert_eq!(Easing::EaseIn.apply(0.0), 0.0);
+/// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+/// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+/// assert_eq!(Easing::Custom(|t| t * t).apply(0.5), 0.25);
+///
+/// let black:RGB = (0, 0, 0).try_into().unwrap();
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// let t = Easing::EaseInOut.apply(0.25);
+/// assert_eq!(black.lerp_in(InterpolationSpace::Rgb, white, t).to_string(), "rgb(33,33,33)");
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// cubic-bezier(0.25, 0.1, 0.25, 1)
+    Ease,
+    /// cubic-bezier(0.42, 0, 1, 1)
+    EaseIn,
+    /// cubic-bezier(0, 0, 0.58, 1)
+    EaseOut,
+    /// cubic-bezier(0.42, 0, 0.58, 1)
+    EaseInOut,
+    /// A CSS cubic-bezier(x1, y1, x2, y2), x1 and x2 are clamped to 0~1.
+    CubicBezier(f32, f32, f32, f32),
+    Custom(fn(f32) -> f32),
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::CubicBezier(a, b, c, d), Self::CubicBezier(e, f, g, h)) => {
+                (a, b, c, d) == (e, f, g, h)
+            }
+            (Self::Custom(f), Self::Custom(g)) => core::ptr::fn_addr_eq(*f, *g),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl Easing {
+    /// Returns the eased progress of `t`, which is clamped to 0~1.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Self::Linear => t,
+            Self::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t)
+            }
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+/// Solves the x of the curve for `t` with bisection, which is monotonic because x1 and x2 are within 0~1, then returns its y.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, t: f32) -> f32 {
+    let curve = |p1: f32, p2: f32, s: f32| {
+        let u = 1.0 - s;
+        3.0 * u * u * s * p1 + 3.0 * u * s * s * p2 + s * s * s
+    };
+    // the curve always passes through (0,0) and (1,1)
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..32 {
+        let mid = (lo + hi) / 2.0;
+        if curve(x1, x2, mid) < t {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    curve(y1, y2, (lo + hi) / 2.0)
+}
@@ -0,0 +1,68 @@
+//! Conversions between the color types and `egui::Color32`, enabled by the `egui` feature.
+//! `Color32` is defined by the `ecolor` crate and re-exported by egui, so only `ecolor` is pulled in.
+//! `Color32` stores the channels premultiplied by the alpha, so `RGBA` and `Hex` are multiplied on the way in and
+//! divided back on the way out, transparent colors can come back with slightly different channels.
+//! `PremultipliedRGBA` maps to it without conversion.
+//! ```rust
+//! use easy_color::{Hex, PremultipliedRGBA, RGB, RGBA};
+//! use ecolor::Color32;
+//! let color:Color32 = RGB::new(43, 196, 138).into();
+//! assert_eq!(color, Color32::from_rgb(43, 196, 138));
+//!
+//! let rgba:RGBA = (255, 128, 0, 0.5).try_into().unwrap();
+//! let color:Color32 = rgba.into();
+//! assert_eq!(color.to_array(), [128, 64, 0, 128]);
+//! assert_eq!(RGBA::from(color).to_string(), "rgba(255,128,0,0.50)");
+//!
+//! let color:Color32 = rgba.premultiply().into();
+//! assert_eq!(color.to_array(), [128, 64, 0, 128]);
+//! let hex:Hex = Color32::from_rgb(43, 196, 138).into();
+//! assert_eq!(hex.to_string(), "#2BC48A");
+//! ```
+use crate::{Hex, PremultipliedRGBA, RGB, RGBA};
+use ::ecolor::Color32;
+
+impl From<RGB> for Color32 {
+    fn from(rgb: RGB) -> Self {
+        Color32::from_rgb(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl From<RGBA> for Color32 {
+    fn from(rgba: RGBA) -> Self {
+        let [r, g, b, a] = rgba.to_bytes();
+        Color32::from_rgba_unmultiplied(r, g, b, a)
+    }
+}
+
+impl From<Color32> for RGBA {
+    fn from(color: Color32) -> Self {
+        color.to_srgba_unmultiplied().into()
+    }
+}
+
+impl From<Hex> for Color32 {
+    fn from(hex: Hex) -> Self {
+        RGBA::from(hex).into()
+    }
+}
+
+impl From<Color32> for Hex {
+    fn from(color: Color32) -> Self {
+        RGBA::from(color).into()
+    }
+}
+
+impl From<PremultipliedRGBA> for Color32 {
+    fn from(pre: PremultipliedRGBA) -> Self {
+        let [r, g, b, a] = pre.to_array().map(|v| (v * 255.0).round() as u8);
+        Color32::from_rgba_premultiplied(r, g, b, a)
+    }
+}
+
+impl From<Color32> for PremultipliedRGBA {
+    fn from(color: Color32) -> Self {
+        let [r, g, b, a] = color.to_array().map(|v| v as f32 / 255.0);
+        Self { r, g, b, a }
+    }
+}
@@ -0,0 +1,163 @@
+//! The float methods of std which are missing from core, implemented with libm for `no_std` builds.
+// The unit tests link std, whose inherent float methods take precedence over these.
+#[cfg_attr(test, allow(dead_code))]
+pub trait Float: Sized {
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn exp(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+impl Float for f32 {
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+
+    fn cbrt(self) -> f32 {
+        libm::cbrtf(self)
+    }
+
+    fn powf(self, n: f32) -> f32 {
+        libm::powf(self, n)
+    }
+
+    fn powi(self, n: i32) -> f32 {
+        libm::powf(self, n as f32)
+    }
+
+    fn exp(self) -> f32 {
+        libm::expf(self)
+    }
+
+    fn exp_m1(self) -> f32 {
+        libm::expm1f(self)
+    }
+
+    fn ln(self) -> f32 {
+        libm::logf(self)
+    }
+
+    fn ln_1p(self) -> f32 {
+        libm::log1pf(self)
+    }
+
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    fn cos(self) -> f32 {
+        libm::cosf(self)
+    }
+
+    fn sin_cos(self) -> (f32, f32) {
+        libm::sincosf(self)
+    }
+
+    fn acos(self) -> f32 {
+        libm::acosf(self)
+    }
+
+    fn atan2(self, other: f32) -> f32 {
+        libm::atan2f(self, other)
+    }
+
+    fn hypot(self, other: f32) -> f32 {
+        libm::hypotf(self, other)
+    }
+
+    fn rem_euclid(self, rhs: f32) -> f32 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
+
+impl Float for f64 {
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn exp_m1(self) -> f64 {
+        libm::expm1(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn ln_1p(self) -> f64 {
+        libm::log1p(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn sin_cos(self) -> (f64, f64) {
+        libm::sincos(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
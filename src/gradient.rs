@@ -0,0 +1,146 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Easing, InterpolationSpace, RGBA};
+
+/// Gradient samples colors along a list of positioned color stops, like a CSS linear gradient.
+/// Positions are 0~1, the stops are sorted by position and two stops at the same position make a hard edge.
+/// The colors between two stops are interpolated in `InterpolationSpace::Oklab` unless another space is set,
+/// and linearly unless an `Easing` is set.
+/// ### example
+/// ```rust
+/// use easy_color::{Gradient, InterpolationSpace, RGB};
+/// let red:RGB = (255, 0, 0).try_into().unwrap();
+/// let blue:RGB = (0, 0, 255).try_into().unwrap();
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// let mut gradient = Gradient::new([(0.0, red), (0.5, white), (1.0, blue)]).unwrap();
+/// assert_eq!(gradient.at(0.0).to_string(), "rgba(255,0,0,1.00)");
+/// assert_eq!(gradient.at(0.25).to_string(), "rgba(255,161,145,1.00)");
+/// assert_eq!(gradient.at(1.5).to_string(), "rgba(0,0,255,1.00)");
+///
+/// gradient.set_space(InterpolationSpace::Rgb);
+/// assert_eq!(gradient.at(0.25).to_string(), "rgba(255,128,128,1.00)");
+///
+/// let gradient = Gradient::from_colors([red, blue]).unwrap();
+/// assert_eq!(gradient.at(0.5).to_string(), "rgba(140,83,162,1.00)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gradient {
+    stops: Vec<(f32, RGBA)>,
+    space: InterpolationSpace,
+    easing: Easing,
+}
+
+impl Gradient {
+    /// Creates a gradient from `(position, color)` stops, it fails when there is no stop or a position is outside 0~1.
+    pub fn new<C: Into<RGBA>>(
+        stops: impl IntoIterator<Item = (f32, C)>,
+    ) -> Result<Self, ColorError> {
+        let mut stops = stops
+            .into_iter()
+            .map(|(pos, color)| (pos, color.into()))
+            .collect::<Vec<_>>();
+        if stops.is_empty() {
+            return Err(ColorError::ValueErr(
+                "Gradient: a gradient needs at least one color stop!".to_string(),
+            ));
+        }
+        if let Some((pos, _)) = stops.iter().find(|(pos, _)| !(0.0..=1.0).contains(pos)) {
+            return Err(ColorError::ValueErr(format!(
+                "Gradient: stop position {} value error, positions must between 0~1!",
+                pos
+            )));
+        }
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(Self {
+            stops,
+            space: InterpolationSpace::Oklab,
+            easing: Easing::Linear,
+        })
+    }
+
+    /// Creates a gradient with the colors evenly spaced from 0 to 1.
+    pub fn from_colors<C: Into<RGBA>>(
+        colors: impl IntoIterator<Item = C>,
+    ) -> Result<Self, ColorError> {
+        let colors = colors.into_iter().map(Into::into).collect::<Vec<RGBA>>();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, color)),
+        )
+    }
+
+    pub fn stops(&self) -> &[(f32, RGBA)] {
+        &self.stops
+    }
+
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    pub fn set_space(&mut self, space: InterpolationSpace) -> &mut Self {
+        self.space = space;
+        self
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Sets the easing between every two stops, the default is `Easing::Linear`.
+    /// ```rust
+    /// use easy_color::{Easing, Gradient, InterpolationSpace, RGB};
+    /// let black:RGB = (0, 0, 0).try_into().unwrap();
+    /// let white:RGB = (255, 255, 255).try_into().unwrap();
+    /// let mut gradient = Gradient::from_colors([black, white]).unwrap();
+    /// gradient.set_space(InterpolationSpace::Rgb).set_easing(Easing::EaseIn);
+    /// assert_eq!(gradient.at(0.5).to_string(), "rgba(80,80,80,1.00)");
+    /// ```
+    pub fn set_easing(&mut self, easing: Easing) -> &mut Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the color at position `t`, positions before the first stop or after the last one take the color of that stop.
+    /// A NaN position takes the color of the first stop.
+    /// ```rust
+    /// use easy_color::{Gradient, Hex};
+    /// let red:Hex = "#FF0000".try_into().unwrap();
+    /// let blue:Hex = "#0000FF".try_into().unwrap();
+    /// let gradient = Gradient::from_colors([red, blue]).unwrap();
+    /// assert_eq!(Hex::from(gradient.at(f32::NAN)).to_string(), "#FF0000");
+    /// assert_eq!(Hex::from(gradient.at(f32::INFINITY)).to_string(), "#0000FF");
+    /// ```
+    pub fn at(&self, t: f32) -> RGBA {
+        let first = self.stops[0];
+        let last = self.stops[self.stops.len() - 1];
+        if t <= first.0 || t.is_nan() {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+        let i = self.stops.partition_point(|(pos, _)| *pos <= t);
+        let (start, from) = self.stops[i - 1];
+        let (end, to) = self.stops[i];
+        let t = self.easing.apply((t - start) / (end - start));
+        self.space.lerp(from, to, t)
+    }
+
+    /// Yields `n` colors evenly spaced from 0 to 1, both ends included.
+    /// ```rust
+    /// use easy_color::{Gradient, Hex, RGB};
+    /// let black:RGB = (0, 0, 0).try_into().unwrap();
+    /// let white:RGB = (255, 255, 255).try_into().unwrap();
+    /// let mut gradient = Gradient::from_colors([black, white]).unwrap();
+    /// gradient.set_space(easy_color::InterpolationSpace::Rgb);
+    /// let legend = gradient.steps(5).map(|c| Hex::from(c).to_string()).collect::<Vec<_>>();
+    /// assert_eq!(legend, ["#000000", "#404040", "#808080", "#BFBFBF", "#FFFFFF"]);
+    /// ```
+    pub fn steps(&self, n: usize) -> impl Iterator<Item = RGBA> + '_ {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(move |i| self.at(i as f32 / last))
+    }
+}
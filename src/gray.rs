@@ -0,0 +1,130 @@
+use crate::common::{parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Gray is a single channel monochrome color, it is computed from the gamma encoded rgb values with the Rec.709 luma weights.
+/// The value can be used as an u8(0~255) or as a f32(0~1).
+/// Gray can be parsed from a string in the format "gray(v)" or "gray(v%)", from an u8 or from a f32.
+/// * v:u8 - gray level(0~255)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Gray, Hex, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let gray:Gray = rgb.into();
+/// assert_eq!(gray.to_string(), "gray(159)");
+///
+/// let gray:Gray = "gray(50%)".try_into().unwrap();
+/// let hex:Hex = gray.into();
+/// assert_eq!(hex.to_string(), "#808080");
+///
+/// let mut gray:Gray = 0.25.try_into().unwrap();
+/// assert_eq!(gray.value(), 64);
+/// gray.set_intensity(1.0);
+/// assert_eq!(gray.value(), 255);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Gray {
+    pub(crate) v: u8,
+}
+
+impl TryFrom<&str> for Gray {
+    type Error = ColorError;
+    fn try_from(gray_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(gray_str, "gray") {
+            if args.len() == 1 {
+                if let Some(v) = parse_number(&args[0], 255.0) {
+                    if (0.0..=255.0).contains(&v) {
+                        return Ok(Self { v: v.round() as u8 });
+                    }
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Gray: {} format error!",
+            gray_str
+        )))
+    }
+}
+
+impl FromStr for Gray {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl From<u8> for Gray {
+    fn from(v: u8) -> Self {
+        Self { v }
+    }
+}
+
+impl TryFrom<f32> for Gray {
+    type Error = ColorError;
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value) {
+            Err(ColorError::ValueErr(format!(
+                "Gray: arg {} value error, the value must between 0~1!",
+                value
+            )))
+        } else {
+            Ok(Self {
+                v: (value * 255.0).round() as u8,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Gray {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Gray {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let luma = 0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32;
+        Self {
+            v: luma.round().clamp(0.0, 255.0) as u8,
+        }
+    }
+}
+
+impl From<RGBA> for Gray {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for Gray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "gray({})", self.v)
+    }
+}
+
+impl Gray {
+    pub fn value(&self) -> u8 {
+        self.v
+    }
+
+    pub fn set_value(&mut self, value: u8) -> &mut Self {
+        self.v = value;
+        self
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.v as f32 / 255.0
+    }
+
+    pub fn set_intensity(&mut self, intensity: f32) -> &mut Self {
+        self.v = (intensity.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self
+    }
+}
@@ -1,6 +1,14 @@
 use crate::common::process_hex;
-use crate::{ColorError, CMYK, HSL, HSLA, HSV, RGB, RGBA};
-use std::fmt::{Display, Formatter};
+use crate::named::named_color;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, Cam16, ColorError, Din99, DisplayP3, Gray, HPLuv, HSLuv, HunterLab, ICtCp, Jzazbz,
+    Kelvin, LCHuv, LCh, Lab, LinearRGB, Luv, Okhsl, Okhsv, Oklab, Oklch, PackedLayout, ProPhotoRGB,
+    Rec2020, XyY, YCbCr, YCoCg, CMYK, HSI, HSL, HSLA, HSV, HSVA, HWB, RGB, RGBA, XYZ, YIQ, YUV,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 /// Parse a hexadecimal string into a `Hex` object, which can be converted into `RGB`, `RGBA`, `HSL`, `HSLA`, `HSV`, and `CMYK` objects.
 ///  ### example
 ///  ```rust
@@ -8,6 +16,14 @@ use std::fmt::{Display, Formatter};
 ///  let _hex:Hex = "#FAC".try_into().unwrap();
 ///  let _hex:Hex = "#FFDFAC".try_into().unwrap();
 ///  let _hex:Hex = "#FFDFACDC".try_into().unwrap(); // hex with transparency
+///  let hex:Hex = "#f00a".try_into().unwrap(); // shorthand hex with transparency
+///  assert_eq!(hex.to_string(), "#FF0000AA");
+///  let hex:Hex = "Tomato".try_into().unwrap(); // CSS named color
+///  assert_eq!(hex.to_string(), "#FF6347");
+///  let hex:Hex = "transparent".try_into().unwrap();
+///  assert_eq!(hex.to_string(), "#00000000");
+///  assert!(Hex::try_from("#€a").is_err());
+///  assert!(Hex::try_from("#12345€").is_err());
 ///  ```
 ///
 /// Convert hex to other types, such as:
@@ -46,6 +62,14 @@ impl TryFrom<&str> for Hex {
                     });
                 }
             }
+            if len == 4 {
+                let val = process_hex(tmp.as_str(), 1);
+                if val.len() == 4 {
+                    return Ok(Self {
+                        rgba: (val[0], val[1], val[2], val[3] as f32 / 255.0),
+                    });
+                }
+            }
             if len == 6 {
                 let val = process_hex(tmp.as_str(), 2);
                 if val.len() == 3 {
@@ -64,6 +88,16 @@ impl TryFrom<&str> for Hex {
                 }
             }
         }
+        if color == "transparent" {
+            return Ok(Self {
+                rgba: (0, 0, 0, 0.0),
+            });
+        }
+        if let Some((r, g, b)) = named_color(&color) {
+            return Ok(Self {
+                rgba: (r, g, b, 1.0),
+            });
+        }
         Err(ColorError::FormatErr(format!(
             "'{}' format error!",
             hex_str
@@ -71,6 +105,28 @@ impl TryFrom<&str> for Hex {
     }
 }
 
+impl FromStr for Hex {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32, f32)> for Hex {
+    type Error = ColorError;
+    /// Channels and alpha are normalized to 0~1, as used by shaders and GPU APIs.
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        RGBA::try_from(value).map(Self::from)
+    }
+}
+
+impl From<u32> for Hex {
+    /// Unpacks a 0xRRGGBB integer, the highest byte is ignored.
+    fn from(value: u32) -> Self {
+        Self::from_u32_with(value, PackedLayout::Rgb)
+    }
+}
+
 impl From<RGB> for Hex {
     fn from(rgb: RGB) -> Self {
         Self {
@@ -97,6 +153,12 @@ impl From<HSLA> for Hex {
         rgba.into()
     }
 }
+impl From<HSVA> for Hex {
+    fn from(hsva: HSVA) -> Self {
+        let rgba: RGBA = hsva.into();
+        rgba.into()
+    }
+}
 impl From<HSV> for Hex {
     fn from(hsv: HSV) -> Self {
         let rgb: RGB = hsv.into();
@@ -110,8 +172,218 @@ impl From<CMYK> for Hex {
     }
 }
 
+impl From<Oklab> for Hex {
+    fn from(oklab: Oklab) -> Self {
+        let rgb: RGB = oklab.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklch> for Hex {
+    fn from(oklch: Oklch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for Hex {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl From<LCh> for Hex {
+    fn from(lch: LCh) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for Hex {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        rgb.into()
+    }
+}
+
+impl From<XyY> for Hex {
+    fn from(xyy: XyY) -> Self {
+        let rgb: RGB = xyy.into();
+        rgb.into()
+    }
+}
+
+impl From<HWB> for Hex {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<YCbCr> for Hex {
+    fn from(ycbcr: YCbCr) -> Self {
+        let rgb: RGB = ycbcr.into();
+        rgb.into()
+    }
+}
+
+impl From<YUV> for Hex {
+    fn from(yuv: YUV) -> Self {
+        let rgb: RGB = yuv.into();
+        rgb.into()
+    }
+}
+
+impl From<YIQ> for Hex {
+    fn from(yiq: YIQ) -> Self {
+        let rgb: RGB = yiq.into();
+        rgb.into()
+    }
+}
+
+impl From<HSI> for Hex {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        rgb.into()
+    }
+}
+
+impl From<Luv> for Hex {
+    fn from(luv: Luv) -> Self {
+        let rgb: RGB = luv.into();
+        rgb.into()
+    }
+}
+
+impl From<LCHuv> for Hex {
+    fn from(lchuv: LCHuv) -> Self {
+        let rgb: RGB = lchuv.into();
+        rgb.into()
+    }
+}
+
+impl From<Cam16> for Hex {
+    fn from(cam16: Cam16) -> Self {
+        let rgb: RGB = cam16.into();
+        rgb.into()
+    }
+}
+
+impl From<LinearRGB> for Hex {
+    fn from(linear: LinearRGB) -> Self {
+        let rgb: RGB = linear.into();
+        rgb.into()
+    }
+}
+
+impl From<DisplayP3> for Hex {
+    fn from(p3: DisplayP3) -> Self {
+        let rgb: RGB = p3.into();
+        rgb.into()
+    }
+}
+
+impl From<AdobeRGB> for Hex {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<Rec2020> for Hex {
+    fn from(rec2020: Rec2020) -> Self {
+        let rgb: RGB = rec2020.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for Hex {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
+impl From<Gray> for Hex {
+    fn from(gray: Gray) -> Self {
+        let rgb: RGB = gray.into();
+        rgb.into()
+    }
+}
+
+impl From<Kelvin> for Hex {
+    fn from(kelvin: Kelvin) -> Self {
+        let rgb: RGB = kelvin.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsl> for Hex {
+    fn from(okhsl: Okhsl) -> Self {
+        let rgb: RGB = okhsl.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsv> for Hex {
+    fn from(okhsv: Okhsv) -> Self {
+        let rgb: RGB = okhsv.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLuv> for Hex {
+    fn from(hsluv: HSLuv) -> Self {
+        let rgb: RGB = hsluv.into();
+        rgb.into()
+    }
+}
+
+impl From<HPLuv> for Hex {
+    fn from(hpluv: HPLuv) -> Self {
+        let rgb: RGB = hpluv.into();
+        rgb.into()
+    }
+}
+
+impl From<Jzazbz> for Hex {
+    fn from(jzazbz: Jzazbz) -> Self {
+        let rgb: RGB = jzazbz.into();
+        rgb.into()
+    }
+}
+
+impl From<ICtCp> for Hex {
+    fn from(ictcp: ICtCp) -> Self {
+        let rgb: RGB = ictcp.into();
+        rgb.into()
+    }
+}
+
+impl From<Din99> for Hex {
+    fn from(din99: Din99) -> Self {
+        let rgb: RGB = din99.into();
+        rgb.into()
+    }
+}
+
+impl From<HunterLab> for Hex {
+    fn from(lab: HunterLab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl From<YCoCg> for Hex {
+    fn from(ycocg: YCoCg) -> Self {
+        let rgb: RGB = ycocg.into();
+        rgb.into()
+    }
+}
+
 impl Display for Hex {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let (r, g, b, a) = self.rgba;
         if self.rgba.3 != 1.0 {
             write!(f, "#{:02X}{:02X}{:02X}{:02X}", r, g, b, (a * 255.0) as u8)
@@ -122,6 +394,67 @@ impl Display for Hex {
 }
 
 impl Hex {
+    /// Parses a `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA` literal in a const context,
+    /// a malformed literal panics, which fails the build when it is evaluated at compile time.
+    /// The `hex!` macro is a shorthand for it.
+    /// ```rust
+    /// use easy_color::Hex;
+    /// const PRIMARY: Hex = Hex::from_literal("#1a2b3c");
+    /// assert_eq!(PRIMARY.to_string(), "#1A2B3C");
+    /// ```
+    pub const fn from_literal(literal: &str) -> Self {
+        let bytes = literal.as_bytes();
+        assert!(
+            !bytes.is_empty() && bytes[0] == b'#',
+            "hex literal must start with '#'"
+        );
+        let len = bytes.len() - 1;
+        assert!(
+            matches!(len, 3 | 4 | 6 | 8),
+            "hex literal must have 3, 4, 6 or 8 digits"
+        );
+        // the shorthand forms use one digit per channel
+        let short = len < 6;
+        let count = if short { len } else { len / 2 };
+        let mut channels = [255u8; 4];
+        let mut i = 0;
+        while i < count {
+            channels[i] = if short {
+                hex_digit(bytes[1 + i]) * 17
+            } else {
+                hex_digit(bytes[1 + 2 * i]) * 16 + hex_digit(bytes[2 + 2 * i])
+            };
+            i += 1;
+        }
+        Self {
+            rgba: (
+                channels[0],
+                channels[1],
+                channels[2],
+                channels[3] as f32 / 255.0,
+            ),
+        }
+    }
+
+    /// Returns the transparency, 8-digit hex keeps it through the conversion to and from `RGBA`.
+    /// ```rust
+    /// use easy_color::{Hex, RGBA};
+    /// let hex:Hex = "#2BC48A80".try_into().unwrap();
+    /// let rgba:RGBA = hex.into();
+    /// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+    /// let hex:Hex = rgba.into();
+    /// assert_eq!(hex.to_string(), "#2BC48A80");
+    /// assert_eq!(format!("{:.2}", hex.alpha()), "0.50");
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        self.rgba.3
+    }
+
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.rgba.3 = alpha.clamp(0.0, 1.0);
+        self
+    }
+
     /// Returns a Hex string with transparency, where the last two characters represent the transparency in hexadecimal.
     /// ```rust
     /// use easy_color::{RGBA, Hex};
@@ -154,8 +487,89 @@ impl Hex {
         format!("#{:02X}{:02X}{:02X}{:02X}", (a * 255.0) as u8, r, g, b)
     }
 
+    /// Unpacks a color from a `u32` with the given channel layout, `Rgb` colors are opaque.
+    /// ```rust
+    /// use easy_color::{Hex, PackedLayout};
+    /// let hex:Hex = 0x2BC48A.into();
+    /// assert_eq!(hex.to_string(), "#2BC48A");
+    /// assert_eq!(hex.to_u32(), 0x2BC48A);
+    /// let hex = Hex::from_u32_with(0x2BC48A80, PackedLayout::Rgba);
+    /// assert_eq!(hex.to_string(), "#2BC48A80");
+    /// assert_eq!(hex.to_u32_with(PackedLayout::Argb), 0x802BC48A);
+    /// ```
+    pub fn from_u32_with(value: u32, layout: PackedLayout) -> Self {
+        Self {
+            rgba: layout.unpack(value),
+        }
+    }
+
+    /// The channels and alpha normalized to 0~1, as used by shaders and GPU APIs.
+    /// ```rust
+    /// use easy_color::Hex;
+    /// let hex:Hex = (1.0, 0.0, 0.0, 1.0).try_into().unwrap();
+    /// assert_eq!(hex.to_string(), "#FF0000");
+    /// assert_eq!(hex.to_f32_tuple(), (1.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b, a) = self.rgba;
+        (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a)
+    }
+
+    /// Packs the color into a 0xRRGGBB integer, the alpha is dropped.
+    pub fn to_u32(&self) -> u32 {
+        self.to_u32_with(PackedLayout::Rgb)
+    }
+
+    /// Packs the color into a `u32` with the given channel layout.
+    pub fn to_u32_with(&self, layout: PackedLayout) -> u32 {
+        layout.pack(self.rgba)
+    }
+
+    /// Rotates the hue by the given degrees in HSL and keeps the alpha.
+    /// ```rust
+    /// use easy_color::Hex;
+    /// let hex:Hex = "#2BC48A".try_into().unwrap();
+    /// assert_eq!(hex.rotate_hue(180).to_string(), "#C42B65");
+    /// ```
+    pub fn rotate_hue(&self, degrees: i32) -> Self {
+        let rgba: RGBA = (*self).into();
+        rgba.rotate_hue(degrees).into()
+    }
+
     // pub fn random() -> Self {
     //     let rgba = RGBA::random();
     //     rgba.into()
     // }
 }
+
+const fn hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("hex literal contains an invalid digit"),
+    }
+}
+
+/// Builds a `Hex` from a literal at compile time, a malformed literal fails the build.
+/// ```rust
+/// use easy_color::{hex, Hex, RGB};
+/// const PRIMARY: Hex = hex!("#1a2b3c");
+/// const OVERLAY: Hex = hex!("#0008");
+/// assert_eq!(PRIMARY.to_string(), "#1A2B3C");
+/// assert_eq!(OVERLAY.to_string(), "#00000088");
+/// let rgb:RGB = hex!("#2BC48A").into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+///
+/// ```compile_fail
+/// use easy_color::{hex, Hex};
+/// const BROKEN: Hex = hex!("#12345");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($literal:literal) => {{
+        const HEX: $crate::Hex = $crate::Hex::from_literal($literal);
+        HEX
+    }};
+}
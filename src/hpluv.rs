@@ -0,0 +1,152 @@
+use crate::common::{lchuv_to_hpluv, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, LCHuv, HSL, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// HPLuv is the pastel variant of `HSLuv`, its saturation is the percentage of the largest chroma that every hue
+/// can reach in sRGB at that lightness, so colors with the same saturation have the same chroma but can't be fully saturated.
+/// Colors outside of the pastel range have a saturation over 100.
+/// HPLuv can be parsed from a string in the format "hpluv(h p% l%)" or from a tuple (h,p,l).
+/// * h:f32 - hue(0~360)
+/// * p:f32 - saturation(0~100, more for the colors outside of the pastel range)
+/// * l:f32 - lightness(0~100)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{HPLuv, HSL, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let hpluv:HPLuv = rgb.into();
+/// assert_eq!(hpluv.to_string(), "hpluv(149.19 118.88% 70.75%)");
+///
+/// let mut hpluv:HPLuv = "hpluv(149.19 118.88% 70.75%)".try_into().unwrap();
+/// let rgb:RGB = hpluv.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// hpluv.set_hue(40.0);
+/// let hsl:HSL = hpluv.into();
+/// assert_eq!(hsl.to_string(), "hsl(26,67%,65%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HPLuv {
+    pub h: f32,
+    pub p: f32,
+    pub l: f32,
+}
+
+impl TryFrom<&str> for HPLuv {
+    type Error = ColorError;
+    fn try_from(hpluv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(hpluv_str, "hpluv") {
+            if args.len() == 3 {
+                let h = parse_number(&args[0], 360.0);
+                let p = parse_number(&args[1], 100.0);
+                let l = parse_number(&args[2], 100.0);
+                if let (Some(h), Some(p), Some(l)) = (h, p, l) {
+                    return (h, p, l).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "HPLuv: {} format error!",
+            hpluv_str
+        )))
+    }
+}
+
+impl FromStr for HPLuv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for HPLuv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..).contains(&value.1)
+            || !(0.0..=100.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("HPLuv: args ({},{},{}) value error, the hue must between 0~360, the saturation must not be negative and the lightness must between 0~100!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                p: value.1,
+                l: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HPLuv {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HPLuv {
+    fn from(rgb: RGB) -> Self {
+        let lchuv: LCHuv = rgb.into();
+        lchuv.into()
+    }
+}
+
+impl From<RGBA> for HPLuv {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for HPLuv {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<LCHuv> for HPLuv {
+    fn from(lchuv: LCHuv) -> Self {
+        let LCHuv { l, c, h } = lchuv;
+        let (h, p, l) = lchuv_to_hpluv(l, c, h);
+        Self { h, p, l }
+    }
+}
+
+impl Display for HPLuv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "hpluv({:.2} {:.2}% {:.2}%)", self.h, self.p, self.l)
+    }
+}
+
+impl HPLuv {
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.p
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) -> &mut Self {
+        self.p = saturation.max(0.0);
+        self
+    }
+
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+}
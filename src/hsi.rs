@@ -0,0 +1,164 @@
+use crate::common::{parse_fn_args, rgb_to_hsi};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// HSI can be parsed from a string in the format "hsi(h, s%, i%)" or from a tuple (h,s,i).
+/// * h:u32 - Hue(0~360)
+/// * s:u32 - saturation(0~100)
+/// * i:u32 - intensity, the average of the channels(0~100)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, HSI, HSV};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let mut hsi:HSI = rgb.into();
+/// assert_eq!(hsi.to_string(), "hsi(158,66%,49%)");
+///
+/// hsi.set_intensity(30);
+/// let rgb:RGB = hsi.into();
+/// assert_eq!(rgb.to_string(), "rgb(26,119,84)");
+///
+/// let hsv:HSV = hsi.into();
+/// assert_eq!(hsv.to_string(), "hsv(157,78%,47%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HSI {
+    pub(crate) h: u32,
+    pub(crate) s: u32,
+    pub(crate) i: u32,
+}
+
+impl TryFrom<&str> for HSI {
+    type Error = ColorError;
+    fn try_from(hsi_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(hsi_str, "hsi") {
+            if args.len() == 3 {
+                let val = args
+                    .iter()
+                    .map(|s| s.trim_end_matches('%').parse::<u32>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "HSI: {} format error!",
+            hsi_str
+        )))
+    }
+}
+
+impl FromStr for HSI {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(u32, u32, u32)> for HSI {
+    type Error = ColorError;
+    fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
+        if !(0..=360).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("HSI: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                i: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HSI {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HSI {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, i) = rgb_to_hsi(r, g, b);
+        Self { h, s, i }
+    }
+}
+
+impl From<RGBA> for HSI {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for HSI {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for HSI {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for HSI {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for HSI {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl Display for HSI {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "hsi({},{}%,{}%)", self.h, self.s, self.i)
+    }
+}
+
+impl HSI {
+    pub fn hue(&self) -> u32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: u32) -> &mut Self {
+        self.h = hue.min(360);
+        self
+    }
+
+    pub fn saturation(&self) -> u32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: u32) -> &mut Self {
+        self.s = saturation.min(100);
+        self
+    }
+
+    pub fn intensity(&self) -> u32 {
+        self.i
+    }
+
+    pub fn set_intensity(&mut self, intensity: u32) -> &mut Self {
+        self.i = intensity.min(100);
+        self
+    }
+}
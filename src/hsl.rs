@@ -1,12 +1,18 @@
-use crate::common::rgb_to_hsl;
-use crate::{ColorError, Hex, CMYK, HSLA, HSV, RGB, RGBA};
+use crate::common::{parse_fn_args_alpha, parse_hsl_args, rgb_to_hsl};
+use crate::{AdobeRGB, ColorError, HPLuv, HSLuv, Hex, LCh, Lab, Okhsl, Okhsv, Oklab, Oklch, ProPhotoRGB, CMYK, HSI, HSLA, HSV, HWB, RGB, RGBA, XYZ};
 // use rand::Rng;
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 
 /// HSL can be parsed from a string in the format "hsl(h, s%, l%)" or from a tuple (h,s,l).
-/// * h:u32 - Hue(0~360)
-/// * s:u32 - saturation(0~100)
-/// * l:u32 - lightness(0~100)
+/// * h:f32 - Hue(0~360)
+/// * s:f32 - saturation(0~100)
+/// * l:f32 - lightness(0~100)
+///
+/// The channels are stored as floats so converting from RGB and back gives the same color,
+/// the accessors and `Display` round them to integers.
 /// ### example
 /// ```rust
 /// use easy_color::{RGB, HSL};
@@ -14,33 +20,51 @@ use std::fmt::{Display, Formatter};
 /// hsl.set_lightness(50);
 /// assert_eq!(hsl.to_string(), "hsl(262,85%,50%)");
 ///
+/// let hsl:HSL = "hsl(262 85% 79%)".try_into().unwrap();
+/// assert_eq!(hsl.to_string(), "hsl(262,85%,79%)");
+///
+/// // the hue can have a CSS angle unit
+/// let hsl:HSL = "hsl(0.5turn 100% 50%)".try_into().unwrap();
+/// assert_eq!(hsl.to_string(), "hsl(180,100%,50%)");
+/// let hsl:HSL = "hsl(3.14rad, 100%, 50%)".try_into().unwrap();
+/// assert_eq!(hsl.to_string(), "hsl(180,100%,50%)");
+/// let hsl:HSL = "hsl(-90deg 100% 50%)".try_into().unwrap();
+/// assert_eq!(hsl.to_string(), "hsl(270,100%,50%)");
+///
+/// // the channels keep their fractions
+/// let hsl:HSL = "hsl(120.5, 50.5%, 40%)".try_into().unwrap();
+/// assert_eq!((hsl.h, hsl.s, hsl.l), (120.5, 50.5, 40.0));
+///
+/// // every type implements `FromStr` as well
+/// let hsl = "hsl(1,2%,3%)".parse::<HSL>().unwrap();
+/// assert_eq!(hsl.to_string(), "hsl(1,2%,3%)");
+///
 /// let hsl:HSL = (125,60,75).try_into().unwrap();
 /// let rgb:RGB = hsl.into();
-/// assert_eq!(rgb.to_string(), "rgb(153,229,159)")
+/// assert_eq!(rgb.to_string(), "rgb(153,230,159)");
+///
+/// // round-trips through HSL don't drift
+/// let rgb = RGB::new(18, 52, 86);
+/// let hsl:HSL = rgb.into();
+/// assert_eq!(hsl.to_string(), "hsl(210,65%,20%)");
+/// assert_eq!(RGB::from(hsl), rgb);
 /// ```
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct HSL {
-  pub h: u32,
-  pub s: u32,
-  pub l: u32,
+  pub h: f32,
+  pub s: f32,
+  pub l: f32,
 }
 
 impl TryFrom<&str> for HSL {
   type Error = ColorError;
   fn try_from(hsl_str: &str) -> Result<Self, Self::Error> {
-    let mut color = hsl_str.trim().to_lowercase();
-    if color.starts_with("hsl(") && color.ends_with(')') {
-      color = color.replace("hsl(", "").replace(')', "");
-      let tmp = color.split(',').collect::<Vec<_>>();
-      if tmp.len() == 3 {
-        let val = tmp
-          .iter()
-          .map(|s| s.trim().trim_end_matches('%').parse::<u32>())
-          .filter_map(|v| v.ok())
-          .collect::<Vec<_>>();
-        if val.len() == 3 {
-          return (val[0], val[1], val[2]).try_into();
-        }
+    if let Some((args, alpha)) = parse_fn_args_alpha(hsl_str, "hsl") {
+      if let Some((h, s, l)) = parse_hsl_args(&args) {
+        return match alpha {
+          Some(a) => HSLA::try_from((h, s, l, a)).map(HSL::from),
+          None => (h, s, l).try_into(),
+        };
       }
     }
     Err(ColorError::FormatErr(format!(
@@ -50,6 +74,13 @@ impl TryFrom<&str> for HSL {
   }
 }
 
+impl FromStr for HSL {
+  type Err = ColorError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s)
+  }
+}
+
 impl TryFrom<(u32, u32, u32)> for HSL {
   type Error = ColorError;
   fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
@@ -59,11 +90,21 @@ impl TryFrom<(u32, u32, u32)> for HSL {
     {
       Err(ColorError::ValueErr(format!("HSL: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
     } else {
-      Ok(Self {
-        h: value.0,
-        s: value.1,
-        l: value.2,
-      })
+      Ok(Self::new_unchecked(value.0, value.1, value.2))
+    }
+  }
+}
+
+impl TryFrom<(f32, f32, f32)> for HSL {
+  type Error = ColorError;
+  fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+    if !(0.0..=360.0).contains(&value.0)
+      || !(0.0..=100.0).contains(&value.1)
+      || !(0.0..=100.0).contains(&value.2)
+    {
+      Err(ColorError::ValueErr(format!("HSL: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
+    } else {
+      Ok(Self { h: value.0, s: value.1, l: value.2 })
     }
   }
 }
@@ -77,9 +118,7 @@ impl From<Hex> for HSL {
 
 impl From<RGB> for HSL {
   fn from(rgb: RGB) -> Self {
-    let RGB { r, g, b } = rgb;
-    let (h, s, l) = rgb_to_hsl(r, g, b);
-    Self { h, s, l }
+    Self::from_rgb(rgb)
   }
 }
 
@@ -111,6 +150,83 @@ impl From<CMYK> for HSL {
   }
 }
 
+impl From<Oklab> for HSL {
+  fn from(oklab: Oklab) -> Self {
+    let rgb: RGB = oklab.into();
+    rgb.into()
+  }
+}
+
+impl From<Oklch> for HSL {
+  fn from(oklch: Oklch) -> Self {
+    let rgb: RGB = oklch.into();
+    rgb.into()
+  }
+}
+
+impl From<Lab> for HSL {
+  fn from(lab: Lab) -> Self {
+    let rgb: RGB = lab.into();
+    rgb.into()
+  }
+}
+
+impl From<LCh> for HSL {
+  fn from(lch: LCh) -> Self {
+    let rgb: RGB = lch.into();
+    rgb.into()
+  }
+}
+
+impl From<XYZ> for HSL {
+  fn from(xyz: XYZ) -> Self {
+    let rgb: RGB = xyz.into();
+    rgb.into()
+  }
+}
+
+impl From<HWB> for HSL {
+  fn from(hwb: HWB) -> Self {
+    let rgb: RGB = hwb.into();
+    rgb.into()
+  }
+}
+
+impl From<HSI> for HSL {
+  fn from(hsi: HSI) -> Self {
+    let rgb: RGB = hsi.into();
+    rgb.into()
+  }
+}
+
+impl From<Okhsl> for HSL {
+  fn from(okhsl: Okhsl) -> Self {
+    let rgb: RGB = okhsl.into();
+    rgb.into()
+  }
+}
+
+impl From<Okhsv> for HSL {
+  fn from(okhsv: Okhsv) -> Self {
+    let rgb: RGB = okhsv.into();
+    rgb.into()
+  }
+}
+
+impl From<HSLuv> for HSL {
+  fn from(hsluv: HSLuv) -> Self {
+    let rgb: RGB = hsluv.into();
+    rgb.into()
+  }
+}
+
+impl From<HPLuv> for HSL {
+  fn from(hpluv: HPLuv) -> Self {
+    let rgb: RGB = hpluv.into();
+    rgb.into()
+  }
+}
+
 /*impl From<(u32, u32, u32)> for HSL {
   fn from(value: (u32, u32, u32)) -> Self {
     if !(0..=360).contains(&value.0)
@@ -129,37 +245,73 @@ impl From<CMYK> for HSL {
   }
 }
 */
+
+impl From<AdobeRGB> for HSL {
+  fn from(adobe: AdobeRGB) -> Self {
+    let rgb: RGB = adobe.into();
+    rgb.into()
+  }
+}
+
+impl From<ProPhotoRGB> for HSL {
+  fn from(prophoto: ProPhotoRGB) -> Self {
+    let rgb: RGB = prophoto.into();
+    rgb.into()
+  }
+}
+
 impl Display for HSL {
-  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-    write!(f, "hsl({},{}%,{}%)", self.h, self.s, self.l)
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "hsl({},{}%,{}%)", self.hue(), self.saturation(), self.lightness())
   }
 }
 
 impl HSL {
+  /// Creates a color in a const context, the values are clamped like the setters do.
+  /// ```rust
+  /// use easy_color::{HSL, RGB};
+  /// const ACCENT: HSL = HSL::from_rgb(RGB::new(43, 196, 138));
+  /// assert_eq!(ACCENT.to_string(), "hsl(157,64%,47%)");
+  /// assert_eq!(HSL::new(400, 50, 120).to_string(), "hsl(360,50%,100%)");
+  /// ```
+  pub const fn new(hue: u32, saturation: u32, lightness: u32) -> Self {
+    Self::new_unchecked(
+      if hue > 360 { 360 } else { hue },
+      if saturation > 100 { 100 } else { saturation },
+      if lightness > 100 { 100 } else { lightness },
+    )
+  }
+
+  /// The const version of `From<RGB>`.
+  pub const fn from_rgb(rgb: RGB) -> Self {
+    let (h, s, l) = rgb_to_hsl(rgb.r, rgb.g, rgb.b);
+    Self { h, s, l }
+  }
+
   pub fn hue(&self) -> u32 {
-    self.h
+    self.h.round() as u32
   }
 
   pub fn set_hue(&mut self, hue: u32) -> &mut Self {
-    self.h = hue.min(360);
+    self.h = hue.min(360) as f32;
     self
   }
 
   pub fn saturation(&self) -> u32 {
-    self.s
+    self.s.round() as u32
   }
 
   pub fn set_saturation(&mut self, saturation: u32) -> &mut Self {
-    self.s = saturation.min(100);
+    self.s = saturation.min(100) as f32;
     self
   }
 
   pub fn lightness(&self) -> u32 {
-    self.l
+    self.l.round() as u32
   }
 
   pub fn set_lightness(&mut self, lightness: u32) -> &mut Self {
-    self.l = lightness.min(100);
+    self.l = lightness.min(100) as f32;
     self
   }
 
@@ -178,7 +330,7 @@ impl HSL {
   /// assert_eq!(color.to_string(), "hsl(120,100%,40%)");
   /// ```
   pub fn darken(&mut self, ratio: f32) -> &mut Self {
-    self.l = (self.l - (self.l as f32 * ratio) as u32).max(0).min(100);
+    self.l = (self.l - self.l * ratio).clamp(0.0, 100.0);
     self
   }
 
@@ -197,7 +349,45 @@ impl HSL {
   /// assert_eq!(color.to_string(), "hsl(120,100%,60%)");
   /// ```
   pub fn lighten(&mut self, ratio: f32) -> &mut Self {
-    self.l = (self.l + (self.l as f32 * ratio) as u32).max(0).min(100);
+    self.l = (self.l + self.l * ratio).clamp(0.0, 100.0);
+    self
+  }
+
+  /// Saturates the color by the given ratio.
+  ///
+  /// # Arguments
+  ///
+  /// * `ratio` - A float value between 0 and 1 representing the amount to saturate the color by.
+  ///
+  /// # Example
+  ///
+  /// ``` rust
+  /// use easy_color::HSL;
+  /// let mut color = HSL::try_from("hsl(120, 50%, 50%)").unwrap();
+  /// color.saturate(0.2);
+  /// assert_eq!(color.to_string(), "hsl(120,60%,50%)");
+  /// ```
+  pub fn saturate(&mut self, ratio: f32) -> &mut Self {
+    self.s = (self.s + self.s * ratio).clamp(0.0, 100.0);
+    self
+  }
+
+  /// Desaturates the color by the given ratio.
+  ///
+  /// # Arguments
+  ///
+  /// * `ratio` - A float value between 0 and 1 representing the amount to desaturate the color by.
+  ///
+  /// # Example
+  ///
+  /// ``` rust
+  /// use easy_color::HSL;
+  /// let mut color = HSL::try_from("hsl(120, 50%, 50%)").unwrap();
+  /// color.desaturate(0.2);
+  /// assert_eq!(color.to_string(), "hsl(120,40%,50%)");
+  /// ```
+  pub fn desaturate(&mut self, ratio: f32) -> &mut Self {
+    self.s = (self.s - self.s * ratio).clamp(0.0, 100.0);
     self
   }
 
@@ -216,17 +406,30 @@ impl HSL {
   /// assert_eq!(color.to_string(), "hsl(180,100%,50%)");
   /// ```
   pub fn rotate(&mut self, degrees: i32) -> &mut Self {
-    let mut h = (self.h as i32 + degrees) % 360;
-    h = if h < 0 { 360 + h } else { h };
-    self.h = h as u32;
+    self.h = (self.h + degrees as f32).rem_euclid(360.0);
     self
   }
 
-  pub fn new_unchecked(hue: u32, saturation: u32, lightness: u32) -> Self {
+  /// Returns the complementary color, the hue rotated by 180 degrees.
+  ///
+  /// # Example
+  ///
+  /// ``` rust
+  /// use easy_color::HSL;
+  /// let color = HSL::try_from("hsl(120, 100%, 50%)").unwrap();
+  /// assert_eq!(color.complement().to_string(), "hsl(300,100%,50%)");
+  /// ```
+  pub fn complement(&self) -> Self {
+    let mut hsl = *self;
+    hsl.rotate(180);
+    hsl
+  }
+
+  pub const fn new_unchecked(hue: u32, saturation: u32, lightness: u32) -> Self {
     Self {
-      h: hue,
-      s: saturation,
-      l: lightness,
+      h: hue as f32,
+      s: saturation as f32,
+      l: lightness as f32,
     }
   }
   // pub fn random() -> Self {
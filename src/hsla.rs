@@ -1,12 +1,18 @@
-use crate::common::{rgb_to_hsl, rgba_to_hsla};
-use crate::{ColorError, Hex, CMYK, HSL, HSV, RGB, RGBA};
-use std::fmt::{Display, Formatter};
-use std::ops::{Deref, DerefMut};
-
-/// HSLA can be parsed from a string in the format "hsla(h, s%, l%, a)" or from a tuple (h,s,l,a).
-/// * h:u32 - Hue(0~360)
-/// * s:u32 - saturation(0~100)
-/// * l:u32 - lightness(0~100)
+use crate::common::{parse_fn_args_alpha, parse_hsl_args, parse_number, rgb_to_hsl, rgba_to_hsla};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, ColorError, Hex, LCh, Lab, Okhsl, Okhsv, Oklab, Oklch, ProPhotoRGB, CMYK, HSI, HSL,
+    HSV, HSVA, HWB, RGB, RGBA, XYZ,
+};
+use core::fmt::{Display, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
+
+/// HSLA can be parsed from a string in the format "hsla(h, s%, l%, a)" or "hsl(h s% l% / a)", or from a tuple (h,s,l,a).
+/// * h:f32 - Hue(0~360)
+/// * s:f32 - saturation(0~100)
+/// * l:f32 - lightness(0~100)
 /// * a:f32 - alpha(0~1)
 /// ### example
 /// ```rust
@@ -15,9 +21,13 @@ use std::ops::{Deref, DerefMut};
 /// hsla.set_alpha(0.5);
 /// assert_eq!(hsla.to_string(), "hsla(262,85%,79%,0.50)");
 ///
+/// // the modern space separated syntax with a slash alpha
+/// let hsla:HSLA = "hsl(262 85% 79% / 40%)".try_into().unwrap();
+/// assert_eq!(hsla.to_string(), "hsla(262,85%,79%,0.40)");
+///
 /// let hsla:HSLA = (125,60,75,0.6).try_into().unwrap();
 /// let rgba:RGBA = hsla.into();
-/// assert_eq!(rgba.to_string(), "rgba(153,229,159,0.60)");
+/// assert_eq!(rgba.to_string(), "rgba(153,230,159,0.60)");
 /// ```
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct HSLA {
@@ -28,27 +38,20 @@ pub struct HSLA {
 impl TryFrom<&str> for HSLA {
     type Error = ColorError;
     fn try_from(hsla_str: &str) -> Result<Self, Self::Error> {
-        let mut color = hsla_str.trim().to_lowercase();
-        if color.starts_with("hsla(") && color.ends_with(')') {
-            let mut val = vec![];
-            let mut alpha = None;
-            color = color.replace("hsla(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 4 {
-                for (idx, s) in tmp.iter().enumerate() {
-                    if idx == 3 {
-                        alpha = s.trim().parse::<f32>().ok();
-                    } else if let Ok(v) = s.trim().trim_end_matches('%').parse::<u32>() {
-                        val.push(v);
-                    }
-                }
+        let parsed =
+            parse_fn_args_alpha(hsla_str, "hsla").or_else(|| parse_fn_args_alpha(hsla_str, "hsl"));
+        if let Some((mut args, mut alpha)) = parsed {
+            // legacy syntax: hsla(h,s%,l%,a)
+            if alpha.is_none() && args.len() == 4 {
+                alpha = args.pop().and_then(|s| parse_number(&s, 1.0));
             }
-            if let Some(alpha) = alpha {
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2], alpha).try_into();
-                }
+            if let (Some((h, s, l)), Some(alpha)) = (parse_hsl_args(&args), alpha) {
+                return (h, s, l, alpha).try_into();
             }
         }
+        if hsla_str.trim().eq_ignore_ascii_case("transparent") {
+            return (0, 0, 0, 0.0).try_into();
+        }
         Err(ColorError::FormatErr(format!(
             "HSLA: {} format error!",
             hsla_str
@@ -56,6 +59,13 @@ impl TryFrom<&str> for HSLA {
     }
 }
 
+impl FromStr for HSLA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl TryFrom<(u32, u32, u32, f32)> for HSLA {
     type Error = ColorError;
     fn try_from(value: (u32, u32, u32, f32)) -> Result<Self, Self::Error> {
@@ -66,15 +76,25 @@ impl TryFrom<(u32, u32, u32, f32)> for HSLA {
         {
             Err(ColorError::ValueErr(format!("HSLA: args ({},{},{},{}) value error. first value must between 0~360, second and third must between 0~100, and last one must between 0~1", value.0, value.1, value.2, value.3)))
         } else {
-            let hsl = HSL {
-                h: value.0,
-                s: value.1,
-                l: value.2,
-            };
+            let hsl = HSL::new_unchecked(value.0, value.1, value.2);
             Ok(HSLA { hsl, a: value.3 })
         }
     }
 }
+
+impl TryFrom<(f32, f32, f32, f32)> for HSLA {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.3) {
+            return Err(ColorError::ValueErr(format!(
+                "HSLA: the alpha value must between 0~1, but got {}.",
+                value.3
+            )));
+        }
+        let hsl = HSL::try_from((value.0, value.1, value.2))?;
+        Ok(HSLA { hsl, a: value.3 })
+    }
+}
 impl From<Hex> for HSLA {
     fn from(hex: Hex) -> Self {
         let rgba: RGBA = hex.into();
@@ -114,6 +134,13 @@ impl From<HSV> for HSLA {
     }
 }
 
+impl From<HSVA> for HSLA {
+    fn from(hsva: HSVA) -> Self {
+        let rgba: RGBA = hsva.into();
+        rgba.into()
+    }
+}
+
 impl From<CMYK> for HSLA {
     fn from(cmyk: CMYK) -> Self {
         let rgb: RGB = cmyk.into();
@@ -121,6 +148,69 @@ impl From<CMYK> for HSLA {
     }
 }
 
+impl From<Oklab> for HSLA {
+    fn from(oklab: Oklab) -> Self {
+        let rgb: RGB = oklab.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklch> for HSLA {
+    fn from(oklch: Oklch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for HSLA {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl From<LCh> for HSLA {
+    fn from(lch: LCh) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for HSLA {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        rgb.into()
+    }
+}
+
+impl From<HWB> for HSLA {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        rgb.into()
+    }
+}
+
+impl From<HSI> for HSLA {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsl> for HSLA {
+    fn from(okhsl: Okhsl) -> Self {
+        let rgb: RGB = okhsl.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsv> for HSLA {
+    fn from(okhsv: Okhsv) -> Self {
+        let rgb: RGB = okhsv.into();
+        rgb.into()
+    }
+}
+
 impl Deref for HSLA {
     type Target = HSL;
     fn deref(&self) -> &Self::Target {
@@ -134,10 +224,30 @@ impl DerefMut for HSLA {
     }
 }
 
+impl From<AdobeRGB> for HSLA {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for HSLA {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
 impl Display for HSLA {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let HSL { h, s, l } = self.hsl;
-        write!(f, "hsla({},{}%,{}%,{:.2})", h, s, l, self.a)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "hsla({},{}%,{}%,{:.2})",
+            self.hue(),
+            self.saturation(),
+            self.lightness(),
+            self.a
+        )
     }
 }
 impl HSLA {
@@ -146,7 +256,7 @@ impl HSLA {
     }
 
     pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
-        self.a = alpha.max(0.0).min(1.0);
+        self.a = alpha.clamp(0.0, 1.0);
         self
     }
 
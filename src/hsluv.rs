@@ -0,0 +1,151 @@
+use crate::common::{lchuv_to_hsluv, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, LCHuv, HSL, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// HSLuv is a human friendly alternative to HSL built on LCHuv, its lightness is perceptual and its saturation
+/// is the percentage of the largest chroma the hue can reach in sRGB, which makes it handy for data visualization palettes.
+/// HSLuv can be parsed from a string in the format "hsluv(h s% l%)" or from a tuple (h,s,l).
+/// * h:f32 - hue(0~360)
+/// * s:f32 - saturation(0~100)
+/// * l:f32 - lightness(0~100)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{HSLuv, HSL, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let hsluv:HSLuv = rgb.into();
+/// assert_eq!(hsluv.to_string(), "hsluv(149.19 93.79% 70.75%)");
+///
+/// let mut hsluv:HSLuv = "hsluv(149.19 93.79% 70.75%)".try_into().unwrap();
+/// let rgb:RGB = hsluv.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// hsluv.set_hue(40.0);
+/// let hsl:HSL = hsluv.into();
+/// assert_eq!(hsl.to_string(), "hsl(32,91%,56%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HSLuv {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl TryFrom<&str> for HSLuv {
+    type Error = ColorError;
+    fn try_from(hsluv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(hsluv_str, "hsluv") {
+            if args.len() == 3 {
+                let h = parse_number(&args[0], 360.0);
+                let s = parse_number(&args[1], 100.0);
+                let l = parse_number(&args[2], 100.0);
+                if let (Some(h), Some(s), Some(l)) = (h, s, l) {
+                    return (h, s, l).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "HSLuv: {} format error!",
+            hsluv_str
+        )))
+    }
+}
+
+impl FromStr for HSLuv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for HSLuv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..=100.0).contains(&value.1)
+            || !(0.0..=100.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("HSLuv: args ({},{},{}) value error, the hue must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                l: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HSLuv {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HSLuv {
+    fn from(rgb: RGB) -> Self {
+        let lchuv: LCHuv = rgb.into();
+        lchuv.into()
+    }
+}
+
+impl From<RGBA> for HSLuv {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for HSLuv {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<LCHuv> for HSLuv {
+    fn from(lchuv: LCHuv) -> Self {
+        let LCHuv { l, c, h } = lchuv;
+        let (h, s, l) = lchuv_to_hsluv(l, c, h);
+        Self { h, s, l }
+    }
+}
+
+impl Display for HSLuv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "hsluv({:.2} {:.2}% {:.2}%)", self.h, self.s, self.l)
+    }
+}
+
+impl HSLuv {
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) -> &mut Self {
+        self.s = saturation.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+}
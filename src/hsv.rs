@@ -1,18 +1,28 @@
-use crate::common::{calc_rgb_with_alpha, rgb_to_hsv};
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, RGB, RGBA};
+use crate::common::{calc_rgb_with_alpha, parse_fn_args_alpha, parse_hsl_args, rgb_to_hsv};
+use crate::{
+    AdobeRGB, ColorError, Hex, LCh, Lab, Okhsl, Okhsv, Oklab, Oklch, ProPhotoRGB, CMYK, HSI, HSL,
+    HSLA, HSVA, HWB, RGB, RGBA, XYZ,
+};
 // use rand::Rng;
-use std::fmt::{Display, Formatter};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
 /// HSV can be parsed from a string in the format "hsl(h, s%, v%)" or from a tuple (h,s,v).
-/// * h:u32 - Hue(0~360)
-/// * s:u32 - saturation(0~100)
-/// * v:u32 - Value(0~100)
+/// * h:f32 - Hue(0~360)
+/// * s:f32 - saturation(0~100)
+/// * v:f32 - Value(0~100)
+///
+/// Like `HSL` the channels are stored as floats, the accessors and `Display` round them.
 /// ### example
 /// ```rust
 /// use easy_color::{RGB, HSV};
 /// let mut hsv:HSV = "hsv(262,85%,79%)".try_into().unwrap();
 /// hsv.set_value(50);
 /// assert_eq!(hsv.to_string(), "hsv(262,85%,50%)");
+/// let hsv:HSV = "hsv(200grad 85% 79%)".try_into().unwrap();
+/// assert_eq!(hsv.to_string(), "hsv(180,85%,79%)");
 ///
 /// let hsv:HSV = (125,60,75).try_into().unwrap();
 /// let rgb:RGB = hsv.into();
@@ -20,27 +30,24 @@ use std::fmt::{Display, Formatter};
 /// ```
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub struct HSV {
-    pub(crate) h: u32,
-    pub(crate) s: u32,
-    pub(crate) v: u32,
+    pub(crate) h: f32,
+    pub(crate) s: f32,
+    pub(crate) v: f32,
 }
 
 impl TryFrom<&str> for HSV {
     type Error = ColorError;
     fn try_from(hsv_str: &str) -> Result<Self, Self::Error> {
-        let mut color = hsv_str.trim().to_lowercase();
-        if color.starts_with("hsv(") && color.ends_with(')') {
-            color = color.replace("hsv(", "").replace(")", "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 3 {
-                let val = tmp
-                    .iter()
-                    .map(|s| s.trim().trim_end_matches('%').parse::<u32>())
-                    .filter_map(|v| v.ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2]).try_into();
-                }
+        if let Some((args, alpha)) = parse_fn_args_alpha(hsv_str, "hsv") {
+            if let Some((h, s, v)) = parse_hsl_args(&args) {
+                let hsv: HSV = (h, s, v).try_into()?;
+                return match alpha {
+                    Some(a) => {
+                        let rgb: RGB = hsv.into();
+                        RGBA::try_from((rgb.r, rgb.g, rgb.b, a)).map(HSV::from)
+                    }
+                    None => Ok(hsv),
+                };
             }
         }
         Err(ColorError::FormatErr(format!(
@@ -50,6 +57,13 @@ impl TryFrom<&str> for HSV {
     }
 }
 
+impl FromStr for HSV {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl TryFrom<(u32, u32, u32)> for HSV {
     type Error = ColorError;
     fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
@@ -58,6 +72,24 @@ impl TryFrom<(u32, u32, u32)> for HSV {
             || !(0..=100).contains(&value.2)
         {
             Err(ColorError::ValueErr(format!("HSV: args ({},{},{}) value error. the first value must between 0~360, others must between 0~1.", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0 as f32,
+                s: value.1 as f32,
+                v: value.2 as f32,
+            })
+        }
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for HSV {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..=100.0).contains(&value.1)
+            || !(0.0..=100.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("HSV: args ({},{},{}) value error. the first value must between 0~360, others must between 0~100.", value.0, value.1, value.2)))
         } else {
             Ok(Self {
                 h: value.0,
@@ -77,9 +109,7 @@ impl From<Hex> for HSV {
 
 impl From<RGB> for HSV {
     fn from(rgb: RGB) -> Self {
-        let RGB { r, g, b } = rgb;
-        let (h, s, v) = rgb_to_hsv(r, g, b);
-        Self { h, s, v }
+        Self::from_rgb(rgb)
     }
 }
 
@@ -109,6 +139,13 @@ impl From<HSLA> for HSV {
     }
 }
 
+impl From<HSVA> for HSV {
+    fn from(hsva: HSVA) -> Self {
+        let rgba: RGBA = hsva.into();
+        rgba.into()
+    }
+}
+
 impl From<CMYK> for HSV {
     fn from(cmyk: CMYK) -> Self {
         let rgb: RGB = cmyk.into();
@@ -116,39 +153,157 @@ impl From<CMYK> for HSV {
     }
 }
 
+impl From<Oklab> for HSV {
+    fn from(oklab: Oklab) -> Self {
+        let rgb: RGB = oklab.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklch> for HSV {
+    fn from(oklch: Oklch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for HSV {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl From<LCh> for HSV {
+    fn from(lch: LCh) -> Self {
+        let rgb: RGB = lch.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for HSV {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        rgb.into()
+    }
+}
+
+impl From<HWB> for HSV {
+    fn from(hwb: HWB) -> Self {
+        let HWB { h, w, b } = hwb;
+        let (h, w, b) = (h as f32, w as f32, b as f32);
+        if w + b >= 100.0 {
+            let v = w / (w + b) * 100.0;
+            return Self { h, s: 0.0, v };
+        }
+        let v = 100.0 - b;
+        let s = (1.0 - w / v) * 100.0;
+        Self { h, s, v }
+    }
+}
+
+impl From<HSI> for HSV {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsl> for HSV {
+    fn from(okhsl: Okhsl) -> Self {
+        let rgb: RGB = okhsl.into();
+        rgb.into()
+    }
+}
+
+impl From<Okhsv> for HSV {
+    fn from(okhsv: Okhsv) -> Self {
+        let rgb: RGB = okhsv.into();
+        rgb.into()
+    }
+}
+
+impl From<AdobeRGB> for HSV {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for HSV {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
 impl Display for HSV {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "hsv({},{}%,{}%)", self.h, self.s, self.v)
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "hsv({},{}%,{}%)",
+            self.hue(),
+            self.saturation(),
+            self.value()
+        )
     }
 }
 impl HSV {
+    /// Creates a color in a const context, the values are clamped like the setters do.
+    pub const fn new(hue: u32, saturation: u32, value: u32) -> Self {
+        Self {
+            h: if hue > 360 { 360 } else { hue } as f32,
+            s: if saturation > 100 { 100 } else { saturation } as f32,
+            v: if value > 100 { 100 } else { value } as f32,
+        }
+    }
+
+    /// The const version of `From<RGB>`.
+    pub const fn from_rgb(rgb: RGB) -> Self {
+        let (h, s, v) = rgb_to_hsv(rgb.r, rgb.g, rgb.b);
+        Self { h, s, v }
+    }
+
     pub fn hue(&self) -> u32 {
-        self.h
+        self.h.round() as u32
     }
 
     pub fn set_hue(&mut self, hue: u32) -> &mut Self {
-        self.h = hue.min(360);
+        self.h = hue.min(360) as f32;
         self
     }
 
     pub fn saturation(&self) -> u32 {
-        self.s
+        self.s.round() as u32
     }
 
     pub fn set_saturation(&mut self, saturation: u32) -> &mut Self {
-        self.s = saturation.min(100);
+        self.s = saturation.min(100) as f32;
         self
     }
 
     pub fn value(&self) -> u32 {
-        self.v
+        self.v.round() as u32
     }
 
     pub fn set_value(&mut self, value: u32) -> &mut Self {
-        self.v = value.min(100);
+        self.v = value.min(100) as f32;
         self
     }
 
+    /// Returns the complementary color, the hue rotated by 180 degrees.
+    /// ``` rust
+    /// use easy_color::HSV;
+    /// let color = HSV::try_from("hsv(300, 100%, 50%)").unwrap();
+    /// assert_eq!(color.complement().to_string(), "hsv(120,100%,50%)");
+    /// ```
+    pub fn complement(&self) -> Self {
+        Self {
+            h: (self.h + 180.0).rem_euclid(360.0),
+            ..*self
+        }
+    }
+
     // pub fn random() -> Self {
     //     let mut rng = rand::thread_rng();
     //     let h = rng.gen_range(0..=360) as u32;
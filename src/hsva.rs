@@ -0,0 +1,181 @@
+use crate::common::{parse_fn_args_alpha, parse_hsl_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{AdobeRGB, ColorError, Hex, ProPhotoRGB, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
+
+/// HSVA can be parsed from a string in the format "hsva(h, s%, v%, a)" or "hsv(h s% v% / a)", or from a tuple (h,s,v,a).
+/// * h:f32 - Hue(0~360)
+/// * s:f32 - saturation(0~100)
+/// * v:f32 - Value(0~100)
+/// * a:f32 - alpha(0~1)
+/// ### example
+/// ```rust
+/// use easy_color::{HSLA, HSVA, RGBA};
+/// let mut hsva:HSVA = "hsva(262,85%,79%, 0.7)".try_into().unwrap();
+/// hsva.set_alpha(0.5);
+/// hsva.set_value(50);
+/// assert_eq!(hsva.to_string(), "hsva(262,85%,50%,0.50)");
+///
+/// let hsva:HSVA = "hsv(262 85% 79% / 40%)".try_into().unwrap();
+/// assert_eq!(hsva.to_string(), "hsva(262,85%,79%,0.40)");
+///
+/// let hsva:HSVA = (125,60,75,0.6).try_into().unwrap();
+/// let rgba:RGBA = hsva.into();
+/// assert_eq!(rgba.to_string(), "rgba(76,191,86,0.60)");
+/// let hsla:HSLA = hsva.into();
+/// assert_eq!(hsla.to_string(), "hsla(125,47%,52%,0.60)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HSVA {
+    pub hsv: HSV,
+    pub a: f32,
+}
+
+impl TryFrom<&str> for HSVA {
+    type Error = ColorError;
+    fn try_from(hsva_str: &str) -> Result<Self, Self::Error> {
+        let parsed =
+            parse_fn_args_alpha(hsva_str, "hsva").or_else(|| parse_fn_args_alpha(hsva_str, "hsv"));
+        if let Some((mut args, mut alpha)) = parsed {
+            // legacy syntax: hsva(h,s%,v%,a)
+            if alpha.is_none() && args.len() == 4 {
+                alpha = args.pop().and_then(|s| parse_number(&s, 1.0));
+            }
+            if let Some((h, s, v)) = parse_hsl_args(&args) {
+                return (h, s, v, alpha.unwrap_or(1.0)).try_into();
+            }
+        }
+        if hsva_str.trim().eq_ignore_ascii_case("transparent") {
+            return (0, 0, 0, 0.0).try_into();
+        }
+        Err(ColorError::FormatErr(format!(
+            "HSVA: {} format error!",
+            hsva_str
+        )))
+    }
+}
+
+impl FromStr for HSVA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(u32, u32, u32, f32)> for HSVA {
+    type Error = ColorError;
+    fn try_from(value: (u32, u32, u32, f32)) -> Result<Self, Self::Error> {
+        if !(0..=360).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+            || !(0.0..=1.0).contains(&value.3)
+        {
+            Err(ColorError::ValueErr(format!("HSVA: args ({},{},{},{}) value error. first value must between 0~360, second and third must between 0~100, and last one must between 0~1", value.0, value.1, value.2, value.3)))
+        } else {
+            let hsv = HSV::new(value.0, value.1, value.2);
+            Ok(HSVA { hsv, a: value.3 })
+        }
+    }
+}
+
+impl TryFrom<(f32, f32, f32, f32)> for HSVA {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.3) {
+            return Err(ColorError::ValueErr(format!(
+                "HSVA: the alpha value must between 0~1, but got {}.",
+                value.3
+            )));
+        }
+        let hsv = HSV::try_from((value.0, value.1, value.2))?;
+        Ok(HSVA { hsv, a: value.3 })
+    }
+}
+
+impl From<Hex> for HSVA {
+    fn from(hex: Hex) -> Self {
+        let rgba: RGBA = hex.into();
+        rgba.into()
+    }
+}
+
+impl From<RGB> for HSVA {
+    fn from(rgb: RGB) -> Self {
+        let hsv = HSV::from_rgb(rgb);
+        Self { hsv, a: 1.0 }
+    }
+}
+
+impl From<RGBA> for HSVA {
+    fn from(rgba: RGBA) -> Self {
+        let hsv = HSV::from_rgb(rgba.rgb);
+        Self { hsv, a: rgba.a }
+    }
+}
+
+impl From<HSV> for HSVA {
+    fn from(hsv: HSV) -> Self {
+        Self { hsv, a: 1.0 }
+    }
+}
+
+impl From<HSLA> for HSVA {
+    fn from(hsla: HSLA) -> Self {
+        let rgba: RGBA = hsla.into();
+        rgba.into()
+    }
+}
+
+impl Deref for HSVA {
+    type Target = HSV;
+    fn deref(&self) -> &Self::Target {
+        &self.hsv
+    }
+}
+
+impl DerefMut for HSVA {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.hsv
+    }
+}
+
+impl From<AdobeRGB> for HSVA {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        rgb.into()
+    }
+}
+
+impl From<ProPhotoRGB> for HSVA {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        rgb.into()
+    }
+}
+
+impl Display for HSVA {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "hsva({},{}%,{}%,{:.2})",
+            self.hue(),
+            self.saturation(),
+            self.value(),
+            self.a
+        )
+    }
+}
+
+impl HSVA {
+    pub fn alpha(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
+        self.a = alpha.clamp(0.0, 1.0);
+        self
+    }
+}
@@ -0,0 +1,139 @@
+use crate::common::{parse_fn_args, parse_number, xyz_to_hunter_lab};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// HunterLab is the Hunter 1948 L, a, b color space(D65) that many colorimeters of the food and paint industries still report.
+/// HunterLab can be parsed from a string in the format "hunterlab(l a b)" or from a tuple (l,a,b).
+/// * l:f32 - lightness(0~100)
+/// * a:f32 - green/red axis
+/// * b:f32 - blue/yellow axis
+///
+/// ### example
+/// ```rust
+/// use easy_color::{HunterLab, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let lab:HunterLab = rgb.into();
+/// assert_eq!(lab.to_string(), "hunterlab(64.67 -40.47 14.05)");
+///
+/// let lab:HunterLab = "hunterlab(64.67 -40.47 14.05)".try_into().unwrap();
+/// let rgb:RGB = lab.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HunterLab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for HunterLab {
+    type Error = ColorError;
+    fn try_from(lab_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(lab_str, "hunterlab") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let a = parse_number(&args[1], 100.0);
+                let b = parse_number(&args[2], 100.0);
+                if let (Some(l), Some(a), Some(b)) = (l, a, b) {
+                    return (l, a, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "HunterLab: {} format error!",
+            lab_str
+        )))
+    }
+}
+
+impl FromStr for HunterLab {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for HunterLab {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "HunterLab: args ({},{},{}) value error, the lightness must between 0~100!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HunterLab {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for HunterLab {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for HunterLab {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for HunterLab {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let (l, a, b) = xyz_to_hunter_lab(x, y, z);
+        Self { l, a, b }
+    }
+}
+
+impl Display for HunterLab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "hunterlab({:.2} {:.2} {:.2})", self.l, self.a, self.b)
+    }
+}
+
+impl HunterLab {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_a(&mut self, a: f32) -> &mut Self {
+        self.a = a;
+        self
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_b(&mut self, b: f32) -> &mut Self {
+        self.b = b;
+        self
+    }
+}
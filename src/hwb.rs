@@ -0,0 +1,170 @@
+use crate::common::{parse_fn_args, rgb_to_hwb};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// HWB describes a color by its hue and the amount of white and black mixed into it.
+/// HWB can be parsed from a string in the format "hwb(h w% b%)" or from a tuple (h,w,b).
+/// * h:u32 - Hue(0~360)
+/// * w:u32 - whiteness(0~100)
+/// * b:u32 - blackness(0~100)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{HWB, RGB};
+/// let mut hwb:HWB = "hwb(120 20% 30%)".try_into().unwrap();
+/// let rgb:RGB = hwb.into();
+/// assert_eq!(rgb.to_string(), "rgb(51,179,51)");
+///
+/// hwb.set_whiteness(0);
+/// assert_eq!(hwb.to_string(), "hwb(120 0% 30%)");
+///
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let hwb:HWB = rgb.into();
+/// assert_eq!(hwb.to_string(), "hwb(157 17% 23%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HWB {
+    pub(crate) h: u32,
+    pub(crate) w: u32,
+    pub(crate) b: u32,
+}
+
+impl TryFrom<&str> for HWB {
+    type Error = ColorError;
+    fn try_from(hwb_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(hwb_str, "hwb") {
+            if args.len() == 3 {
+                let val = args
+                    .iter()
+                    .map(|s| s.trim_end_matches('%').parse::<u32>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "HWB: {} format error!",
+            hwb_str
+        )))
+    }
+}
+
+impl FromStr for HWB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(u32, u32, u32)> for HWB {
+    type Error = ColorError;
+    fn try_from(value: (u32, u32, u32)) -> Result<Self, Self::Error> {
+        if !(0..=360).contains(&value.0)
+            || !(0..=100).contains(&value.1)
+            || !(0..=100).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("HWB: args ({},{},{}) value error, first value must between 0~360, others must between 0~100!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                w: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for HWB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for HWB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, w, b) = rgb_to_hwb(r, g, b);
+        Self { h, w, b }
+    }
+}
+
+impl From<RGBA> for HWB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for HWB {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for HWB {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for HWB {
+    fn from(hsv: HSV) -> Self {
+        let HSV { h, s, v } = hsv;
+        let w = (100.0 - s) * v / 100.0;
+        Self {
+            h: h.round() as u32,
+            w: w.round() as u32,
+            b: (100.0 - v).round() as u32,
+        }
+    }
+}
+
+impl From<CMYK> for HWB {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl Display for HWB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "hwb({} {}% {}%)", self.h, self.w, self.b)
+    }
+}
+
+impl HWB {
+    pub fn hue(&self) -> u32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: u32) -> &mut Self {
+        self.h = hue.min(360);
+        self
+    }
+
+    pub fn whiteness(&self) -> u32 {
+        self.w
+    }
+
+    pub fn set_whiteness(&mut self, whiteness: u32) -> &mut Self {
+        self.w = whiteness.min(100);
+        self
+    }
+
+    pub fn blackness(&self) -> u32 {
+        self.b
+    }
+
+    pub fn set_blackness(&mut self, blackness: u32) -> &mut Self {
+        self.b = blackness.min(100);
+        self
+    }
+}
@@ -0,0 +1,167 @@
+use crate::common::{
+    linear_rec2020_to_ictcp, mul_matrix3, parse_fn_args, parse_number, XYZ_TO_REC2020,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Rec2020, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// ICtCp is the BT.2100 color space of HDR video, it encodes Rec.2020 with the PQ transfer function
+/// so that the differences stay perceptually even over the whole HDR luminance range.
+/// The SDR white(Y = 1) is taken as 203 cd/m².
+/// ICtCp can be parsed from a string in the format "ictcp(i ct cp)" or from a tuple (i,ct,cp).
+/// * i:f32 - intensity(0~1, the SDR white is about 0.58)
+/// * ct:f32 - blue/yellow axis(about -0.5~0.5)
+/// * cp:f32 - red/green axis(about -0.5~0.5)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{ICtCp, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let ictcp:ICtCp = rgb.into();
+/// assert_eq!(ictcp.to_string(), "ictcp(0.4879 -0.0914 -0.0628)");
+///
+/// let ictcp:ICtCp = "ictcp(0.4879 -0.0914 -0.0628)".try_into().unwrap();
+/// let rgb:RGB = ictcp.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ICtCp {
+    pub i: f32,
+    pub ct: f32,
+    pub cp: f32,
+}
+
+impl TryFrom<&str> for ICtCp {
+    type Error = ColorError;
+    fn try_from(ictcp_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(ictcp_str, "ictcp") {
+            if args.len() == 3 {
+                let i = parse_number(&args[0], 1.0);
+                let ct = parse_number(&args[1], 0.5);
+                let cp = parse_number(&args[2], 0.5);
+                if let (Some(i), Some(ct), Some(cp)) = (i, ct, cp) {
+                    return (i, ct, cp).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "ICtCp: {} format error!",
+            ictcp_str
+        )))
+    }
+}
+
+impl FromStr for ICtCp {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for ICtCp {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "ICtCp: args ({},{},{}) value error, the intensity must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                i: value.0,
+                ct: value.1,
+                cp: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for ICtCp {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for ICtCp {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for ICtCp {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for ICtCp {
+    fn from(xyz: XYZ) -> Self {
+        let (r, g, b) = mul_matrix3(&XYZ_TO_REC2020, (xyz.x, xyz.y, xyz.z));
+        let (i, ct, cp) = linear_rec2020_to_ictcp(r, g, b);
+        Self { i, ct, cp }
+    }
+}
+
+impl From<Rec2020> for ICtCp {
+    fn from(rec2020: Rec2020) -> Self {
+        let (r, g, b) = rec2020.to_linear();
+        let (i, ct, cp) = linear_rec2020_to_ictcp(r, g, b);
+        Self { i, ct, cp }
+    }
+}
+
+impl Display for ICtCp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ictcp({:.4} {:.4} {:.4})", self.i, self.ct, self.cp)
+    }
+}
+
+impl ICtCp {
+    pub fn intensity(&self) -> f32 {
+        self.i
+    }
+
+    pub fn set_intensity(&mut self, intensity: f32) -> &mut Self {
+        self.i = intensity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn ct(&self) -> f32 {
+        self.ct
+    }
+
+    pub fn set_ct(&mut self, ct: f32) -> &mut Self {
+        self.ct = ct;
+        self
+    }
+
+    pub fn cp(&self) -> f32 {
+        self.cp
+    }
+
+    pub fn set_cp(&mut self, cp: f32) -> &mut Self {
+        self.cp = cp;
+        self
+    }
+
+    /// Returns the ΔE ITP color difference(ITU-R BT.2124) between two colors, 1 is about a just noticeable difference.
+    /// ```rust
+    /// use easy_color::{ICtCp, RGB};
+    /// let a:RGB = (43, 196, 138).try_into().unwrap();
+    /// let b:RGB = (43, 190, 138).try_into().unwrap();
+    /// let ictcp:ICtCp = a.into();
+    /// assert_eq!(format!("{:.2}", ictcp.delta_e_itp(b)), "5.33");
+    /// ```
+    pub fn delta_e_itp(&self, other: impl Into<Self>) -> f32 {
+        let other: ICtCp = other.into();
+        let di = self.i - other.i;
+        let dt = 0.5 * (self.ct - other.ct);
+        let dp = self.cp - other.cp;
+        720.0 * (di * di + dt * dt + dp * dp).sqrt()
+    }
+}
@@ -0,0 +1,57 @@
+//! Conversions between the color types and the pixels of the `image` crate, enabled by the `image` feature.
+//! `Rgb<u8>` and `Rgba<u8>` map to `RGB` and `RGBA`, `Rgba<f32>` holds the channels normalized to 0~1.
+//! ```rust
+//! use easy_color::{RGB, RGBA};
+//! use image::{Rgb, Rgba};
+//! let rgb:RGB = Rgb([43, 196, 138]).into();
+//! assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+//! let pixel:Rgba<u8> = RGBA::from(rgb).into();
+//! assert_eq!(pixel, Rgba([43, 196, 138, 255]));
+//!
+//! let rgba:RGBA = Rgba([1.0, 0.0, 0.0, 0.5]).try_into().unwrap();
+//! assert_eq!(rgba.to_string(), "rgba(255,0,0,0.50)");
+//! let pixel:Rgba<f32> = rgba.into();
+//! assert_eq!(pixel, Rgba([1.0, 0.0, 0.0, 0.5]));
+//! assert!(RGBA::try_from(Rgba([2.0, 0.0, 0.0, 1.0])).is_err());
+//! ```
+use crate::{ColorError, RGB, RGBA};
+use ::image::{Rgb, Rgba};
+
+impl From<Rgb<u8>> for RGB {
+    fn from(pixel: Rgb<u8>) -> Self {
+        pixel.0.into()
+    }
+}
+
+impl From<RGB> for Rgb<u8> {
+    fn from(rgb: RGB) -> Self {
+        Rgb(rgb.to_bytes())
+    }
+}
+
+impl From<Rgba<u8>> for RGBA {
+    fn from(pixel: Rgba<u8>) -> Self {
+        pixel.0.into()
+    }
+}
+
+impl From<RGBA> for Rgba<u8> {
+    fn from(rgba: RGBA) -> Self {
+        Rgba(rgba.to_bytes())
+    }
+}
+
+impl TryFrom<Rgba<f32>> for RGBA {
+    type Error = ColorError;
+    fn try_from(pixel: Rgba<f32>) -> Result<Self, Self::Error> {
+        let [r, g, b, a] = pixel.0;
+        (r, g, b, a).try_into()
+    }
+}
+
+impl From<RGBA> for Rgba<f32> {
+    fn from(rgba: RGBA) -> Self {
+        let (r, g, b, a) = rgba.to_f32_tuple();
+        Rgba([r, g, b, a])
+    }
+}
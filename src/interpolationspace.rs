@@ -0,0 +1,100 @@
+use crate::common::{
+    hsl_to_rgb, lab_to_lch, lab_to_rgb, lch_to_lab, linear_rgb, linear_to_rgb, oklab_to_rgb,
+    rgb_to_hsl, rgb_to_lab, rgb_to_oklab,
+};
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+use crate::RGBA;
+
+/// The color space two colors are interpolated in, see `Interpolate::lerp_in`.
+/// The hues of `Hsl`, `Lch` and `Oklch` take the shorter way around the color wheel,
+/// and the hue of a gray takes the hue of the other color, as in CSS.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InterpolationSpace {
+    Rgb,
+    LinearRgb,
+    Hsl,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+impl InterpolationSpace {
+    /// The index of the hue coordinate and the chroma below which the hue is powerless.
+    fn hue(&self) -> Option<(usize, f32)> {
+        match self {
+            Self::Hsl => Some((0, 0.01)),
+            Self::Lch => Some((2, 0.01)),
+            Self::Oklch => Some((2, 0.0001)),
+            _ => None,
+        }
+    }
+
+    fn coordinates(&self, rgba: RGBA) -> [f32; 3] {
+        let (r, g, b) = (rgba.r, rgba.g, rgba.b);
+        let (x, y, z) = match self {
+            Self::Rgb => (r as f32, g as f32, b as f32),
+            Self::LinearRgb => linear_rgb(r, g, b),
+            Self::Hsl => rgb_to_hsl(r, g, b),
+            Self::Lab => rgb_to_lab(r, g, b),
+            Self::Lch => {
+                let (l, a, b) = rgb_to_lab(r, g, b);
+                lab_to_lch(l, a, b)
+            }
+            Self::Oklab => rgb_to_oklab(r, g, b),
+            Self::Oklch => {
+                let (l, a, b) = rgb_to_oklab(r, g, b);
+                lab_to_lch(l, a, b)
+            }
+        };
+        [x, y, z]
+    }
+
+    fn rgba(self, c: [f32; 3], alpha: f32) -> RGBA {
+        let (r, g, b) = match self {
+            Self::Rgb => {
+                let to_u8 = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+                (to_u8(c[0]), to_u8(c[1]), to_u8(c[2]))
+            }
+            Self::LinearRgb => linear_to_rgb(c[0], c[1], c[2]),
+            Self::Hsl => hsl_to_rgb(c[0], c[1].clamp(0.0, 100.0), c[2].clamp(0.0, 100.0)),
+            Self::Lab => lab_to_rgb(c[0], c[1], c[2]),
+            Self::Lch => {
+                let (l, a, b) = lch_to_lab(c[0], c[1], c[2]);
+                lab_to_rgb(l, a, b)
+            }
+            Self::Oklab => oklab_to_rgb(c[0], c[1], c[2]),
+            Self::Oklch => {
+                let (l, a, b) = lch_to_lab(c[0], c[1], c[2]);
+                oklab_to_rgb(l, a, b)
+            }
+        };
+        RGBA::new(r, g, b, alpha)
+    }
+
+    /// Interpolates from `from` to `to`, `t` is clamped to 0~1. The alpha is interpolated linearly.
+    pub(crate) fn lerp(&self, from: RGBA, to: RGBA, t: f32) -> RGBA {
+        let t = t.clamp(0.0, 1.0);
+        let mut a = self.coordinates(from);
+        let mut b = self.coordinates(to);
+        let mut c: [f32; 3] = core::array::from_fn(|i| a[i] + (b[i] - a[i]) * t);
+        if let Some((h, min_chroma)) = self.hue() {
+            // the saturation or chroma is the second coordinate of every space with a hue
+            let (gray_a, gray_b) = (a[1] < min_chroma, b[1] < min_chroma);
+            if gray_a && !gray_b {
+                a[h] = b[h];
+            } else if gray_b && !gray_a {
+                b[h] = a[h];
+            }
+            let mut delta = b[h] - a[h];
+            if delta > 180.0 {
+                delta -= 360.0;
+            } else if delta < -180.0 {
+                delta += 360.0;
+            }
+            c[h] = (a[h] + delta * t).rem_euclid(360.0);
+        }
+        self.rgba(c, from.a + (to.a - from.a) * t)
+    }
+}
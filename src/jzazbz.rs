@@ -0,0 +1,157 @@
+use crate::common::{lab_to_lch, parse_fn_args, parse_number, xyz_to_jzazbz};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Jzazbz is a perceptually uniform color space designed for high dynamic range, it keeps its uniformity
+/// at luminances where Lab breaks down. The white of `XYZ`(Y = 1) is taken as 203 cd/m², the SDR reference white.
+/// Jzazbz can be parsed from a string in the format "jzazbz(jz az bz)" or from a tuple (jz,az,bz).
+/// * jz:f32 - lightness(0~1, the SDR white is about 0.22)
+/// * az:f32 - green/red axis(about -0.5~0.5)
+/// * bz:f32 - blue/yellow axis(about -0.5~0.5)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Jzazbz, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let jzazbz:Jzazbz = rgb.into();
+/// assert_eq!(jzazbz.to_string(), "jzazbz(0.1458 -0.0690 0.0253)");
+///
+/// let jzazbz:Jzazbz = "jzazbz(0.1458 -0.0690 0.0253)".try_into().unwrap();
+/// let rgb:RGB = jzazbz.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Jzazbz {
+    pub jz: f32,
+    pub az: f32,
+    pub bz: f32,
+}
+
+impl TryFrom<&str> for Jzazbz {
+    type Error = ColorError;
+    fn try_from(jzazbz_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(jzazbz_str, "jzazbz") {
+            if args.len() == 3 {
+                let jz = parse_number(&args[0], 1.0);
+                let az = parse_number(&args[1], 0.5);
+                let bz = parse_number(&args[2], 0.5);
+                if let (Some(jz), Some(az), Some(bz)) = (jz, az, bz) {
+                    return (jz, az, bz).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Jzazbz: {} format error!",
+            jzazbz_str
+        )))
+    }
+}
+
+impl FromStr for Jzazbz {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Jzazbz {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "Jzazbz: args ({},{},{}) value error, the lightness must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                jz: value.0,
+                az: value.1,
+                bz: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Jzazbz {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for Jzazbz {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for Jzazbz {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for Jzazbz {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let (jz, az, bz) = xyz_to_jzazbz(x, y, z);
+        Self { jz, az, bz }
+    }
+}
+
+impl Display for Jzazbz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "jzazbz({:.4} {:.4} {:.4})", self.jz, self.az, self.bz)
+    }
+}
+
+impl Jzazbz {
+    pub fn lightness(&self) -> f32 {
+        self.jz
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.jz = lightness.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn az(&self) -> f32 {
+        self.az
+    }
+
+    pub fn set_az(&mut self, az: f32) -> &mut Self {
+        self.az = az;
+        self
+    }
+
+    pub fn bz(&self) -> f32 {
+        self.bz
+    }
+
+    pub fn set_bz(&mut self, bz: f32) -> &mut Self {
+        self.bz = bz;
+        self
+    }
+
+    /// Returns the ΔEz color difference between two colors, computed on the lightness, chroma and hue of Jzazbz.
+    /// ```rust
+    /// use easy_color::{Jzazbz, RGB};
+    /// let a:RGB = (43, 196, 138).try_into().unwrap();
+    /// let b:RGB = (43, 190, 138).try_into().unwrap();
+    /// let jzazbz:Jzazbz = a.into();
+    /// assert_eq!(format!("{:.4}", jzazbz.delta_e(b)), "0.0060");
+    /// assert_eq!(jzazbz.delta_e(a), 0.0);
+    /// ```
+    pub fn delta_e(&self, other: impl Into<Self>) -> f32 {
+        let other: Jzazbz = other.into();
+        let (j1, c1, h1) = lab_to_lch(self.jz, self.az, self.bz);
+        let (j2, c2, h2) = lab_to_lch(other.jz, other.az, other.bz);
+        let dh = 2.0 * (c1 * c2).sqrt() * ((h1 - h2).to_radians() / 2.0).sin();
+        ((j1 - j2).powi(2) + (c1 - c2).powi(2) + dh * dh).sqrt()
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::ColorError;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Kelvin is a color temperature, it is converted into the color of a blackbody radiator at that temperature.
+/// The approximation is fitted for 1000K~40000K, temperatures outside of the range are clamped when converted.
+/// Kelvin can be parsed from a string in the format "3200K" or created directly.
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Kelvin, RGB};
+/// let rgb:RGB = Kelvin(3200).into();
+/// assert_eq!(rgb.to_string(), "rgb(255,184,123)");
+///
+/// let kelvin:Kelvin = "6500K".try_into().unwrap();
+/// let rgb:RGB = kelvin.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,254,250)");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Kelvin(pub u32);
+
+impl Default for Kelvin {
+    fn default() -> Self {
+        Self(6500)
+    }
+}
+
+impl TryFrom<&str> for Kelvin {
+    type Error = ColorError;
+    fn try_from(kelvin_str: &str) -> Result<Self, Self::Error> {
+        let s = kelvin_str.trim();
+        if let Some(v) = s.strip_suffix(['K', 'k']) {
+            if let Ok(v) = v.trim().parse::<u32>() {
+                return Ok(Self(v));
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Kelvin: {} format error!",
+            kelvin_str
+        )))
+    }
+}
+
+impl FromStr for Kelvin {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for Kelvin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}K", self.0)
+    }
+}
+
+impl Kelvin {
+    pub fn temperature(&self) -> u32 {
+        self.0
+    }
+
+    pub fn set_temperature(&mut self, temperature: u32) -> &mut Self {
+        self.0 = temperature;
+        self
+    }
+}
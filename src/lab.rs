@@ -0,0 +1,348 @@
+use crate::common::{
+    din99_to_lab, lch_to_lab, parse_fn_args, parse_number, rgb_to_lab, xyz_to_lab,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, ColorError, Din99, Hex, LCh, LabF64, Oklab, Oklch, ProPhotoRGB, CMYK, HSL, HSLA, HSV,
+    RGB, RGBA, XYZ,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Lab is the CIE L\*a\*b\* color space with a D65 reference white.
+/// Lab can be parsed from a string in the CSS format "lab(l a b)" or "lab(l a b / a)", or from a tuple (l,a,b).
+/// Lab has no alpha channel, so the alpha of a string is ignored.
+/// * l:f32 - lightness(0~100), can also be written as a percentage in strings
+/// * a:f32 - green/red axis(about -128~127)
+/// * b:f32 - blue/yellow axis(about -128~127)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Lab, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let lab:Lab = rgb.into();
+/// assert_eq!(lab.to_string(), "lab(70.75 -52.20 18.31)");
+///
+/// let mut lab:Lab = "lab(70.75 -52.20 18.31)".try_into().unwrap();
+/// let rgb:RGB = lab.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// lab.set_lightness(50.0);
+/// assert_eq!(lab.to_string(), "lab(50.00 -52.20 18.31)");
+///
+/// let lab:Lab = "lab(52% -40 60 / 0.5)".try_into().unwrap();
+/// assert_eq!(lab.to_string(), "lab(52.00 -40.00 60.00)");
+/// // a and b percentages are relative to 125
+/// let lab:Lab = "lab(52% 40% -60%)".try_into().unwrap();
+/// assert_eq!(lab.to_string(), "lab(52.00 50.00 -75.00)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// The parameter set of the CIE94 color difference, the weights differ between industries.
+/// * GraphicArts - kL = 1, K1 = 0.045, K2 = 0.015
+/// * Textiles - kL = 2, K1 = 0.048, K2 = 0.014
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Cie94Application {
+    #[default]
+    GraphicArts,
+    Textiles,
+}
+
+impl TryFrom<&str> for Lab {
+    type Error = ColorError;
+    fn try_from(lab_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(lab_str, "lab") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let a = parse_number(&args[1], 125.0);
+                let b = parse_number(&args[2], 125.0);
+                if let (Some(l), Some(a), Some(b)) = (l, a, b) {
+                    return (l, a, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Lab: {} format error!",
+            lab_str
+        )))
+    }
+}
+
+impl FromStr for Lab {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Lab {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "Lab: args ({},{},{}) value error, the lightness must between 0~100!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Lab {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Lab {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (l, a, b) = rgb_to_lab(r, g, b);
+        Self { l, a, b }
+    }
+}
+
+impl From<RGBA> for Lab {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for Lab {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for Lab {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for Lab {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for Lab {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklab> for Lab {
+    fn from(oklab: Oklab) -> Self {
+        let rgb: RGB = oklab.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklch> for Lab {
+    fn from(oklch: Oklch) -> Self {
+        let rgb: RGB = oklch.into();
+        rgb.into()
+    }
+}
+
+impl From<LCh> for Lab {
+    fn from(lch: LCh) -> Self {
+        let LCh { l, c, h } = lch;
+        let (l, a, b) = lch_to_lab(l, c, h);
+        Self { l, a, b }
+    }
+}
+
+impl From<XYZ> for Lab {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let (l, a, b) = xyz_to_lab(x, y, z);
+        Self { l, a, b }
+    }
+}
+
+impl From<Din99> for Lab {
+    fn from(din99: Din99) -> Self {
+        let Din99 { l, a, b } = din99;
+        let (l, a, b) = din99_to_lab(l, a, b);
+        Self { l, a, b }
+    }
+}
+
+impl From<LabF64> for Lab {
+    fn from(lab: LabF64) -> Self {
+        Self {
+            l: lab.l as f32,
+            a: lab.a as f32,
+            b: lab.b as f32,
+        }
+    }
+}
+
+impl From<AdobeRGB> for Lab {
+    fn from(adobe: AdobeRGB) -> Self {
+        let xyz: XYZ = adobe.into();
+        xyz.into()
+    }
+}
+
+impl From<ProPhotoRGB> for Lab {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let xyz: XYZ = prophoto.into();
+        xyz.into()
+    }
+}
+
+impl Display for Lab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lab({:.2} {:.2} {:.2})", self.l, self.a, self.b)
+    }
+}
+
+impl Lab {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_a(&mut self, a: f32) -> &mut Self {
+        self.a = a;
+        self
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_b(&mut self, b: f32) -> &mut Self {
+        self.b = b;
+        self
+    }
+
+    /// The CIE76 color difference, the euclidean distance in Lab. A difference below about 2.3 is hardly noticeable.
+    /// ```rust
+    /// use easy_color::Lab;
+    /// let lab1:Lab = "lab(50 2.6772 -79.7751)".try_into().unwrap();
+    /// let lab2:Lab = "lab(50 0 -82.7485)".try_into().unwrap();
+    /// assert_eq!(format!("{:.4}", lab1.delta_e76(lab2)), "4.0011");
+    /// ```
+    pub fn delta_e76(&self, other: impl Into<Lab>) -> f32 {
+        let other: Lab = other.into();
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+
+    /// The CIE94 color difference with the weights of the given application, `self` is the reference color.
+    /// ```rust
+    /// use easy_color::{Cie94Application, Lab};
+    /// let lab1:Lab = "lab(50 2.6772 -79.7751)".try_into().unwrap();
+    /// let lab2:Lab = "lab(50 0 -82.7485)".try_into().unwrap();
+    /// assert_eq!(format!("{:.4}", lab1.delta_e94(lab2, Cie94Application::GraphicArts)), "1.3950");
+    /// assert_eq!(format!("{:.4}", lab1.delta_e94(lab2, Cie94Application::Textiles)), "1.4230");
+    /// ```
+    pub fn delta_e94(&self, other: impl Into<Lab>, application: Cie94Application) -> f32 {
+        let other: Lab = other.into();
+        let (kl, k1, k2) = match application {
+            Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+            Cie94Application::Textiles => (2.0, 0.048, 0.014),
+        };
+        let c1 = self.a.hypot(self.b);
+        let c2 = other.a.hypot(other.b);
+        let dl = self.l - other.l;
+        let dc = c1 - c2;
+        let dh2 = ((self.a - other.a).powi(2) + (self.b - other.b).powi(2) - dc * dc).max(0.0);
+        let sc = 1.0 + k1 * c1;
+        let sh = 1.0 + k2 * c1;
+        ((dl / kl).powi(2) + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+    }
+
+    /// The CIEDE2000 color difference, with the hue rotation term for blues and kL = kC = kH = 1.
+    /// It is computed in f64, since the hue of near neutral colors is very sensitive.
+    /// ```rust
+    /// use easy_color::Lab;
+    /// let lab1:Lab = "lab(50 2.6772 -79.7751)".try_into().unwrap();
+    /// let lab2:Lab = "lab(50 0 -82.7485)".try_into().unwrap();
+    /// assert_eq!(format!("{:.4}", lab1.delta_e2000(lab2)), "2.0425");
+    /// ```
+    pub fn delta_e2000(&self, other: impl Into<Lab>) -> f32 {
+        let other: Lab = other.into();
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+        let pow25_7 = 25.0_f64.powi(7);
+        let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+        let hue = |a: f64, b: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else if h2 - h1 < -180.0 {
+            h2 - h1 + 360.0
+        } else {
+            h2 - h1
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+        let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_bar).to_radians().cos()
+            + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+        let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+        let sl = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+        let sc = 1.0 + 0.045 * c_bar;
+        let sh = 1.0 + 0.015 * c_bar * t;
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+        let (dl, dc, dh) = (dl / sl, dc / sc, dh / sh);
+        (dl * dl + dc * dc + dh * dh + rt * dc * dh).sqrt() as f32
+    }
+}
@@ -0,0 +1,114 @@
+use crate::common::{parse_fn_args, parse_number_f64, xyz_to_lab_f64};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Lab, RGB, RGBF64, XYZF64};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// LabF64 is `Lab` with f64 values and the same D65 reference white.
+/// LabF64 can be parsed from a string in the format "lab(l a b)" or from a tuple (l,a,b).
+/// * l:f64 - lightness(0~100), can also be written as a percentage in strings
+/// * a:f64 - green/red axis(about -128~127)
+/// * b:f64 - blue/yellow axis(about -128~127)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Lab, LabF64, RGB};
+/// let lab:LabF64 = RGB::new(43, 196, 138).into();
+/// assert_eq!(lab.to_string(), "lab(70.7512 -52.1962 18.3139)");
+///
+/// let lab:Lab = lab.into();
+/// assert_eq!(lab.to_string(), "lab(70.75 -52.20 18.31)");
+///
+/// let white:LabF64 = RGB::new(255, 255, 255).into();
+/// assert_eq!(white.to_string(), "lab(100.0000 0.0000 0.0000)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LabF64 {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl TryFrom<&str> for LabF64 {
+    type Error = ColorError;
+    fn try_from(lab_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(lab_str, "lab") {
+            if args.len() == 3 {
+                let l = parse_number_f64(&args[0], 100.0);
+                let a = parse_number_f64(&args[1], 125.0);
+                let b = parse_number_f64(&args[2], 125.0);
+                if let (Some(l), Some(a), Some(b)) = (l, a, b) {
+                    return (l, a, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "LabF64: {} format error!",
+            lab_str
+        )))
+    }
+}
+
+impl FromStr for LabF64 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for LabF64 {
+    type Error = ColorError;
+    fn try_from(value: (f64, f64, f64)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) {
+            Err(ColorError::ValueErr(format!(
+                "LabF64: args ({},{},{}) value error, the lightness must between 0~100!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<RGB> for LabF64 {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZF64 = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBF64> for LabF64 {
+    fn from(rgb: RGBF64) -> Self {
+        let xyz: XYZF64 = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZF64> for LabF64 {
+    fn from(xyz: XYZF64) -> Self {
+        let XYZF64 { x, y, z } = xyz;
+        let (l, a, b) = xyz_to_lab_f64(x, y, z);
+        Self { l, a, b }
+    }
+}
+
+impl From<Lab> for LabF64 {
+    fn from(lab: Lab) -> Self {
+        Self {
+            l: lab.l as f64,
+            a: lab.a as f64,
+            b: lab.b as f64,
+        }
+    }
+}
+
+impl Display for LabF64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lab({:.4} {:.4} {:.4})", self.l, self.a, self.b)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::common::{lab_to_lch, parse_fn_args, parse_hue, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Lab, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// LCh is the cylindrical form of the CIE Lab color space, so the hue can be changed while the perceived lightness stays the same.
+/// LCh can be parsed from a string in the CSS format "lch(l c h)" or "lch(l c h / a)", or from a tuple (l,c,h).
+/// LCh has no alpha channel, so the alpha of a string is ignored.
+/// * l:f32 - lightness(0~100), can also be written as a percentage in strings
+/// * c:f32 - chroma(0~about 150)
+/// * h:f32 - hue(0~360), can have a CSS angle unit in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{LCh, HSL, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let mut lch:LCh = rgb.into();
+/// assert_eq!(lch.to_string(), "lch(70.75 55.32 160.67)");
+///
+/// lch.rotate(180.0);
+/// let hsl:HSL = lch.into();
+/// assert_eq!(hsl.to_string(), "hsl(321,89%,75%)");
+///
+/// let lch:LCh = "lch(52% 70 130)".try_into().unwrap();
+/// assert_eq!(lch.to_string(), "lch(52.00 70.00 130.00)");
+/// let lch:LCh = "lch(52% 70 0.25turn / 0.5)".try_into().unwrap();
+/// assert_eq!(lch.to_string(), "lch(52.00 70.00 90.00)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LCh {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl TryFrom<&str> for LCh {
+    type Error = ColorError;
+    fn try_from(lch_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(lch_str, "lch") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let c = parse_number(&args[1], 150.0);
+                let h = parse_hue(&args[2]).or_else(|| parse_number(&args[2], 360.0));
+                if let (Some(l), Some(c), Some(h)) = (l, c, h) {
+                    return (l, c, h).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "LCh: {} format error!",
+            lch_str
+        )))
+    }
+}
+
+impl FromStr for LCh {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for LCh {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0)
+            || !(0.0..).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("LCh: args ({},{},{}) value error, the lightness must between 0~100, the chroma must not be negative and the hue must between 0~360!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                l: value.0,
+                c: value.1,
+                h: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for LCh {
+    fn from(hex: Hex) -> Self {
+        let lab: Lab = hex.into();
+        lab.into()
+    }
+}
+
+impl From<RGB> for LCh {
+    fn from(rgb: RGB) -> Self {
+        let lab: Lab = rgb.into();
+        lab.into()
+    }
+}
+
+impl From<RGBA> for LCh {
+    fn from(rgba: RGBA) -> Self {
+        let lab: Lab = rgba.into();
+        lab.into()
+    }
+}
+
+impl From<HSL> for LCh {
+    fn from(hsl: HSL) -> Self {
+        let lab: Lab = hsl.into();
+        lab.into()
+    }
+}
+
+impl From<HSLA> for LCh {
+    fn from(hsla: HSLA) -> Self {
+        let lab: Lab = hsla.into();
+        lab.into()
+    }
+}
+
+impl From<HSV> for LCh {
+    fn from(hsv: HSV) -> Self {
+        let lab: Lab = hsv.into();
+        lab.into()
+    }
+}
+
+impl From<CMYK> for LCh {
+    fn from(cmyk: CMYK) -> Self {
+        let lab: Lab = cmyk.into();
+        lab.into()
+    }
+}
+
+impl From<Lab> for LCh {
+    fn from(lab: Lab) -> Self {
+        let Lab { l, a, b } = lab;
+        let (l, c, h) = lab_to_lch(l, a, b);
+        Self { l, c, h }
+    }
+}
+
+impl Display for LCh {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lch({:.2} {:.2} {:.2})", self.l, self.c, self.h)
+    }
+}
+
+impl LCh {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn set_chroma(&mut self, chroma: f32) -> &mut Self {
+        self.c = chroma.max(0.0);
+        self
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+
+    /// Rotates the hue of the color by the given degrees, the lightness and chroma are kept.
+    ///
+    /// # Example
+    ///
+    /// ``` rust
+    /// use easy_color::LCh;
+    /// let mut color:LCh = (50.0, 40.0, 300.0).try_into().unwrap();
+    /// color.rotate(90.0);
+    /// assert_eq!(color.to_string(), "lch(50.00 40.00 30.00)");
+    /// ```
+    pub fn rotate(&mut self, degrees: f32) -> &mut Self {
+        self.h = (self.h + degrees).rem_euclid(360.0);
+        self
+    }
+}
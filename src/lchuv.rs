@@ -0,0 +1,162 @@
+use crate::common::{hpluv_to_lchuv, hsluv_to_lchuv, lab_to_lch, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, HPLuv, HSLuv, Hex, Luv, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// LCHuv is the cylindrical form of the CIE Luv color space.
+/// LCHuv can be parsed from a string in the format "lchuv(l c h)" or from a tuple (l,c,h).
+/// * l:f32 - lightness(0~100), can also be written as a percentage in strings
+/// * c:f32 - chroma(0~about 180)
+/// * h:f32 - hue(0~360)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{LCHuv, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let mut lchuv:LCHuv = rgb.into();
+/// assert_eq!(lchuv.to_string(), "lchuv(70.75 66.28 149.19)");
+///
+/// lchuv.set_chroma(30.0);
+/// let rgb:RGB = lchuv.into();
+/// assert_eq!(rgb.to_string(), "rgb(132,184,158)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LCHuv {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl TryFrom<&str> for LCHuv {
+    type Error = ColorError;
+    fn try_from(lchuv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(lchuv_str, "lchuv") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let c = parse_number(&args[1], 100.0);
+                let h = parse_number(&args[2], 360.0);
+                if let (Some(l), Some(c), Some(h)) = (l, c, h) {
+                    return (l, c, h).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "LCHuv: {} format error!",
+            lchuv_str
+        )))
+    }
+}
+
+impl FromStr for LCHuv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for LCHuv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0)
+            || !(0.0..).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("LCHuv: args ({},{},{}) value error, the lightness must between 0~100, the chroma must not be negative and the hue must between 0~360!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                l: value.0,
+                c: value.1,
+                h: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for LCHuv {
+    fn from(hex: Hex) -> Self {
+        let luv: Luv = hex.into();
+        luv.into()
+    }
+}
+
+impl From<RGB> for LCHuv {
+    fn from(rgb: RGB) -> Self {
+        let luv: Luv = rgb.into();
+        luv.into()
+    }
+}
+
+impl From<RGBA> for LCHuv {
+    fn from(rgba: RGBA) -> Self {
+        let luv: Luv = rgba.into();
+        luv.into()
+    }
+}
+
+impl From<XYZ> for LCHuv {
+    fn from(xyz: XYZ) -> Self {
+        let luv: Luv = xyz.into();
+        luv.into()
+    }
+}
+
+impl From<Luv> for LCHuv {
+    fn from(luv: Luv) -> Self {
+        let Luv { l, u, v } = luv;
+        let (l, c, h) = lab_to_lch(l, u, v);
+        Self { l, c, h }
+    }
+}
+
+impl From<HSLuv> for LCHuv {
+    fn from(hsluv: HSLuv) -> Self {
+        let HSLuv { h, s, l } = hsluv;
+        let (l, c, h) = hsluv_to_lchuv(h, s, l);
+        Self { l, c, h }
+    }
+}
+
+impl From<HPLuv> for LCHuv {
+    fn from(hpluv: HPLuv) -> Self {
+        let HPLuv { h, p, l } = hpluv;
+        let (l, c, h) = hpluv_to_lchuv(h, p, l);
+        Self { l, c, h }
+    }
+}
+
+impl Display for LCHuv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "lchuv({:.2} {:.2} {:.2})", self.l, self.c, self.h)
+    }
+}
+
+impl LCHuv {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn set_chroma(&mut self, chroma: f32) -> &mut Self {
+        self.c = chroma.max(0.0);
+        self
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+}
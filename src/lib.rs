@@ -28,47 +28,163 @@
 //! let hex:Hex = hsl.into();
 //! // or
 //! let hex = hsl.to_hex();
-//! assert_eq!(hex.to_string(), "#C2C1FF");
+//! assert_eq!(hex.to_string(), "#C4C4FF");
 //!
 //! // mix color
 //! let hsl:HSL = (0,0,0).try_into().unwrap();
 //! let rgba:RGBA = (255,255,255,1.0).try_into().unwrap();
-//! rgba.mix(hsl, None).to_string(); // rgba(127,127,127,1.00)
-//! rgba.mix(hsl, Some(0.35)).to_string(); // rgba(165,165,165,1.00)
+//! rgba.mix(hsl, None).to_string(); // rgba(128,128,128,1.00)
+//! rgba.mix(hsl, Some(0.35)).to_string(); // rgba(166,166,166,1.00)
 //! hsl.mix(rgba, None).to_string(); // hsl(0,0%,50%)
 //!
-//! // creat random color
-//! let rgb = RGB::random();
-//! let rgba = RGBA::random();
-//! let hsl = HSL::random();
-//!
 //! let hex:Hex = "#2bc48a".try_into().unwrap();
 //! let hex_str = hex.to_rgb().set_blue(255).to_hsl().set_lightness(50).to_cmyk().set_cyan(100).to_hex().to_string(); // #00B5FF
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("easy_color needs the `std` or the `libm` feature for its float math");
+
+// Always linked, even without std: parsing, ColorError and the palette helpers allocate.
+extern crate alloc;
+
+mod adobergb;
+mod ansi;
+mod blendmode;
+mod cam16;
 mod cmyk;
+mod color;
+mod colorfunction;
 mod common;
+mod deficiency;
+mod din99;
+mod displayp3;
+mod distancemetric;
+mod easing;
+#[cfg(feature = "egui")]
+mod egui;
+#[cfg(not(feature = "std"))]
+mod float;
+mod gradient;
+mod gray;
 mod hex;
+mod hpluv;
+mod hsi;
 mod hsl;
 mod hsla;
+mod hsluv;
 mod hsv;
+mod hsva;
+mod hunterlab;
+mod hwb;
+#[cfg(feature = "image")]
+mod image;
+mod ictcp;
+mod interpolationspace;
+mod jzazbz;
+mod kelvin;
+mod lab;
+mod labf64;
+mod lch;
+mod lchuv;
+mod linearrgb;
+mod luv;
+mod named;
+mod okhsl;
+mod okhsv;
+mod oklab;
+mod oklch;
+mod packedlayout;
+mod palette;
+#[cfg(feature = "palette-interop")]
+mod paletteinterop;
+#[cfg(not(feature = "std"))]
+mod prelude;
+mod premultipliedrgba;
+mod prophotorgb;
+mod rec2020;
 mod rgb;
 mod rgba;
+mod rgbf64;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+mod sgrcolor;
 mod traits;
+mod wcaglevel;
+mod whitepoint;
+mod xyy;
+mod ycbcr;
+mod ycocg;
+mod yiq;
+mod yuv;
+mod xyz;
+mod xyzf64;
 
+pub use adobergb::AdobeRGB;
+pub use ansi::ANSI_RESET;
+pub use blendmode::BlendMode;
+pub use cam16::{Cam16, ViewingConditions};
 pub use cmyk::CMYK;
-pub use common::ColorError;
+pub use color::AnyColor;
+pub use colorfunction::ColorFunction;
+pub use common::{linear_to_srgb, srgb_to_linear, ColorError};
+pub use deficiency::Deficiency;
+pub use din99::Din99;
+pub use displayp3::DisplayP3;
+pub use distancemetric::DistanceMetric;
+pub use easing::Easing;
+pub use gradient::Gradient;
+pub use gray::Gray;
 pub use hex::Hex;
+pub use hpluv::HPLuv;
+pub use hsi::HSI;
 pub use hsl::HSL;
 pub use hsla::HSLA;
+pub use hsluv::HSLuv;
 pub use hsv::HSV;
+pub use hsva::HSVA;
+pub use hunterlab::HunterLab;
+pub use hwb::HWB;
+pub use ictcp::ICtCp;
+pub use interpolationspace::InterpolationSpace;
+pub use jzazbz::Jzazbz;
+pub use kelvin::Kelvin;
+pub use lab::{Cie94Application, Lab};
+pub use labf64::LabF64;
+pub use lch::LCh;
+pub use lchuv::LCHuv;
+pub use linearrgb::LinearRGB;
+pub use luv::Luv;
+pub use okhsl::Okhsl;
+pub use okhsv::Okhsv;
+pub use oklab::Oklab;
+pub use oklch::Oklch;
+pub use packedlayout::PackedLayout;
+pub use palette::{CvdConflict, Palette};
+pub use premultipliedrgba::PremultipliedRGBA;
+pub use prophotorgb::ProPhotoRGB;
+pub use rec2020::Rec2020;
 pub use rgb::RGB;
 pub use rgba::RGBA;
+pub use rgbf64::RGBF64;
+pub use sgrcolor::SgrColor;
 pub use traits::*;
+pub use wcaglevel::{TextSize, WcagLevel};
+pub use whitepoint::WhitePoint;
+pub use xyy::XyY;
+pub use xyz::XYZ;
+pub use xyzf64::XYZF64;
+pub use ycbcr::{YCbCr, YCbCrStandard};
+pub use ycocg::YCoCg;
+pub use yiq::YIQ;
+pub use yuv::YUV;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
 
     #[test]
     fn it_works() {
@@ -137,4 +253,145 @@ mod tests {
         assert_eq!(cmyk.to_string(), "cmyk(64,0,24,20)");
 
     }
+
+    #[test]
+    fn round_trips() {
+        for r in (0..=255).step_by(3) {
+            for g in (0..=255).step_by(5) {
+                for b in (0..=255).step_by(7) {
+                    let rgb = RGB::new(r, g, b);
+                    assert_eq!(RGB::from(HSL::from(rgb)), rgb);
+                    assert_eq!(RGB::from(HSV::from(rgb)), rgb);
+                    assert_eq!(RGB::from(CMYK::from(rgb)), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn color_parse_keywords() {
+        let black = AnyColor::parse("black").unwrap();
+        assert_eq!(black, AnyColor::RGB(RGB::new(0, 0, 0)));
+        let pink = AnyColor::parse("pink").unwrap();
+        assert_eq!(pink, AnyColor::RGB(RGB::new(255, 192, 203)));
+        assert_eq!(AnyColor::parse("3200K").unwrap(), AnyColor::Kelvin(Kelvin(3200)));
+        assert_eq!(AnyColor::parse("6500 k").unwrap(), AnyColor::Kelvin(Kelvin(6500)));
+        assert!(AnyColor::parse("k").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        fn round_trip<T>(color: T)
+        where
+            T: ::serde::Serialize + for<'de> ::serde::Deserialize<'de> + PartialEq + core::fmt::Debug,
+        {
+            let json = serde_json::to_string(&color).unwrap();
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), color, "{}", json);
+        }
+        let rgb = RGB::new(43, 196, 138);
+        let ycbcr = YCbCr::from_rgb(rgb, YCbCrStandard::BT709);
+        round_trip(ycbcr);
+        let json = serde_json::to_string(&ycbcr).unwrap();
+        assert_eq!(serde_json::from_str::<YCbCr>(&json).unwrap().standard, YCbCrStandard::BT709);
+        round_trip(HSL { h: 157.3, s: 64.7, l: 47.2 });
+        round_trip(HSLA { hsl: HSL { h: 157.3, s: 64.7, l: 47.2 }, a: 0.25 });
+        round_trip(HSV::from(rgb));
+        round_trip(HSVA::from(RGBA::new(43, 196, 138, 0.5)));
+        round_trip(CMYK::from(rgb));
+        let dim = ViewingConditions::new(XYZ::D65, 16.0, 20.0, 1.0, false);
+        round_trip(Cam16::from_xyz(rgb.into(), dim));
+        round_trip(RGBF64 { r: 0.1 + 0.2, g: 1.0 / 3.0, b: 0.5 });
+        round_trip(XYZF64::from(RGBF64 { r: 0.1, g: 0.2, b: 0.3 }));
+        round_trip(LabF64::from(RGBF64 { r: 0.1, g: 0.2, b: 0.3 }));
+        let rgba = RGBA::new(43, 196, 138, 1.0 / 3.0);
+        round_trip(rgba);
+        round_trip(Lab::from(rgb));
+        round_trip(LCh::from(rgb));
+        round_trip(Oklab::from(rgb));
+        round_trip(Oklch::from(rgb));
+        round_trip(Okhsl::from(rgb));
+        round_trip(Okhsv::from(rgb));
+        round_trip(XYZ::from(rgb));
+        round_trip(XyY::from(rgb));
+        round_trip(Luv::from(rgb));
+        round_trip(LCHuv::from(rgb));
+        round_trip(HSLuv::from(rgb));
+        round_trip(HPLuv::from(rgb));
+        round_trip(YUV::from(rgb));
+        round_trip(YIQ::from(rgb));
+        round_trip(Jzazbz::from(rgb));
+        round_trip(ICtCp::from(rgb));
+        round_trip(Din99::from(rgb));
+        round_trip(HunterLab::from(rgb));
+        round_trip(LinearRGB::from(rgb));
+        round_trip(DisplayP3::from(rgb));
+        round_trip(AdobeRGB::from(rgb));
+        round_trip(Rec2020::from(rgb));
+        round_trip(ProPhotoRGB::from(rgb));
+        round_trip(ColorFunction::DisplayP3(rgb.into(), 1.0 / 3.0));
+        round_trip(AnyColor::from(rgba));
+        round_trip(AnyColor::from(Lab::from(rgb)));
+        round_trip(Hex::from(rgb));
+        round_trip(HWB::from(rgb));
+        let white = RGB::new(255, 255, 255);
+        round_trip(Lab::from(white));
+        round_trip(Oklab::from(white));
+        round_trip(Okhsl::from(white));
+        round_trip(XyY::from(white));
+        round_trip(HSLuv::from(white));
+        round_trip(YUV::from(white));
+        round_trip(Jzazbz::from(white));
+        round_trip(ICtCp::from(white));
+        let hsl: HSL = serde_json::from_str(r#""hsl(157 64% 47%)""#).unwrap();
+        assert_eq!(hsl.to_string(), "hsl(157,64%,47%)");
+    }
+
+    #[test]
+    fn delta_e2000_sharma() {
+        // the test data of G. Sharma, W. Wu and E. N. Dalal, "The CIEDE2000 Color-Difference Formula"
+        let pairs = [
+            ((50.0000, 2.6772, -79.7751), (50.0000, 0.0000, -82.7485), 2.0425),
+            ((50.0000, 3.1571, -77.2803), (50.0000, 0.0000, -82.7485), 2.8615),
+            ((50.0000, 2.8361, -74.0200), (50.0000, 0.0000, -82.7485), 3.4412),
+            ((50.0000, -1.3802, -84.2814), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -1.1848, -84.8006), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -0.9009, -85.5211), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, 0.0000, 0.0000), (50.0000, -1.0000, 2.0000), 2.3669),
+            ((50.0000, -1.0000, 2.0000), (50.0000, 0.0000, 0.0000), 2.3669),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0009), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0010), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0011), 7.2195),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0012), 7.2195),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0009, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0010, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0011, -2.4900), 4.7461),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 0.0000, -2.5000), 4.3065),
+            ((50.0000, 2.5000, 0.0000), (73.0000, 25.0000, -18.0000), 27.1492),
+            ((50.0000, 2.5000, 0.0000), (61.0000, -5.0000, 29.0000), 22.8977),
+            ((50.0000, 2.5000, 0.0000), (56.0000, -27.0000, -3.0000), 31.9030),
+            ((50.0000, 2.5000, 0.0000), (58.0000, 24.0000, 15.0000), 19.4535),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.1736, 0.5854), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2972, 0.0000), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 1.8634, 0.5757), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2592, 0.3350), 1.0000),
+            ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+            ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+            ((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731),
+            ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+            ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+            ((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146),
+            ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441),
+            ((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381),
+            ((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377),
+            ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lab1 = Lab { l: l1, a: a1, b: b1 };
+            let lab2 = Lab { l: l2, a: a2, b: b2 };
+            let de = lab1.delta_e2000(lab2);
+            assert!((de - expected).abs() < 1e-4, "{:?} {:?} {} != {}", lab1, lab2, de, expected);
+            assert!((lab2.delta_e2000(lab1) - expected).abs() < 1e-4);
+        }
+    }
 }
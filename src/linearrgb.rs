@@ -0,0 +1,181 @@
+use crate::common::{linear_rgb, linear_to_rgb, parse_fn_args, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// LinearRGB is sRGB without the gamma encoding, so the channels are proportional to the emitted light.
+/// Blending, averaging and lighting math should be done in it, doing the same on `RGB` gives muddy results.
+/// LinearRGB can be parsed from a string in the format "color(srgb-linear r g b)" or from a tuple (r,g,b).
+/// * r:f32 - linear red(0~1), can also be written as a percentage in strings
+/// * g:f32 - linear green(0~1), can also be written as a percentage in strings
+/// * b:f32 - linear blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{LinearRGB, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let linear = rgb.to_linear();
+/// assert_eq!(linear.to_string(), "color(srgb-linear 0.0242 0.5520 0.2542)");
+///
+/// let linear:LinearRGB = "color(srgb-linear 0.0242 0.5520 0.2542)".try_into().unwrap();
+/// assert_eq!(linear.to_srgb().to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LinearRGB {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for LinearRGB {
+    type Error = ColorError;
+    fn try_from(linear_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(linear_str, "color") {
+            if args.len() == 4 && args[0] == "srgb-linear" {
+                let r = parse_number(&args[1], 1.0);
+                let g = parse_number(&args[2], 1.0);
+                let b = parse_number(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "LinearRGB: {} format error!",
+            linear_str
+        )))
+    }
+}
+
+impl FromStr for LinearRGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for LinearRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "LinearRGB: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for LinearRGB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for LinearRGB {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (r, g, b) = linear_rgb(r, g, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<RGBA> for LinearRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for LinearRGB {
+    fn from(xyz: XYZ) -> Self {
+        let (r, g, b) = xyz.to_linear_rgb(&XYZ::XYZ_TO_SRGB);
+        Self {
+            r: r.clamp(0.0, 1.0),
+            g: g.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Display for LinearRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color(srgb-linear {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl LinearRGB {
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The linear channels(0~1) as a tuple, `TryFrom<(f32, f32, f32)>` goes the other way.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Encodes the color with the sRGB transfer function.
+    pub fn to_srgb(&self) -> RGB {
+        let (r, g, b) = linear_to_rgb(self.r, self.g, self.b);
+        RGB { r, g, b }
+    }
+
+    /// mix color in linear light
+    /// ### Arguments
+    /// * other - any struct that impl into LinearRGB
+    /// * weight: Option<f32> the mixed color`s weight, default is 0.5
+    /// ### Example
+    /// ```rust
+    /// use easy_color::{LinearRGB, RGB};
+    /// let red:RGB = (255, 0, 0).try_into().unwrap();
+    /// let green:RGB = (0, 255, 0).try_into().unwrap();
+    /// let mixed = red.to_linear().mix(green, None);
+    /// assert_eq!(mixed.to_srgb().to_string(), "rgb(188,188,0)");
+    /// ```
+    pub fn mix(&self, other: impl Into<Self>, weight: Option<f32>) -> Self {
+        let other: LinearRGB = other.into();
+        let p = weight.unwrap_or(0.5).clamp(0.0, 1.0);
+        Self {
+            r: self.r + (other.r - self.r) * p,
+            g: self.g + (other.g - self.g) * p,
+            b: self.b + (other.b - self.b) * p,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::common::{lch_to_lab, parse_fn_args, parse_number, xyz_to_luv};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, LCHuv, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Luv is the CIE L\*u\*v\* color space with a D65 reference white, it is often preferred for additive displays.
+/// Luv can be parsed from a string in the format "luv(l u v)" or from a tuple (l,u,v).
+/// * l:f32 - lightness(0~100), can also be written as a percentage in strings
+/// * u:f32 - green/red axis(about -84~176)
+/// * v:f32 - blue/yellow axis(about -135~108)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Luv, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let luv:Luv = rgb.into();
+/// assert_eq!(luv.to_string(), "luv(70.75 -56.93 33.95)");
+///
+/// let luv:Luv = "luv(70.75 -56.93 33.95)".try_into().unwrap();
+/// let rgb:RGB = luv.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Luv {
+    pub l: f32,
+    pub u: f32,
+    pub v: f32,
+}
+
+impl TryFrom<&str> for Luv {
+    type Error = ColorError;
+    fn try_from(luv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(luv_str, "luv") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 100.0);
+                let u = parse_number(&args[1], 100.0);
+                let v = parse_number(&args[2], 100.0);
+                if let (Some(l), Some(u), Some(v)) = (l, u, v) {
+                    return (l, u, v).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Luv: {} format error!",
+            luv_str
+        )))
+    }
+}
+
+impl FromStr for Luv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Luv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=100.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "Luv: args ({},{},{}) value error, the lightness must between 0~100!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                u: value.1,
+                v: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Luv {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for Luv {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for Luv {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for Luv {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let (l, u, v) = xyz_to_luv(x, y, z);
+        Self { l, u, v }
+    }
+}
+
+impl From<LCHuv> for Luv {
+    fn from(lchuv: LCHuv) -> Self {
+        let LCHuv { l, c, h } = lchuv;
+        let (l, u, v) = lch_to_lab(l, c, h);
+        Self { l, u, v }
+    }
+}
+
+impl Display for Luv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "luv({:.2} {:.2} {:.2})", self.l, self.u, self.v)
+    }
+}
+
+impl Luv {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 100.0);
+        self
+    }
+
+    pub fn u(&self) -> f32 {
+        self.u
+    }
+
+    pub fn set_u(&mut self, u: f32) -> &mut Self {
+        self.u = u;
+        self
+    }
+
+    pub fn v(&self) -> f32 {
+        self.v
+    }
+
+    pub fn set_v(&mut self, v: f32) -> &mut Self {
+        self.v = v;
+        self
+    }
+}
@@ -0,0 +1,171 @@
+use crate::common::{parse_fn_args, parse_number, rgb_to_okhsl};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Okhsl is Björn Ottosson's rework of HSL on top of Oklab, the hue and lightness follow the perception
+/// while the saturation still spans the whole sRGB gamut, which makes it a good fit for color pickers.
+/// Okhsl can be parsed from a string in the format "okhsl(h s% l%)" or from a tuple (h,s,l).
+/// * h:f32 - hue(0~360)
+/// * s:f32 - saturation(0~1), written as a percentage in strings
+/// * l:f32 - lightness(0~1), written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Okhsl, HSL, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let okhsl:Okhsl = rgb.into();
+/// assert_eq!(okhsl.to_string(), "okhsl(161.90 92.22% 68.58%)");
+///
+/// let mut okhsl:Okhsl = "okhsl(161.90 92.22% 68.58%)".try_into().unwrap();
+/// let rgb:RGB = okhsl.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// okhsl.set_hue(40.0);
+/// let hsl:HSL = okhsl.into();
+/// assert_eq!(hsl.to_string(), "hsl(16,93%,65%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Okhsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl TryFrom<&str> for Okhsl {
+    type Error = ColorError;
+    fn try_from(okhsl_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(okhsl_str, "okhsl") {
+            if args.len() == 3 {
+                let h = parse_number(&args[0], 360.0);
+                let s = parse_number(&args[1], 1.0);
+                let l = parse_number(&args[2], 1.0);
+                if let (Some(h), Some(s), Some(l)) = (h, s, l) {
+                    return (h, s, l).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Okhsl: {} format error!",
+            okhsl_str
+        )))
+    }
+}
+
+impl FromStr for Okhsl {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Okhsl {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("Okhsl: args ({},{},{}) value error, the hue must between 0~360, others must between 0~1!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                l: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Okhsl {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Okhsl {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, l) = rgb_to_okhsl(r, g, b);
+        Self { h, s, l }
+    }
+}
+
+impl From<RGBA> for Okhsl {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for Okhsl {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for Okhsl {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for Okhsl {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for Okhsl {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl Display for Okhsl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "okhsl({:.2} {:.2}% {:.2}%)",
+            self.h,
+            self.s * 100.0,
+            self.l * 100.0
+        )
+    }
+}
+
+impl Okhsl {
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) -> &mut Self {
+        self.s = saturation.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 1.0);
+        self
+    }
+}
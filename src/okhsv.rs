@@ -0,0 +1,171 @@
+use crate::common::{parse_fn_args, parse_number, rgb_to_okhsv};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Okhsv is Björn Ottosson's rework of HSV on top of Oklab, the hue follows the perception and a value of 1
+/// always reaches the edge of the sRGB gamut, just like the classic HSV picker.
+/// Okhsv can be parsed from a string in the format "okhsv(h s% v%)" or from a tuple (h,s,v).
+/// * h:f32 - hue(0~360)
+/// * s:f32 - saturation(0~1), written as a percentage in strings
+/// * v:f32 - value(0~1), written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Okhsv, HSV, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let okhsv:Okhsv = rgb.into();
+/// assert_eq!(okhsv.to_string(), "okhsv(161.90 89.07% 78.76%)");
+///
+/// let mut okhsv:Okhsv = "okhsv(161.90 89.07% 78.76%)".try_into().unwrap();
+/// let rgb:RGB = okhsv.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// okhsv.set_hue(40.0);
+/// let hsv:HSV = okhsv.into();
+/// assert_eq!(hsv.to_string(), "hsv(17,82%,78%)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Okhsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+impl TryFrom<&str> for Okhsv {
+    type Error = ColorError;
+    fn try_from(okhsv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(okhsv_str, "okhsv") {
+            if args.len() == 3 {
+                let h = parse_number(&args[0], 360.0);
+                let s = parse_number(&args[1], 1.0);
+                let v = parse_number(&args[2], 1.0);
+                if let (Some(h), Some(s), Some(v)) = (h, s, v) {
+                    return (h, s, v).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Okhsv: {} format error!",
+            okhsv_str
+        )))
+    }
+}
+
+impl FromStr for Okhsv {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Okhsv {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=360.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..=1.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("Okhsv: args ({},{},{}) value error, the hue must between 0~360, others must between 0~1!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                h: value.0,
+                s: value.1,
+                v: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Okhsv {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Okhsv {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (h, s, v) = rgb_to_okhsv(r, g, b);
+        Self { h, s, v }
+    }
+}
+
+impl From<RGBA> for Okhsv {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for Okhsv {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for Okhsv {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for Okhsv {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for Okhsv {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl Display for Okhsv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "okhsv({:.2} {:.2}% {:.2}%)",
+            self.h,
+            self.s * 100.0,
+            self.v * 100.0
+        )
+    }
+}
+
+impl Okhsv {
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+
+    pub fn saturation(&self) -> f32 {
+        self.s
+    }
+
+    pub fn set_saturation(&mut self, saturation: f32) -> &mut Self {
+        self.s = saturation.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn value(&self) -> f32 {
+        self.v
+    }
+
+    pub fn set_value(&mut self, value: f32) -> &mut Self {
+        self.v = value.clamp(0.0, 1.0);
+        self
+    }
+}
@@ -0,0 +1,251 @@
+use crate::common::{
+    lch_to_lab, linear_rgb_to_oklab, parse_fn_args, parse_number, rgb_to_oklab, xyz_to_linear_rgb,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, ColorError, Hex, Lab, Oklch, ProPhotoRGB, CMYK, HSL, HSLA, HSV, RGB, RGBA, XYZ,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Oklab is a perceptually uniform color space, so lightening, darkening and mixing in it
+/// gives more even results than doing the same in HSL.
+/// Oklab can be parsed from a string in the CSS format "oklab(l a b / a)" or from a tuple (l,a,b).
+/// Like CSS, a `none` component is treated as zero, and the alpha is ignored.
+/// * l:f32 - perceived lightness(0~1), can also be written as a percentage in strings
+/// * a:f32 - green/red axis(about -0.4~0.4)
+/// * b:f32 - blue/yellow axis(about -0.4~0.4)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Oklab, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let oklab:Oklab = rgb.into();
+/// assert_eq!(oklab.to_string(), "oklab(0.7297 -0.1424 0.0465)");
+///
+/// let mut oklab:Oklab = "oklab(0.7297 -0.1424 0.0465)".try_into().unwrap();
+/// let rgb:RGB = oklab.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// oklab.set_lightness(0.5);
+/// assert_eq!(oklab.to_string(), "oklab(0.5000 -0.1424 0.0465)");
+///
+/// let oklab:Oklab = "oklab(62% 0.1 none / 0.8)".try_into().unwrap();
+/// assert_eq!(oklab.to_string(), "oklab(0.6200 0.1000 0.0000)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for Oklab {
+    type Error = ColorError;
+    fn try_from(oklab_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(oklab_str, "oklab") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 1.0);
+                let a = parse_number(&args[1], 0.4);
+                let b = parse_number(&args[2], 0.4);
+                if let (Some(l), Some(a), Some(b)) = (l, a, b) {
+                    return (l, a, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Oklab: {} format error!",
+            oklab_str
+        )))
+    }
+}
+
+impl FromStr for Oklab {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Oklab {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0) || !value.1.is_finite() || !value.2.is_finite() {
+            Err(ColorError::ValueErr(format!(
+                "Oklab: args ({},{},{}) value error, the lightness must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                l: value.0,
+                a: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Oklab {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Oklab {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (l, a, b) = rgb_to_oklab(r, g, b);
+        Self { l, a, b }
+    }
+}
+
+impl From<RGBA> for Oklab {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for Oklab {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for Oklab {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for Oklab {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for Oklab {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let Oklch { l, c, h } = oklch;
+        let (l, a, b) = lch_to_lab(l, c, h);
+        Self { l, a, b }
+    }
+}
+
+impl From<Lab> for Oklab {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl From<AdobeRGB> for Oklab {
+    fn from(adobe: AdobeRGB) -> Self {
+        let XYZ { x, y, z } = adobe.into();
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        let (l, a, b) = linear_rgb_to_oklab(r, g, b);
+        Self { l, a, b }
+    }
+}
+
+impl From<ProPhotoRGB> for Oklab {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let XYZ { x, y, z } = prophoto.into();
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        let (l, a, b) = linear_rgb_to_oklab(r, g, b);
+        Self { l, a, b }
+    }
+}
+
+impl Display for Oklab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "oklab({:.4} {:.4} {:.4})", self.l, self.a, self.b)
+    }
+}
+
+impl Oklab {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn set_a(&mut self, a: f32) -> &mut Self {
+        self.a = a;
+        self
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_b(&mut self, b: f32) -> &mut Self {
+        self.b = b;
+        self
+    }
+
+    /// Darkens the color by reducing its perceived lightness by the given ratio.
+    /// ```rust
+    /// use easy_color::Oklab;
+    /// let mut oklab:Oklab = (0.8, 0.1, 0.1).try_into().unwrap();
+    /// oklab.darken(0.25);
+    /// assert_eq!(oklab.to_string(), "oklab(0.6000 0.1000 0.1000)");
+    /// ```
+    pub fn darken(&mut self, ratio: f32) -> &mut Self {
+        self.l = (self.l - self.l * ratio).clamp(0.0, 1.0);
+        self
+    }
+
+    /// Lightens the color by increasing its perceived lightness by the given ratio.
+    /// ```rust
+    /// use easy_color::Oklab;
+    /// let mut oklab:Oklab = (0.5, 0.1, 0.1).try_into().unwrap();
+    /// oklab.lighten(0.5);
+    /// assert_eq!(oklab.to_string(), "oklab(0.7500 0.1000 0.1000)");
+    /// ```
+    pub fn lighten(&mut self, ratio: f32) -> &mut Self {
+        self.l = (self.l + self.l * ratio).clamp(0.0, 1.0);
+        self
+    }
+
+    /// mix color in Oklab space
+    /// ### Arguments
+    /// * other - any struct that impl into Oklab
+    /// * weight: Option<f32> the mixed color`s weight, default is 0.5
+    /// ### Example
+    /// ```rust
+    /// use easy_color::{Oklab, RGB};
+    /// let black:RGB = (0, 0, 0).try_into().unwrap();
+    /// let oklab:Oklab = (1.0, 0.0, 0.0).try_into().unwrap();
+    /// assert_eq!(oklab.mix(black, None).to_string(), "oklab(0.5000 0.0000 0.0000)");
+    /// assert_eq!(oklab.mix(black, Some(0.25)).to_string(), "oklab(0.7500 0.0000 0.0000)");
+    /// ```
+    pub fn mix(&self, other: impl Into<Self>, weight: Option<f32>) -> Self {
+        let other: Oklab = other.into();
+        let p = weight.unwrap_or(0.5).clamp(0.0, 1.0);
+        Self {
+            l: self.l + (other.l - self.l) * p,
+            a: self.a + (other.a - self.a) * p,
+            b: self.b + (other.b - self.b) * p,
+        }
+    }
+}
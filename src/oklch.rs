@@ -0,0 +1,182 @@
+use crate::common::{lab_to_lch, parse_fn_args, parse_hue, parse_number};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Lab, Oklab, CMYK, HSL, HSLA, HSV, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Oklch is the cylindrical form of Oklab, which makes it easy to design palettes with an even perceived lightness.
+/// Oklch can be parsed from a string in the CSS format "oklch(l c h / a)" or from a tuple (l,c,h).
+/// Like CSS, a `none` component is treated as zero, and the alpha is ignored.
+/// * l:f32 - perceived lightness(0~1), can also be written as a percentage in strings
+/// * c:f32 - chroma(0~about 0.4)
+/// * h:f32 - hue(0~360), can have a CSS angle unit in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Oklch, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let oklch:Oklch = rgb.into();
+/// assert_eq!(oklch.to_string(), "oklch(0.7297 0.1498 161.90)");
+///
+/// let mut oklch:Oklch = "oklch(0.7297 0.1498 161.90)".try_into().unwrap();
+/// oklch.set_hue(40.0);
+/// let rgb:RGB = oklch.into();
+/// assert_eq!(rgb.to_string(), "rgb(245,131,91)");
+///
+/// // achromatic colors are often serialized with a `none` hue
+/// let oklch:Oklch = "oklch(0.7 0 none)".try_into().unwrap();
+/// assert_eq!(oklch.to_string(), "oklch(0.7000 0.0000 0.00)");
+/// let oklch:Oklch = "oklch(70% 0.15 145deg / 0.5)".try_into().unwrap();
+/// assert_eq!(oklch.to_string(), "oklch(0.7000 0.1500 145.00)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl TryFrom<&str> for Oklch {
+    type Error = ColorError;
+    fn try_from(oklch_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(oklch_str, "oklch") {
+            if args.len() == 3 {
+                let l = parse_number(&args[0], 1.0);
+                let c = parse_number(&args[1], 0.4);
+                let h = parse_hue(&args[2]).or_else(|| parse_number(&args[2], 360.0));
+                if let (Some(l), Some(c), Some(h)) = (l, c, h) {
+                    return (l, c, h).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Oklch: {} format error!",
+            oklch_str
+        )))
+    }
+}
+
+impl FromStr for Oklch {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Oklch {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..).contains(&value.1)
+            || !(0.0..=360.0).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("Oklch: args ({},{},{}) value error, the lightness must between 0~1, the chroma must not be negative and the hue must between 0~360!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                l: value.0,
+                c: value.1,
+                h: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Oklch {
+    fn from(hex: Hex) -> Self {
+        let oklab: Oklab = hex.into();
+        oklab.into()
+    }
+}
+
+impl From<RGB> for Oklch {
+    fn from(rgb: RGB) -> Self {
+        let oklab: Oklab = rgb.into();
+        oklab.into()
+    }
+}
+
+impl From<RGBA> for Oklch {
+    fn from(rgba: RGBA) -> Self {
+        let oklab: Oklab = rgba.into();
+        oklab.into()
+    }
+}
+
+impl From<HSL> for Oklch {
+    fn from(hsl: HSL) -> Self {
+        let oklab: Oklab = hsl.into();
+        oklab.into()
+    }
+}
+
+impl From<HSLA> for Oklch {
+    fn from(hsla: HSLA) -> Self {
+        let oklab: Oklab = hsla.into();
+        oklab.into()
+    }
+}
+
+impl From<HSV> for Oklch {
+    fn from(hsv: HSV) -> Self {
+        let oklab: Oklab = hsv.into();
+        oklab.into()
+    }
+}
+
+impl From<CMYK> for Oklch {
+    fn from(cmyk: CMYK) -> Self {
+        let oklab: Oklab = cmyk.into();
+        oklab.into()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { l, a, b } = oklab;
+        let (l, c, h) = lab_to_lch(l, a, b);
+        Self { l, c, h }
+    }
+}
+
+impl From<Lab> for Oklch {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        rgb.into()
+    }
+}
+
+impl Display for Oklch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "oklch({:.4} {:.4} {:.2})", self.l, self.c, self.h)
+    }
+}
+
+impl Oklch {
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    pub fn set_lightness(&mut self, lightness: f32) -> &mut Self {
+        self.l = lightness.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn chroma(&self) -> f32 {
+        self.c
+    }
+
+    pub fn set_chroma(&mut self, chroma: f32) -> &mut Self {
+        self.c = chroma.max(0.0);
+        self
+    }
+
+    pub fn hue(&self) -> f32 {
+        self.h
+    }
+
+    pub fn set_hue(&mut self, hue: f32) -> &mut Self {
+        self.h = hue.clamp(0.0, 360.0);
+        self
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::prelude::*;
+/// The order of the channels of a color packed into a `u32`, one byte per channel.
+/// * Rgb - 0xRRGGBB, the highest byte is ignored and the color is opaque
+/// * Argb - 0xAARRGGBB, used by win32, Android and most game engines
+/// * Rgba - 0xRRGGBBAA, the order of a `#RRGGBBAA` hex string
+///
+/// ### example
+/// ```rust
+/// use easy_color::{PackedLayout, RGBA};
+/// let rgba = RGBA::from_u32_with(0x2BC48A80, PackedLayout::Rgba);
+/// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+/// assert_eq!(rgba.to_u32_with(PackedLayout::Argb), 0x802BC48A);
+/// assert_eq!(rgba.to_u32_with(PackedLayout::Rgb), 0x2BC48A);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PackedLayout {
+    Rgb,
+    Argb,
+    Rgba,
+}
+
+impl PackedLayout {
+    pub(crate) fn pack(&self, rgba: (u8, u8, u8, f32)) -> u32 {
+        let (r, g, b, a) = rgba;
+        let (r, g, b, a) = (r as u32, g as u32, b as u32, (a * 255.0).round() as u32);
+        match self {
+            Self::Rgb => r << 16 | g << 8 | b,
+            Self::Argb => a << 24 | r << 16 | g << 8 | b,
+            Self::Rgba => r << 24 | g << 16 | b << 8 | a,
+        }
+    }
+
+    pub(crate) fn unpack(&self, value: u32) -> (u8, u8, u8, f32) {
+        let [b0, b1, b2, b3] = value.to_be_bytes();
+        match self {
+            Self::Rgb => (b1, b2, b3, 1.0),
+            Self::Argb => (b1, b2, b3, b0 as f32 / 255.0),
+            Self::Rgba => (b0, b1, b2, b3 as f32 / 255.0),
+        }
+    }
+}
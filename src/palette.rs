@@ -0,0 +1,125 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{AnyColor, ColorVision, Deficiency, DeltaE, DistanceMetric, RGBA};
+
+/// Palette is a fixed list of colors, such as the colors an e-ink display or a brand can show,
+/// other colors can be mapped onto it with `nearest`.
+/// ### example
+/// ```rust
+/// use easy_color::{AnyColor, DistanceMetric, Hex, Palette, RGB};
+/// let palette = Palette::new([
+///     Hex::try_from("#000000").unwrap(),
+///     Hex::try_from("#FFFFFF").unwrap(),
+///     Hex::try_from("#FF0000").unwrap(),
+///     Hex::try_from("#FF0000").unwrap(),
+/// ]);
+/// assert_eq!(palette.len(), 4);
+///
+/// let orange:RGB = (255, 140, 0).try_into().unwrap();
+/// let (index, color) = palette.nearest(orange, DistanceMetric::DeltaE2000).unwrap();
+/// assert_eq!(index, 2);
+/// assert_eq!(color.to_string(), "#FF0000");
+///
+/// let gold:RGB = (255, 215, 0).try_into().unwrap();
+/// let (index, _) = palette.nearest(gold, DistanceMetric::Redmean).unwrap();
+/// assert_eq!(index, 1);
+///
+/// assert_eq!(Palette::new(Vec::<AnyColor>::new()).nearest(orange, DistanceMetric::Oklab), None);
+/// ```
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Palette {
+    colors: Vec<AnyColor>,
+}
+
+/// Two entries of a palette that are told apart with normal vision but not with a color vision deficiency,
+/// see `Palette::cvd_conflicts`. `first` and `second` are the indexes of the entries, `first` < `second`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CvdConflict {
+    pub deficiency: Deficiency,
+    pub first: usize,
+    pub second: usize,
+    /// The CIEDE2000 difference of the two simulated colors.
+    pub delta_e: f32,
+}
+
+impl Palette {
+    pub fn new<C: Into<AnyColor>>(colors: impl IntoIterator<Item = C>) -> Self {
+        Self {
+            colors: colors.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn colors(&self) -> &[AnyColor] {
+        &self.colors
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns the index and the entry of the palette closest to `color` by the given metric,
+    /// or `None` when the palette is empty. The first entry wins a tie.
+    pub fn nearest(
+        &self,
+        color: impl Into<RGBA>,
+        metric: DistanceMetric,
+    ) -> Option<(usize, AnyColor)> {
+        let color: RGBA = color.into();
+        self.colors
+            .iter()
+            .map(|c| metric.distance(color, *c))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| (i, self.colors[i]))
+    }
+
+    /// Lints the palette for color blind users: returns the pairs of entries whose CIEDE2000 difference is at least
+    /// `threshold` with normal vision but falls below it under the full simulation of any `Deficiency`.
+    /// A threshold of about 10 keeps chart series apart at a glance. Translucent entries are compared as they
+    /// look on white, see `DeltaE`.
+    /// ```rust
+    /// use easy_color::{Deficiency, Hex, Palette};
+    /// let palette = Palette::new([
+    ///     Hex::try_from("#D62728").unwrap(),
+    ///     Hex::try_from("#2CA02C").unwrap(),
+    ///     Hex::try_from("#1F77B4").unwrap(),
+    /// ]);
+    /// let conflicts = palette.cvd_conflicts(10.0);
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].deficiency, Deficiency::Deuteranopia);
+    /// assert_eq!((conflicts[0].first, conflicts[0].second), (0, 1));
+    /// ```
+    pub fn cvd_conflicts(&self, threshold: f32) -> Vec<CvdConflict> {
+        let colors = self
+            .colors
+            .iter()
+            .map(|c| RGBA::from(*c))
+            .collect::<Vec<_>>();
+        let mut conflicts = Vec::new();
+        for deficiency in Deficiency::ALL {
+            let simulated = colors
+                .iter()
+                .map(|c| c.simulate_cvd(deficiency, 1.0))
+                .collect::<Vec<_>>();
+            for first in 0..colors.len() {
+                for second in first + 1..colors.len() {
+                    let delta_e = simulated[first].delta_e2000(simulated[second]);
+                    if delta_e < threshold && colors[first].delta_e2000(colors[second]) >= threshold
+                    {
+                        conflicts.push(CvdConflict {
+                            deficiency,
+                            first,
+                            second,
+                            delta_e,
+                        });
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+}
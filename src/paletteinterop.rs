@@ -0,0 +1,98 @@
+//! Conversions between the color types and the `palette` crate, enabled by the `palette-interop` feature.
+//! * `Srgb<u8>` and `Srgb<f32>` - `RGB`, the float channels(0~1) are checked
+//! * `Hsl` - `HSL`, the saturation and lightness are scaled between 0~1 and 0~100
+//! * `Lab` - `Lab`, both use the D65 white point
+//! * `Laba` - `RGBA`, through `Lab`
+//! ```rust
+//! use easy_color::{HSL, Lab, RGB, RGBA};
+//! use palette::{Hsl, Laba, Srgb};
+//! let rgb:RGB = Srgb::new(43u8, 196, 138).into();
+//! assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+//! let srgb:Srgb = rgb.into();
+//! assert_eq!(RGB::try_from(srgb).unwrap(), rgb);
+//! assert!(RGB::try_from(Srgb::new(1.5, 0.0, 0.0)).is_err());
+//!
+//! let hsl:HSL = Hsl::new(-90.0, 1.0, 0.5).into();
+//! assert_eq!(hsl.to_string(), "hsl(270,100%,50%)");
+//! let hsl:Hsl = HSL::from(rgb).into();
+//! assert_eq!(format!("{:.0}", hsl.hue.into_positive_degrees()), "157");
+//!
+//! let rgba:RGBA = Laba::new(70.75, -52.2, 18.31, 0.5).into();
+//! assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+//! let laba:Laba = rgba.into();
+//! assert_eq!(Lab::from(laba.color).to_string(), "lab(70.75 -52.20 18.31)");
+//! ```
+use crate::{ColorError, Lab, HSL, RGB, RGBA};
+use ::palette::{Hsl, Laba, Srgb};
+
+impl From<Srgb<u8>> for RGB {
+    fn from(srgb: Srgb<u8>) -> Self {
+        Self::new(srgb.red, srgb.green, srgb.blue)
+    }
+}
+
+impl From<RGB> for Srgb<u8> {
+    fn from(rgb: RGB) -> Self {
+        Srgb::new(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+impl TryFrom<Srgb> for RGB {
+    type Error = ColorError;
+    fn try_from(srgb: Srgb) -> Result<Self, Self::Error> {
+        (srgb.red, srgb.green, srgb.blue).try_into()
+    }
+}
+
+impl From<RGB> for Srgb {
+    fn from(rgb: RGB) -> Self {
+        let (r, g, b) = rgb.to_f32_tuple();
+        Srgb::new(r, g, b)
+    }
+}
+
+impl From<Hsl> for HSL {
+    fn from(hsl: Hsl) -> Self {
+        Self {
+            h: hsl.hue.into_positive_degrees(),
+            s: (hsl.saturation * 100.0).clamp(0.0, 100.0),
+            l: (hsl.lightness * 100.0).clamp(0.0, 100.0),
+        }
+    }
+}
+
+impl From<HSL> for Hsl {
+    fn from(hsl: HSL) -> Self {
+        Hsl::new(hsl.h, hsl.s / 100.0, hsl.l / 100.0)
+    }
+}
+
+impl From<::palette::Lab> for Lab {
+    fn from(lab: ::palette::Lab) -> Self {
+        Self {
+            l: lab.l,
+            a: lab.a,
+            b: lab.b,
+        }
+    }
+}
+
+impl From<Lab> for ::palette::Lab {
+    fn from(lab: Lab) -> Self {
+        ::palette::Lab::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<Laba> for RGBA {
+    fn from(laba: Laba) -> Self {
+        let rgb: RGB = Lab::from(laba.color).into();
+        Self::new(rgb.r, rgb.g, rgb.b, laba.alpha)
+    }
+}
+
+impl From<RGBA> for Laba {
+    fn from(rgba: RGBA) -> Self {
+        let lab: Lab = rgba.rgb.into();
+        Laba::new(lab.l, lab.a, lab.b, rgba.a)
+    }
+}
@@ -0,0 +1,5 @@
+//! The items which the std prelude provides, imported by the modules when the crate is built without std.
+pub use crate::float::Float;
+pub use alloc::format;
+pub use alloc::string::{String, ToString};
+pub use alloc::vec::Vec;
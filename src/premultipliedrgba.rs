@@ -0,0 +1,52 @@
+use crate::RGBA;
+
+/// PremultipliedRGBA holds the channels of an `RGBA` already multiplied by its alpha, the form GPU blending expects.
+/// All values are 0~1. A fully transparent color keeps no color information, it turns back into `rgba(0,0,0,0.00)`.
+/// * r:f32 - red multiplied by alpha(0~a)
+/// * g:f32 - green multiplied by alpha(0~a)
+/// * b:f32 - blue multiplied by alpha(0~a)
+/// * a:f32 - alpha(0~1)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{PremultipliedRGBA, RGBA};
+/// let rgba:RGBA = (255, 128, 0, 0.5).try_into().unwrap();
+/// let pre = rgba.premultiply();
+/// assert_eq!(pre.to_array(), [0.5, 0.2509804, 0.0, 0.5]);
+/// assert_eq!(pre.unpremultiply(), rgba);
+///
+/// let clear:RGBA = (255, 128, 0, 0.0).try_into().unwrap();
+/// assert_eq!(clear.premultiply().unpremultiply().to_string(), "rgba(0,0,0,0.00)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct PremultipliedRGBA {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl From<RGBA> for PremultipliedRGBA {
+    fn from(rgba: RGBA) -> Self {
+        let a = rgba.a;
+        let scale = |v: u8| v as f32 / 255.0 * a;
+        Self {
+            r: scale(rgba.r),
+            g: scale(rgba.g),
+            b: scale(rgba.b),
+            a,
+        }
+    }
+}
+
+impl PremultipliedRGBA {
+    /// Divides the alpha back out of the channels, a zero alpha gives transparent black.
+    pub fn unpremultiply(&self) -> RGBA {
+        (*self).into()
+    }
+
+    /// The channels as `[r, g, b, a]`, ready for a vertex or uniform buffer.
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
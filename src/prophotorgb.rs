@@ -0,0 +1,233 @@
+use crate::common::{
+    linear_rgb_to_xyz, linear_to_prophoto, mul_matrix3, oklab_to_linear_rgb, parse_fn_args,
+    parse_number, prophoto_to_linear, D65_TO_D50, PROPHOTO_TO_XYZ, XYZ_TO_PROPHOTO,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, Lab, Oklab, CMYK, HSL, HSLA, HSV, HSVA, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// ProPhotoRGB is the very wide gamut RGB space used by Lightroom, it is defined relative to the D50 white point,
+/// so the conversions adapt it to D65 with the Bradford transform before reaching the other types.
+/// Colors outside of the sRGB gamut are clipped when converted into `RGB` and the types built on it(HSL, HSV, CMYK),
+/// `Lab` and `Oklab` go through XYZ and keep them.
+/// ProPhotoRGB can be parsed from a string in the format "color(prophoto-rgb r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red(0~1), can also be written as a percentage in strings
+/// * g:f32 - green(0~1), can also be written as a percentage in strings
+/// * b:f32 - blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Lab, Oklab, ProPhotoRGB, CMYK, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let prophoto:ProPhotoRGB = rgb.into();
+/// assert_eq!(prophoto.to_string(), "color(prophoto-rgb 0.4428 0.6741 0.4981)");
+///
+/// let prophoto:ProPhotoRGB = "color(prophoto-rgb 0.4428 0.6741 0.4981)".try_into().unwrap();
+/// let rgb:RGB = prophoto.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// let cmyk:CMYK = prophoto.into();
+/// assert_eq!(cmyk.to_string(), "cmyk(78,0,30,23)");
+/// let red:ProPhotoRGB = (1.0, 0.0, 0.0).try_into().unwrap();
+/// let lab:Lab = red.into();
+/// assert_eq!(lab.to_string(), "lab(58.82 140.69 95.81)");
+/// let oklab:Oklab = red.into();
+/// assert_eq!(ProPhotoRGB::from(oklab).to_string(), "color(prophoto-rgb 1.0000 0.0000 0.0000)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ProPhotoRGB {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for ProPhotoRGB {
+    type Error = ColorError;
+    fn try_from(prophoto_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(prophoto_str, "color") {
+            if args.len() == 4 && args[0] == "prophoto-rgb" {
+                let r = parse_number(&args[1], 1.0);
+                let g = parse_number(&args[2], 1.0);
+                let b = parse_number(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "ProPhotoRGB: {} format error!",
+            prophoto_str
+        )))
+    }
+}
+
+impl FromStr for ProPhotoRGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for ProPhotoRGB {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "ProPhotoRGB: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for ProPhotoRGB {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for ProPhotoRGB {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for ProPhotoRGB {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for ProPhotoRGB {
+    fn from(xyz: XYZ) -> Self {
+        let d50 = mul_matrix3(&D65_TO_D50, (xyz.x, xyz.y, xyz.z));
+        let (r, g, b) = mul_matrix3(&XYZ_TO_PROPHOTO, d50);
+        Self {
+            r: linear_to_prophoto(r).clamp(0.0, 1.0),
+            g: linear_to_prophoto(g).clamp(0.0, 1.0),
+            b: linear_to_prophoto(b).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<HSL> for ProPhotoRGB {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for ProPhotoRGB {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for ProPhotoRGB {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<HSVA> for ProPhotoRGB {
+    fn from(hsva: HSVA) -> Self {
+        let rgb: RGB = hsva.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for ProPhotoRGB {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for ProPhotoRGB {
+    fn from(lab: Lab) -> Self {
+        let xyz: XYZ = lab.into();
+        xyz.into()
+    }
+}
+
+impl From<Oklab> for ProPhotoRGB {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { l, a, b } = oklab;
+        let (r, g, b) = oklab_to_linear_rgb(l, a, b);
+        let (x, y, z) = linear_rgb_to_xyz(r, g, b);
+        XYZ { x, y, z }.into()
+    }
+}
+
+impl Display for ProPhotoRGB {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color(prophoto-rgb {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl ProPhotoRGB {
+    /// The matrix converting linear ProPhoto RGB into XYZ relative to D50.
+    pub const TO_XYZ: [[f32; 3]; 3] = PROPHOTO_TO_XYZ;
+
+    /// The matrix converting XYZ relative to D50 into linear ProPhoto RGB.
+    pub const FROM_XYZ: [[f32; 3]; 3] = XYZ_TO_PROPHOTO;
+
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The encoded channels(0~1) as a tuple, `TryFrom<(f32, f32, f32)>` goes the other way.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the channels without the transfer function applied.
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        (
+            prophoto_to_linear(self.r),
+            prophoto_to_linear(self.g),
+            prophoto_to_linear(self.b),
+        )
+    }
+}
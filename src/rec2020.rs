@@ -0,0 +1,183 @@
+use crate::common::{
+    ictcp_to_linear_rec2020, linear_to_rec2020, parse_fn_args, parse_number, rec2020_to_linear,
+    REC2020_TO_XYZ, XYZ_TO_REC2020,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, ICtCp, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Rec2020 is the ITU-R BT.2020 RGB space of UHD and HDR video, its primaries cover most of the visible colors.
+/// Colors outside of the sRGB gamut are clipped when converted into `RGB`.
+/// Rec2020 can be parsed from a string in the format "color(rec2020 r g b)" or from a tuple (r,g,b).
+/// * r:f32 - red(0~1), can also be written as a percentage in strings
+/// * g:f32 - green(0~1), can also be written as a percentage in strings
+/// * b:f32 - blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Rec2020, RGB};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let rec2020:Rec2020 = rgb.into();
+/// assert_eq!(rec2020.to_string(), "color(rec2020 0.4429 0.7142 0.5172)");
+///
+/// let rec2020:Rec2020 = "color(rec2020 0.4429 0.7142 0.5172)".try_into().unwrap();
+/// let rgb:RGB = rec2020.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Rec2020 {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl TryFrom<&str> for Rec2020 {
+    type Error = ColorError;
+    fn try_from(rec2020_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(rec2020_str, "color") {
+            if args.len() == 4 && args[0] == "rec2020" {
+                let r = parse_number(&args[1], 1.0);
+                let g = parse_number(&args[2], 1.0);
+                let b = parse_number(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "Rec2020: {} format error!",
+            rec2020_str
+        )))
+    }
+}
+
+impl FromStr for Rec2020 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for Rec2020 {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "Rec2020: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for Rec2020 {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for Rec2020 {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for Rec2020 {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<XYZ> for Rec2020 {
+    fn from(xyz: XYZ) -> Self {
+        let (r, g, b) = xyz.to_linear_rgb(&XYZ_TO_REC2020);
+        Self {
+            r: linear_to_rec2020(r).clamp(0.0, 1.0),
+            g: linear_to_rec2020(g).clamp(0.0, 1.0),
+            b: linear_to_rec2020(b).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl From<ICtCp> for Rec2020 {
+    fn from(ictcp: ICtCp) -> Self {
+        let ICtCp { i, ct, cp } = ictcp;
+        let (r, g, b) = ictcp_to_linear_rec2020(i, ct, cp);
+        Self {
+            r: linear_to_rec2020(r).clamp(0.0, 1.0),
+            g: linear_to_rec2020(g).clamp(0.0, 1.0),
+            b: linear_to_rec2020(b).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Display for Rec2020 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "color(rec2020 {:.4} {:.4} {:.4})",
+            self.r, self.g, self.b
+        )
+    }
+}
+
+impl Rec2020 {
+    /// The matrix converting linear Rec.2020 into XYZ.
+    pub const TO_XYZ: [[f32; 3]; 3] = REC2020_TO_XYZ;
+
+    /// The matrix converting XYZ into linear Rec.2020.
+    pub const FROM_XYZ: [[f32; 3]; 3] = XYZ_TO_REC2020;
+
+    pub fn red(&self) -> f32 {
+        self.r
+    }
+
+    pub fn set_red(&mut self, red: f32) -> &mut Self {
+        self.r = red.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn green(&self) -> f32 {
+        self.g
+    }
+
+    pub fn set_green(&mut self, green: f32) -> &mut Self {
+        self.g = green.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn blue(&self) -> f32 {
+        self.b
+    }
+
+    pub fn set_blue(&mut self, blue: f32) -> &mut Self {
+        self.b = blue.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The encoded channels(0~1) as a tuple, `TryFrom<(f32, f32, f32)>` goes the other way.
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the channels without the transfer function applied.
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        (
+            rec2020_to_linear(self.r),
+            rec2020_to_linear(self.g),
+            rec2020_to_linear(self.b),
+        )
+    }
+}
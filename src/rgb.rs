@@ -1,11 +1,27 @@
-use crate::common::{calc_rgb_with_alpha, cmyk_to_rgb, hsl_to_rgb, hsv_to_rgb};
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGBA};
-use std::fmt::{Display, Formatter};
+use crate::ansi::{ansi256_to_rgb, ANSI16};
+use crate::common::{
+    calc_rgb_with_alpha, cmyk_to_rgb, hsi_to_rgb, hsl_to_rgb, hsv_to_rgb, hwb_to_rgb,
+    kelvin_to_rgb, lab_to_rgb, okhsl_to_rgb, okhsv_to_rgb, oklab_to_rgb, parse_fn_args_alpha,
+    parse_rgb_channel, relative_luminance, xyz_to_rgb, ycbcr_to_rgb, ycocg_to_rgb, yiq_to_rgb,
+    yuv_to_rgb,
+};
+use crate::named::named_color;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, Cam16, ColorError, ColorFunction, Din99, DisplayP3, Gray, HPLuv, HSLuv, Hex,
+    HunterLab, ICtCp, Jzazbz, Kelvin, LCHuv, LCh, Lab, LabF64, LinearRGB, Luv, Okhsl, Okhsv, Oklab,
+    Oklch, PackedLayout, ProPhotoRGB, Rec2020, SgrColor, XyY, YCbCr, YCbCrStandard, YCoCg, CMYK,
+    HSI, HSL, HSLA, HSV, HSVA, HWB, RGBA, RGBF64, XYZ, XYZF64, YIQ, YUV,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
-/// RGB can be parsed from a string in the format "rgb(r,g,b)" or from a tuple (r,g,b).
+/// RGB can be parsed from a string in the format "rgb(r,g,b)" or "rgb(r g b / a)", from a CSS named color, or from a tuple (r,g,b).
 /// * r:u8 - red value(0~255)
 /// * g:u8 - green value(0~255)
 /// * b:u8 - blue value(0~255)
+///
 /// The red, green, and blue values can be individually set or retrieved.
 /// ### example
 /// ```rust
@@ -15,6 +31,20 @@ use std::fmt::{Display, Formatter};
 /// assert_eq!(rgb.to_string(), "rgb(43,255,138)");
 /// let green = rgb.green(); // 255
 ///
+/// // the modern space separated syntax, a translucent color is blended onto white
+/// let rgb:RGB = "rgb(43 196 138)".try_into().unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// let rgb:RGB = "rgb(255 0 0 / 50%)".try_into().unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(255,127,127)");
+/// let rgb:RGB = "rgb(100%, 0%, 25%)".try_into().unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(255,0,64)");
+/// let rgb:RGB = "rgb(10.5 20 30)".try_into().unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(11,20,30)");
+///
+/// // the CSS named colors
+/// let rgb:RGB = "rebeccapurple".try_into().unwrap();
+/// assert_eq!(rgb.to_string(), "rgb(102,51,153)");
+///
 /// let rgb:RGB = (43, 196, 138).try_into().unwrap();
 /// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
 ///
@@ -31,21 +61,22 @@ pub struct RGB {
 impl TryFrom<&str> for RGB {
     type Error = ColorError;
     fn try_from(rgb_str: &str) -> Result<Self, Self::Error> {
-        let mut color = rgb_str.trim().to_lowercase();
-        if color.starts_with("rgb(") && color.ends_with(')') {
-            color = color.replace("rgb(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 3 {
-                let val = tmp
-                    .iter()
-                    .map(|s| s.trim().parse::<u8>())
-                    .filter_map(|v| v.ok())
-                    .collect::<Vec<_>>();
-                if val.len() == 3 {
-                    return (val[0], val[1], val[2]).try_into();
-                }
+        if let Some((args, alpha)) = parse_fn_args_alpha(rgb_str, "rgb") {
+            let val = args
+                .iter()
+                .filter_map(|s| parse_rgb_channel(s))
+                .collect::<Vec<_>>();
+            if args.len() == 3 && val.len() == 3 {
+                return match alpha {
+                    // a translucent color is blended onto white, the same as `From<RGBA>`
+                    Some(a) => RGBA::try_from((val[0], val[1], val[2], a)).map(RGB::from),
+                    None => (val[0], val[1], val[2]).try_into(),
+                };
             }
         }
+        if let Some(rgb) = named_color(rgb_str) {
+            return rgb.try_into();
+        }
         Err(ColorError::FormatErr(format!(
             "RGB:{} format error!",
             rgb_str
@@ -53,6 +84,13 @@ impl TryFrom<&str> for RGB {
     }
 }
 
+impl FromStr for RGB {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl TryFrom<(u8, u8, u8)> for RGB {
     type Error = ColorError;
     fn try_from(value: (u8, u8, u8)) -> Result<Self, Self::Error> {
@@ -64,6 +102,47 @@ impl TryFrom<(u8, u8, u8)> for RGB {
     }
 }
 
+impl TryFrom<(f32, f32, f32)> for RGB {
+    type Error = ColorError;
+    /// Channels are normalized to 0~1, as used by shaders and GPU APIs.
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "RGB: args ({},{},{}) value error, all normalized value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            let to_u8 = |v: f32| (v * 255.0).round() as u8;
+            Ok(Self {
+                r: to_u8(value.0),
+                g: to_u8(value.1),
+                b: to_u8(value.2),
+            })
+        }
+    }
+}
+
+impl From<[u8; 3]> for RGB {
+    fn from(bytes: [u8; 3]) -> Self {
+        let [r, g, b] = bytes;
+        Self { r, g, b }
+    }
+}
+
+impl TryFrom<&[u8]> for RGB {
+    type Error = ColorError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match *bytes {
+            [r, g, b] => Ok(Self { r, g, b }),
+            _ => Err(ColorError::ValueErr(format!(
+                "RGB: expected 3 bytes, but got {}.",
+                bytes.len()
+            ))),
+        }
+    }
+}
+
 impl From<Hex> for RGB {
     fn from(hex: Hex) -> Self {
         let (r, g, b, a) = hex.rgba;
@@ -85,9 +164,7 @@ impl From<RGBA> for RGB {
 
 impl From<HSL> for RGB {
     fn from(hsl: HSL) -> Self {
-        let HSL { h, s, l } = hsl;
-        let (r, g, b) = hsl_to_rgb(h, s, l);
-        Self { r, g, b }
+        Self::from_hsl(hsl)
     }
 }
 
@@ -103,59 +180,521 @@ impl From<HSLA> for RGB {
     }
 }
 
+impl From<HSVA> for RGB {
+    fn from(hsva: HSVA) -> Self {
+        let rgba: RGBA = hsva.into();
+        rgba.into()
+    }
+}
+
 impl From<HSV> for RGB {
     fn from(hsv: HSV) -> Self {
-        let HSV { h, s, v } = hsv;
-        let (r, g, b) = hsv_to_rgb(h, s, v);
-        Self { r, g, b }
+        Self::from_hsv(hsv)
     }
 }
 
 impl From<CMYK> for RGB {
     fn from(cmyk: CMYK) -> Self {
-        let CMYK { c, m, y, k } = cmyk;
-        let (r, g, b) = cmyk_to_rgb(c, m, y, k);
+        Self::from_cmyk(cmyk)
+    }
+}
+
+impl From<Oklab> for RGB {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { l, a, b } = oklab;
+        let (r, g, b) = oklab_to_rgb(l, a, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<Oklch> for RGB {
+    fn from(oklch: Oklch) -> Self {
+        let oklab: Oklab = oklch.into();
+        oklab.into()
+    }
+}
+
+impl From<Lab> for RGB {
+    fn from(lab: Lab) -> Self {
+        let Lab { l, a, b } = lab;
+        let (r, g, b) = lab_to_rgb(l, a, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<LCh> for RGB {
+    fn from(lch: LCh) -> Self {
+        let lab: Lab = lch.into();
+        lab.into()
+    }
+}
+
+impl From<XYZ> for RGB {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let (r, g, b) = xyz_to_rgb(x, y, z);
         Self { r, g, b }
     }
 }
 
+impl From<XyY> for RGB {
+    fn from(xyy: XyY) -> Self {
+        let xyz: XYZ = xyy.into();
+        xyz.into()
+    }
+}
+
+impl From<HWB> for RGB {
+    fn from(hwb: HWB) -> Self {
+        let HWB { h, w, b } = hwb;
+        let (r, g, b) = hwb_to_rgb(h, w, b);
+        Self { r, g, b }
+    }
+}
+
+impl From<YCbCr> for RGB {
+    fn from(ycbcr: YCbCr) -> Self {
+        let YCbCr {
+            y,
+            cb,
+            cr,
+            standard,
+        } = ycbcr;
+        let (r, g, b) = ycbcr_to_rgb(y, cb, cr, standard == YCbCrStandard::BT709);
+        Self { r, g, b }
+    }
+}
+
+impl From<YUV> for RGB {
+    fn from(yuv: YUV) -> Self {
+        let YUV { y, u, v } = yuv;
+        let (r, g, b) = yuv_to_rgb(y, u, v);
+        Self { r, g, b }
+    }
+}
+
+impl From<YIQ> for RGB {
+    fn from(yiq: YIQ) -> Self {
+        let YIQ { y, i, q } = yiq;
+        let (r, g, b) = yiq_to_rgb(y, i, q);
+        Self { r, g, b }
+    }
+}
+
+impl From<HSI> for RGB {
+    fn from(hsi: HSI) -> Self {
+        let HSI { h, s, i } = hsi;
+        let (r, g, b) = hsi_to_rgb(h, s, i);
+        Self { r, g, b }
+    }
+}
+
+impl From<Luv> for RGB {
+    fn from(luv: Luv) -> Self {
+        let xyz: XYZ = luv.into();
+        xyz.into()
+    }
+}
+
+impl From<LCHuv> for RGB {
+    fn from(lchuv: LCHuv) -> Self {
+        let xyz: XYZ = lchuv.into();
+        xyz.into()
+    }
+}
+
+impl From<Cam16> for RGB {
+    fn from(cam16: Cam16) -> Self {
+        let xyz: XYZ = cam16.into();
+        xyz.into()
+    }
+}
+
+impl From<LinearRGB> for RGB {
+    fn from(linear: LinearRGB) -> Self {
+        linear.to_srgb()
+    }
+}
+
+impl From<DisplayP3> for RGB {
+    fn from(p3: DisplayP3) -> Self {
+        let xyz: XYZ = p3.into();
+        xyz.into()
+    }
+}
+
+impl From<AdobeRGB> for RGB {
+    fn from(adobe: AdobeRGB) -> Self {
+        let xyz: XYZ = adobe.into();
+        xyz.into()
+    }
+}
+
+impl From<Rec2020> for RGB {
+    fn from(rec2020: Rec2020) -> Self {
+        let xyz: XYZ = rec2020.into();
+        xyz.into()
+    }
+}
+
+impl From<ProPhotoRGB> for RGB {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let xyz: XYZ = prophoto.into();
+        xyz.into()
+    }
+}
+
+impl From<Gray> for RGB {
+    fn from(gray: Gray) -> Self {
+        Self {
+            r: gray.v,
+            g: gray.v,
+            b: gray.v,
+        }
+    }
+}
+
+impl From<Kelvin> for RGB {
+    fn from(kelvin: Kelvin) -> Self {
+        let (r, g, b) = kelvin_to_rgb(kelvin.0 as f32);
+        Self { r, g, b }
+    }
+}
+
+impl From<u32> for RGB {
+    /// Unpacks a 0xRRGGBB integer, the highest byte is ignored.
+    fn from(value: u32) -> Self {
+        Self::from_u32_with(value, PackedLayout::Rgb)
+    }
+}
+
+impl From<SgrColor> for RGB {
+    fn from(color: SgrColor) -> Self {
+        color.rgb()
+    }
+}
+
+impl From<Okhsl> for RGB {
+    fn from(okhsl: Okhsl) -> Self {
+        let Okhsl { h, s, l } = okhsl;
+        let (r, g, b) = okhsl_to_rgb(h, s, l);
+        Self { r, g, b }
+    }
+}
+
+impl From<Okhsv> for RGB {
+    fn from(okhsv: Okhsv) -> Self {
+        let Okhsv { h, s, v } = okhsv;
+        let (r, g, b) = okhsv_to_rgb(h, s, v);
+        Self { r, g, b }
+    }
+}
+
+impl From<HSLuv> for RGB {
+    fn from(hsluv: HSLuv) -> Self {
+        let lchuv: LCHuv = hsluv.into();
+        lchuv.into()
+    }
+}
+
+impl From<HPLuv> for RGB {
+    fn from(hpluv: HPLuv) -> Self {
+        let lchuv: LCHuv = hpluv.into();
+        lchuv.into()
+    }
+}
+
+impl From<Jzazbz> for RGB {
+    fn from(jzazbz: Jzazbz) -> Self {
+        let xyz: XYZ = jzazbz.into();
+        xyz.into()
+    }
+}
+
+impl From<ICtCp> for RGB {
+    fn from(ictcp: ICtCp) -> Self {
+        let xyz: XYZ = ictcp.into();
+        xyz.into()
+    }
+}
+
+impl From<Din99> for RGB {
+    fn from(din99: Din99) -> Self {
+        let lab: Lab = din99.into();
+        lab.into()
+    }
+}
+
+impl From<HunterLab> for RGB {
+    fn from(lab: HunterLab) -> Self {
+        let xyz: XYZ = lab.into();
+        xyz.into()
+    }
+}
+
+impl From<YCoCg> for RGB {
+    fn from(ycocg: YCoCg) -> Self {
+        let YCoCg { y, co, cg } = ycocg;
+        let (r, g, b) = ycocg_to_rgb(y, co, cg);
+        Self {
+            r: r.clamp(0, 255) as u8,
+            g: g.clamp(0, 255) as u8,
+            b: b.clamp(0, 255) as u8,
+        }
+    }
+}
+
+impl From<ColorFunction> for RGB {
+    fn from(color: ColorFunction) -> Self {
+        let rgba: RGBA = color.into();
+        rgba.into()
+    }
+}
+
+impl From<RGBF64> for RGB {
+    fn from(rgb: RGBF64) -> Self {
+        let to_u8 = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self {
+            r: to_u8(rgb.r),
+            g: to_u8(rgb.g),
+            b: to_u8(rgb.b),
+        }
+    }
+}
+
+impl From<XYZF64> for RGB {
+    fn from(xyz: XYZF64) -> Self {
+        let rgb: RGBF64 = xyz.into();
+        rgb.into()
+    }
+}
+
+impl From<LabF64> for RGB {
+    fn from(lab: LabF64) -> Self {
+        let rgb: RGBF64 = lab.into();
+        rgb.into()
+    }
+}
+
 impl Display for RGB {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(f, "rgb({},{},{})", self.r, self.g, self.b)
     }
 }
 
 impl RGB {
+    /// Creates a color in a const context, so colors can live in `const` and `static` tables.
+    /// ```rust
+    /// use easy_color::{HSL, RGB};
+    /// const PALETTE: [RGB; 2] = [
+    ///     RGB::new(43, 196, 138),
+    ///     RGB::from_hsl(HSL::new(262, 85, 79)),
+    /// ];
+    /// assert_eq!(PALETTE[1].to_string(), "rgb(189,156,247)");
+    /// ```
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// The const version of `From<HSL>`.
+    pub const fn from_hsl(hsl: HSL) -> Self {
+        let (r, g, b) = hsl_to_rgb(hsl.h, hsl.s, hsl.l);
+        Self { r, g, b }
+    }
+
+    /// The const version of `From<HSV>`.
+    pub const fn from_hsv(hsv: HSV) -> Self {
+        let (r, g, b) = hsv_to_rgb(hsv.h, hsv.s, hsv.v);
+        Self { r, g, b }
+    }
+
+    /// The const version of `From<CMYK>`.
+    pub const fn from_cmyk(cmyk: CMYK) -> Self {
+        let (r, g, b) = cmyk_to_rgb(cmyk.c, cmyk.m, cmyk.y, cmyk.k);
+        Self { r, g, b }
+    }
+
+    /// Unpacks a color from a `u32` with the given channel layout, the alpha byte of `Argb` and `Rgba` is ignored.
+    /// ```rust
+    /// use easy_color::{PackedLayout, RGB};
+    /// let rgb:RGB = 0x2BC48A.into();
+    /// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+    /// let rgb = RGB::from_u32_with(0x2BC48AFF, PackedLayout::Rgba);
+    /// assert_eq!(rgb.to_u32(), 0x2BC48A);
+    /// ```
+    pub fn from_u32_with(value: u32, layout: PackedLayout) -> Self {
+        let (r, g, b, _) = layout.unpack(value);
+        Self { r, g, b }
+    }
+
+    /// The channels normalized to 0~1, as used by shaders and GPU APIs. `TryFrom<(f32, f32, f32)>` goes the other way.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (1.0, 0.5, 0.0).try_into().unwrap();
+    /// assert_eq!(rgb.to_string(), "rgb(255,128,0)");
+    /// let (r, g, b) = rgb.to_f32_tuple();
+    /// assert_eq!((r, b), (1.0, 0.0));
+    /// assert_eq!(format!("{:.3}", g), "0.502");
+    /// assert!(RGB::try_from((1.5, 0.0, 0.0)).is_err());
+    /// ```
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32) {
+        (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        )
+    }
+
+    /// The channels as bytes in the order of a RGB pixel buffer.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let pixels = [43, 196, 138, 255, 99, 71];
+    /// let colors = pixels.chunks_exact(3).map(RGB::try_from).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(colors[1].to_string(), "rgb(255,99,71)");
+    /// assert_eq!(colors[0].to_bytes(), [43, 196, 138]);
+    /// assert_eq!(RGB::from([43, 196, 138]), colors[0]);
+    /// assert!(RGB::try_from(&pixels[..4]).is_err());
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Packs the color into a 0xRRGGBB integer.
+    pub fn to_u32(&self) -> u32 {
+        self.to_u32_with(PackedLayout::Rgb)
+    }
+
+    /// Packs the color into a `u32` with the given channel layout, the alpha byte is 0xFF.
+    /// ```rust
+    /// use easy_color::{PackedLayout, RGB};
+    /// let rgb = RGB::new(43, 196, 138);
+    /// assert_eq!(rgb.to_u32_with(PackedLayout::Argb), 0xFF2BC48A);
+    /// assert_eq!(rgb.to_u32_with(PackedLayout::Rgba), 0x2BC48AFF);
+    /// ```
+    pub fn to_u32_with(&self, layout: PackedLayout) -> u32 {
+        layout.pack((self.r, self.g, self.b, 1.0))
+    }
+
     pub fn red(&self) -> u8 {
         self.r
     }
     pub fn set_red(&mut self, red: u8) -> &mut Self {
-        self.r = red.min(255);
+        self.r = red;
         self
     }
     pub fn green(&self) -> u8 {
         self.g
     }
     pub fn set_green(&mut self, green: u8) -> &mut Self {
-        self.g = green.min(255);
+        self.g = green;
         self
     }
     pub fn blue(&self) -> u8 {
         self.b
     }
     pub fn set_blue(&mut self, blue: u8) -> &mut Self {
-        self.b = blue.min(255);
+        self.b = blue;
         self
     }
 
+    /// Removes the sRGB gamma encoding, so the color can be used in linear light math.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (255, 128, 0).try_into().unwrap();
+    /// assert_eq!(rgb.to_linear().to_string(), "color(srgb-linear 1.0000 0.2159 0.0000)");
+    /// ```
+    pub fn to_linear(&self) -> LinearRGB {
+        (*self).into()
+    }
+
+    /// The terminal color of an ANSI 16 color index(0~15), with the default values of xterm:
+    /// 0~7 are black, red, green, yellow, blue, magenta, cyan and white, and 8~15 are their bright versions.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// assert_eq!(RGB::from_ansi16(1).unwrap().to_string(), "rgb(205,0,0)");
+    /// assert_eq!(RGB::from_ansi16(12).unwrap().to_string(), "rgb(92,92,255)");
+    /// assert!(RGB::from_ansi16(16).is_err());
+    /// ```
+    pub fn from_ansi16(index: u8) -> Result<Self, ColorError> {
+        match ANSI16.get(index as usize) {
+            Some(&(r, g, b)) => Ok(Self { r, g, b }),
+            None => Err(ColorError::ValueErr(format!(
+                "RGB: ansi16 index {} value error, the index must between 0~15!",
+                index
+            ))),
+        }
+    }
+
+    /// The color of an index of the 256 color terminal palette: 0~15 are the colors of `from_ansi16`,
+    /// 16~231 are the 6x6x6 color cube and 232~255 are a grayscale ramp from rgb(8,8,8) to rgb(238,238,238).
+    /// ```rust
+    /// use easy_color::RGB;
+    /// assert_eq!(RGB::from_ansi256(9).to_string(), "rgb(255,0,0)");
+    /// assert_eq!(RGB::from_ansi256(16).to_string(), "rgb(0,0,0)");
+    /// assert_eq!(RGB::from_ansi256(208).to_string(), "rgb(255,135,0)");
+    /// assert_eq!(RGB::from_ansi256(231).to_string(), "rgb(255,255,255)");
+    /// assert_eq!(RGB::from_ansi256(244).to_string(), "rgb(128,128,128)");
+    /// ```
+    pub fn from_ansi256(index: u8) -> Self {
+        let (r, g, b) = ansi256_to_rgb(index);
+        Self { r, g, b }
+    }
+
+    /// The color of a blackbody(planckian radiator) at the given temperature, from an approximation of the
+    /// planckian locus fitted for 1000K~40000K. Temperatures outside of it are clamped.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// assert_eq!(RGB::from_kelvin(1850.0).to_string(), "rgb(255,129,0)");
+    /// assert_eq!(RGB::from_kelvin(3200.0).to_string(), "rgb(255,184,123)");
+    /// assert_eq!(RGB::from_kelvin(6500.0).to_string(), "rgb(255,254,250)");
+    /// assert_eq!(RGB::from_kelvin(15000.0).to_string(), "rgb(181,205,255)");
+    /// ```
+    pub fn from_kelvin(temp: f32) -> Self {
+        let (r, g, b) = kelvin_to_rgb(temp);
+        Self { r, g, b }
+    }
+
+    /// Rotates the hue by the given degrees in HSL, so there is no need to convert the color by hand.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let rgb:RGB = (255, 0, 0).try_into().unwrap();
+    /// assert_eq!(rgb.rotate_hue(120).to_string(), "rgb(0,255,0)");
+    /// assert_eq!(rgb.rotate_hue(-120).to_string(), "rgb(0,0,255)");
+    /// ```
+    pub fn rotate_hue(&self, degrees: i32) -> Self {
+        let mut hsl: HSL = (*self).into();
+        hsl.rotate(degrees);
+        hsl.into()
+    }
+
+    /// Whether the WCAG relative luminance of the color is below 0.179, the luminance at which black and white text
+    /// have the same contrast on it, so white text reads better on a dark color.
+    /// ```rust
+    /// use easy_color::RGB;
+    /// let navy:RGB = (0, 0, 128).try_into().unwrap();
+    /// assert!(navy.is_dark());
+    /// let green:RGB = (43, 196, 138).try_into().unwrap();
+    /// assert!(green.is_light());
+    /// // a custom threshold
+    /// assert!(green.is_dark_with(0.5));
+    /// ```
     pub fn is_dark(&self) -> bool {
-        self.r as f32 * 0.299 + self.g as f32 * 0.587 + self.b as f32 * 0.114 < 192.0
+        self.is_dark_with(0.179)
     }
 
     pub fn is_light(&self) -> bool {
         !self.is_dark()
     }
 
+    /// Whether the WCAG relative luminance(0~1) of the color is below the given threshold.
+    pub fn is_dark_with(&self, threshold: f32) -> bool {
+        relative_luminance(self.r, self.g, self.b) < threshold
+    }
+
+    pub fn is_light_with(&self, threshold: f32) -> bool {
+        !self.is_dark_with(threshold)
+    }
+
     // pub fn random() -> Self {
     //     let r = rand::random::<u8>();
     //     let g = rand::random::<u8>();
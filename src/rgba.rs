@@ -1,13 +1,26 @@
-use crate::common::hsl_to_rgb;
-use crate::{ColorError, Hex, CMYK, HSL, HSLA, HSV, RGB};
-use std::fmt::{Display, Formatter};
-use std::ops::{Deref, DerefMut};
+use crate::common::{
+    hsl_to_rgb, parse_fn_args_alpha, parse_number, parse_rgb_channel, ycbcr_coefficients,
+};
+use crate::named::named_color;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, Cam16, ColorError, ColorFunction, Din99, DisplayP3, Gray, HPLuv, HSLuv, Hex,
+    HunterLab, ICtCp, Jzazbz, Kelvin, LCHuv, LCh, Lab, LinearRGB, Luv, Okhsl, Okhsv, Oklab, Oklch,
+    PackedLayout, PremultipliedRGBA, ProPhotoRGB, Rec2020, XyY, YCbCr, YCbCrStandard, YCoCg, CMYK,
+    HSI, HSL, HSLA, HSV, HSVA, HWB, RGB, XYZ, YIQ, YUV,
+};
+use core::fmt::{Display, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
 
-/// RGBA can be parsed from a string in the format "rgba(r,g,b,a)" or from a tuple (r,g,b,a).
+/// RGBA can be parsed from a string in the format "rgba(r,g,b,a)" or "rgb(r g b / a)", or from a tuple (r,g,b,a).
+/// As in CSS, `rgb()` and `rgba()` are aliases and the alpha defaults to 1 when it is left out.
 /// * r:u8 - red value(0~255)
 /// * g:u8 - green value(0~255)
 /// * b:u8 - blue value(0~255)
 /// * a:f32 - alpha(0~1)
+///
 /// The red, green, and blue values can be individually set or retrieved.
 /// ### example
 /// ```rust
@@ -16,6 +29,22 @@ use std::ops::{Deref, DerefMut};
 /// rgba.set_alpha(0.5);
 /// assert_eq!(rgba.to_string(), "rgba(125,60,98,0.50)");
 ///
+/// // the modern space separated syntax with a slash alpha
+/// let rgba:RGBA = "rgb(255 0 0 / 0.5)".try_into().unwrap();
+/// assert_eq!(rgba.to_string(), "rgba(255,0,0,0.50)");
+/// let rgba:RGBA = "rgb(100% 0% 25% / 50%)".try_into().unwrap();
+/// assert_eq!(rgba.to_string(), "rgba(255,0,64,0.50)");
+///
+/// // a missing alpha is opaque
+/// let rgba:RGBA = "rgba(255 0 0)".try_into().unwrap();
+/// assert_eq!(rgba.to_string(), "rgba(255,0,0,1.00)");
+/// let rgba:RGBA = "rgb(255,0,0)".try_into().unwrap();
+/// assert_eq!(rgba.alpha(), 1.0);
+/// assert!(RGBA::try_from("rgba(255,0,0,x)").is_err());
+///
+/// let rgba:RGBA = "transparent".try_into().unwrap();
+/// assert_eq!(rgba.to_string(), "rgba(0,0,0,0.00)");
+///
 /// let rgba:RGBA = (125,60,240,0.5).try_into().unwrap();
 /// let hsl:HSL = rgba.into();
 /// assert_eq!(hsl.to_string(), "hsl(262,85%,79%)");
@@ -29,28 +58,31 @@ pub struct RGBA {
 impl TryFrom<&str> for RGBA {
     type Error = ColorError;
     fn try_from(rgba_str: &str) -> Result<Self, Self::Error> {
-        let mut color = rgba_str.trim().to_lowercase();
-        if color.starts_with("rgba(") && color.ends_with(')') {
-            let mut val = vec![];
-            let mut alpha = None;
-            color = color.replace("rgba(", "").replace(')', "");
-            let tmp = color.split(',').collect::<Vec<_>>();
-            if tmp.len() == 4 {
-                for (idx, s) in tmp.iter().enumerate() {
-                    if idx == 3 {
-                        alpha = s.trim().parse::<f32>().ok();
-                    } else if let Ok(v) = s.trim().parse::<u8>() {
-                        val.push(v);
-                    }
+        let parsed =
+            parse_fn_args_alpha(rgba_str, "rgba").or_else(|| parse_fn_args_alpha(rgba_str, "rgb"));
+        if let Some((mut args, alpha)) = parsed {
+            let alpha = match (alpha, args.len()) {
+                // legacy syntax: rgba(r,g,b,a)
+                (None, 4) => args.pop().and_then(|s| parse_number(&s, 1.0)),
+                // like CSS Color 4, the alpha is optional and rgba() is an alias of rgb()
+                (None, _) => Some(1.0),
+                (alpha, _) => alpha,
+            };
+            let val = args
+                .iter()
+                .filter_map(|s| parse_rgb_channel(s))
+                .collect::<Vec<_>>();
+            if let (3, Some(alpha)) = (val.len(), alpha) {
+                if args.len() == 3 {
+                    return (val[0], val[1], val[2], alpha).try_into();
                 }
             }
-            if val.len() != 3 || alpha.is_none() {
-                return Err(ColorError::FormatErr(format!(
-                    "RGBA:{} format error!",
-                    rgba_str
-                )));
-            }
-            return (val[0], val[1], val[2], alpha.unwrap()).try_into();
+        }
+        if rgba_str.trim().eq_ignore_ascii_case("transparent") {
+            return (0, 0, 0, 0.0).try_into();
+        }
+        if let Some((r, g, b)) = named_color(rgba_str) {
+            return (r, g, b, 1.0).try_into();
         }
         Err(ColorError::FormatErr(format!(
             "RGBA:{} format error!",
@@ -59,6 +91,13 @@ impl TryFrom<&str> for RGBA {
     }
 }
 
+impl FromStr for RGBA {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl TryFrom<(u8, u8, u8, f32)> for RGBA {
     type Error = ColorError;
     fn try_from(value: (u8, u8, u8, f32)) -> Result<Self, Self::Error> {
@@ -78,6 +117,50 @@ impl TryFrom<(u8, u8, u8, f32)> for RGBA {
     }
 }
 
+impl TryFrom<(f32, f32, f32, f32)> for RGBA {
+    type Error = ColorError;
+    /// Channels and alpha are normalized to 0~1, as used by shaders and GPU APIs.
+    fn try_from(value: (f32, f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.3) {
+            return Err(ColorError::ValueErr(format!(
+                "RGBA: the alpha value must between 0~1, but got {}.",
+                value.3
+            )));
+        }
+        let rgb = RGB::try_from((value.0, value.1, value.2))?;
+        Ok(RGBA { rgb, a: value.3 })
+    }
+}
+
+impl From<[u8; 4]> for RGBA {
+    /// The alpha byte is mapped from 0~255 to 0~1.
+    fn from(bytes: [u8; 4]) -> Self {
+        let [r, g, b, a] = bytes;
+        Self::new(r, g, b, a as f32 / 255.0)
+    }
+}
+
+impl TryFrom<&[u8]> for RGBA {
+    type Error = ColorError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match *bytes {
+            [r, g, b, a] => Ok(Self::from([r, g, b, a])),
+            _ => Err(ColorError::ValueErr(format!(
+                "RGBA: expected 4 bytes, but got {}.",
+                bytes.len()
+            ))),
+        }
+    }
+}
+
+impl From<u32> for RGBA {
+    /// Unpacks a 0xRRGGBB integer into an opaque color like `RGB` and `Hex` do, the highest byte is ignored.
+    /// Use `RGBA::from_u32_with` to read the alpha of a 0xAARRGGBB or 0xRRGGBBAA integer.
+    fn from(value: u32) -> Self {
+        Self::from_u32_with(value, PackedLayout::Rgb)
+    }
+}
+
 impl From<Hex> for RGBA {
     fn from(hex: Hex) -> Self {
         let (r, g, b, a) = hex.rgba;
@@ -109,6 +192,13 @@ impl From<HSLA> for RGBA {
     }
 }
 
+impl From<HSVA> for RGBA {
+    fn from(hsva: HSVA) -> Self {
+        let rgb = RGB::from_hsv(hsva.hsv);
+        Self { rgb, a: hsva.a }
+    }
+}
+
 impl From<HSV> for RGBA {
     fn from(hsv: HSV) -> Self {
         let rgb: RGB = hsv.into();
@@ -123,6 +213,216 @@ impl From<CMYK> for RGBA {
     }
 }
 
+impl From<Oklab> for RGBA {
+    fn from(oklab: Oklab) -> Self {
+        let rgb: RGB = oklab.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Oklch> for RGBA {
+    fn from(oklch: Oklch) -> Self {
+        let rgb: RGB = oklch.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Lab> for RGBA {
+    fn from(lab: Lab) -> Self {
+        let rgb: RGB = lab.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<LCh> for RGBA {
+    fn from(lch: LCh) -> Self {
+        let rgb: RGB = lch.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<XYZ> for RGBA {
+    fn from(xyz: XYZ) -> Self {
+        let rgb: RGB = xyz.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<XyY> for RGBA {
+    fn from(xyy: XyY) -> Self {
+        let rgb: RGB = xyy.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HWB> for RGBA {
+    fn from(hwb: HWB) -> Self {
+        let rgb: RGB = hwb.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<YCbCr> for RGBA {
+    fn from(ycbcr: YCbCr) -> Self {
+        let rgb: RGB = ycbcr.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<YUV> for RGBA {
+    fn from(yuv: YUV) -> Self {
+        let rgb: RGB = yuv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<YIQ> for RGBA {
+    fn from(yiq: YIQ) -> Self {
+        let rgb: RGB = yiq.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HSI> for RGBA {
+    fn from(hsi: HSI) -> Self {
+        let rgb: RGB = hsi.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Luv> for RGBA {
+    fn from(luv: Luv) -> Self {
+        let rgb: RGB = luv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<LCHuv> for RGBA {
+    fn from(lchuv: LCHuv) -> Self {
+        let rgb: RGB = lchuv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Cam16> for RGBA {
+    fn from(cam16: Cam16) -> Self {
+        let rgb: RGB = cam16.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<LinearRGB> for RGBA {
+    fn from(linear: LinearRGB) -> Self {
+        let rgb: RGB = linear.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<DisplayP3> for RGBA {
+    fn from(p3: DisplayP3) -> Self {
+        let rgb: RGB = p3.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<AdobeRGB> for RGBA {
+    fn from(adobe: AdobeRGB) -> Self {
+        let rgb: RGB = adobe.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Rec2020> for RGBA {
+    fn from(rec2020: Rec2020) -> Self {
+        let rgb: RGB = rec2020.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<ProPhotoRGB> for RGBA {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let rgb: RGB = prophoto.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Gray> for RGBA {
+    fn from(gray: Gray) -> Self {
+        let rgb: RGB = gray.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Kelvin> for RGBA {
+    fn from(kelvin: Kelvin) -> Self {
+        let rgb: RGB = kelvin.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Okhsl> for RGBA {
+    fn from(okhsl: Okhsl) -> Self {
+        let rgb: RGB = okhsl.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Okhsv> for RGBA {
+    fn from(okhsv: Okhsv) -> Self {
+        let rgb: RGB = okhsv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HSLuv> for RGBA {
+    fn from(hsluv: HSLuv) -> Self {
+        let rgb: RGB = hsluv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HPLuv> for RGBA {
+    fn from(hpluv: HPLuv) -> Self {
+        let rgb: RGB = hpluv.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Jzazbz> for RGBA {
+    fn from(jzazbz: Jzazbz) -> Self {
+        let rgb: RGB = jzazbz.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<ICtCp> for RGBA {
+    fn from(ictcp: ICtCp) -> Self {
+        let rgb: RGB = ictcp.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<Din99> for RGBA {
+    fn from(din99: Din99) -> Self {
+        let rgb: RGB = din99.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<HunterLab> for RGBA {
+    fn from(lab: HunterLab) -> Self {
+        let rgb: RGB = lab.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
+impl From<YCoCg> for RGBA {
+    fn from(ycocg: YCoCg) -> Self {
+        let rgb: RGB = ycocg.into();
+        Self { rgb, a: 1.0 }
+    }
+}
+
 impl Deref for RGBA {
     type Target = RGB;
     fn deref(&self) -> &Self::Target {
@@ -136,22 +436,150 @@ impl DerefMut for RGBA {
     }
 }
 
+impl From<ColorFunction> for RGBA {
+    fn from(color: ColorFunction) -> Self {
+        let rgb: RGB = match color {
+            ColorFunction::Srgb(rgb, _) => rgb,
+            ColorFunction::SrgbLinear(c, _) => c.into(),
+            ColorFunction::DisplayP3(c, _) => c.into(),
+            ColorFunction::A98Rgb(c, _) => c.into(),
+            ColorFunction::ProPhotoRgb(c, _) => c.into(),
+            ColorFunction::Rec2020(c, _) => c.into(),
+            ColorFunction::Xyz(c, _) => c.into(),
+        };
+        Self {
+            rgb,
+            a: color.alpha(),
+        }
+    }
+}
+
+impl From<PremultipliedRGBA> for RGBA {
+    fn from(pre: PremultipliedRGBA) -> Self {
+        let PremultipliedRGBA { r, g, b, a } = pre;
+        if a <= 0.0 {
+            return Self::new(0, 0, 0, 0.0);
+        }
+        let to_u8 = |v: f32| (v / a * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::new(to_u8(r), to_u8(g), to_u8(b), a)
+    }
+}
+
 impl Display for RGBA {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let RGB { r, g, b } = self.rgb;
         write!(f, "rgba({},{},{},{:.2})", r, g, b, self.a)
     }
 }
 
 impl RGBA {
+    /// Creates a color in a const context, the alpha is clamped to 0~1.
+    pub const fn new(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Self {
+            rgb: RGB::new(r, g, b),
+            a: a.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Unpacks a color from a `u32` with the given channel layout, `Rgb` colors are opaque.
+    /// ```rust
+    /// use easy_color::{PackedLayout, RGBA};
+    /// let rgba = RGBA::from_u32_with(0x802BC48A, PackedLayout::Argb);
+    /// assert_eq!(rgba.to_string(), "rgba(43,196,138,0.50)");
+    /// assert_eq!(rgba.to_u32_with(PackedLayout::Argb), 0x802BC48A);
+    /// assert_eq!(rgba.to_u32(), 0x2BC48A);
+    /// let rgba:RGBA = 0x2BC48A.into();
+    /// assert_eq!(rgba.to_string(), "rgba(43,196,138,1.00)");
+    /// assert_eq!(rgba.to_u32_with(PackedLayout::Rgba), 0x2BC48AFF);
+    /// ```
+    pub fn from_u32_with(value: u32, layout: PackedLayout) -> Self {
+        let (r, g, b, a) = layout.unpack(value);
+        Self::new(r, g, b, a)
+    }
+
+    /// The channels and alpha normalized to 0~1, as used by shaders and GPU APIs.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (1.0, 0.0, 0.0, 0.5).try_into().unwrap();
+    /// assert_eq!(rgba.to_string(), "rgba(255,0,0,0.50)");
+    /// assert_eq!(rgba.to_f32_tuple(), (1.0, 0.0, 0.0, 0.5));
+    /// ```
+    pub fn to_f32_tuple(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b) = self.rgb.to_f32_tuple();
+        (r, g, b, self.a)
+    }
+
+    /// The channels as bytes in the order of a RGBA pixel buffer, the alpha is mapped from 0~1 to 0~255.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let pixels = [43, 196, 138, 128, 255, 99, 71, 255];
+    /// let colors = pixels.chunks_exact(4).map(RGBA::try_from).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(colors[0].to_string(), "rgba(43,196,138,0.50)");
+    /// assert_eq!(colors[0].to_bytes(), [43, 196, 138, 128]);
+    /// assert_eq!(RGBA::from([255, 99, 71, 255]), colors[1]);
+    /// assert!(RGBA::try_from(&pixels[..3]).is_err());
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 4] {
+        let RGB { r, g, b } = self.rgb;
+        [r, g, b, (self.a * 255.0).round() as u8]
+    }
+
+    /// Packs the color into a 0xRRGGBB integer like `RGB` and `Hex` do, the alpha is dropped.
+    /// Use `to_u32_with` to keep it.
+    pub fn to_u32(&self) -> u32 {
+        self.to_u32_with(PackedLayout::Rgb)
+    }
+
+    /// Packs the color into a `u32` with the given channel layout, `Rgb` drops the alpha.
+    pub fn to_u32_with(&self, layout: PackedLayout) -> u32 {
+        let RGB { r, g, b } = self.rgb;
+        layout.pack((r, g, b, self.a))
+    }
+
     pub fn alpha(&self) -> f32 {
         self.a
     }
     pub fn set_alpha(&mut self, alpha: f32) -> &mut Self {
-        self.a = alpha.max(0.0).min(1.0);
+        self.a = alpha.clamp(0.0, 1.0);
         self
     }
 
+    /// Multiplies the channels by the alpha, see `PremultipliedRGBA`.
+    pub fn premultiply(&self) -> PremultipliedRGBA {
+        (*self).into()
+    }
+
+    /// Composites this color over a backdrop with the Porter-Duff source-over operator.
+    /// ``` rust
+    /// use easy_color::RGBA;
+    /// let red:RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+    /// let blue:RGBA = (0, 0, 255, 0.5).try_into().unwrap();
+    /// assert_eq!(red.over(blue).to_string(), "rgba(170,0,85,0.75)");
+    /// ```
+    pub fn over(&self, backdrop: RGBA) -> RGBA {
+        let src = self.premultiply();
+        let dst = backdrop.premultiply();
+        let t = 1.0 - src.a;
+        PremultipliedRGBA {
+            r: src.r + dst.r * t,
+            g: src.g + dst.g * t,
+            b: src.b + dst.b * t,
+            a: src.a + dst.a * t,
+        }
+        .into()
+    }
+
+    /// Flattens this color onto an opaque background, which gives the color that is actually displayed.
+    /// ``` rust
+    /// use easy_color::{RGB, RGBA};
+    /// let shadow:RGBA = (0, 0, 0, 0.2).try_into().unwrap();
+    /// let white:RGB = (255, 255, 255).try_into().unwrap();
+    /// assert_eq!(shadow.onto(white).to_string(), "rgb(204,204,204)");
+    /// ```
+    pub fn onto(&self, background: RGB) -> RGB {
+        self.over(background.into()).rgb
+    }
+
     /// mix color
     /// ### Arguments
     /// * other - any struct that impl into RGBA
@@ -161,14 +589,14 @@ impl RGBA {
     /// use easy_color::{HSL, RGBA, ColorMix};
     /// let hsl:HSL = (0,0,0).try_into().unwrap();
     /// let rgba:RGBA = (255,255,255,1.0).try_into().unwrap();
-    /// rgba.mix(hsl, None).to_string(); // rgba(127,127,127,1.00)
-    /// rgba.mix(hsl, Some(0.35)).to_string(); // rgba(165,165,165,1.00)
+    /// rgba.mix(hsl, None).to_string(); // rgba(128,128,128,1.00)
+    /// rgba.mix(hsl, Some(0.35)).to_string(); // rgba(166,166,166,1.00)
     /// hsl.mix(rgba, None).to_string(); // hsl(0,0%,50%)
     /// ```
     ///
     pub fn mix(&self, other: impl Into<Self>, weight: Option<f32>) -> Self {
         let rgba: RGBA = other.into();
-        let p = weight.unwrap_or(0.5);
+        let p = weight.unwrap_or(0.5).clamp(0.0, 1.0);
         let w = 2.0 * p - 1.0;
         let a = rgba.a - self.a;
         let w1 = if w * a == -1.0 {
@@ -177,14 +605,44 @@ impl RGBA {
             ((w + a) / (1.0 + w * a) + 1.0) / 2.0
         };
         let w2 = 1.0 - w1;
-        let r = (w1 * rgba.r as f32 + w2 * self.r as f32) as u8;
-        let g = (w1 * rgba.g as f32 + w2 * self.g as f32) as u8;
-        let b = (w1 * rgba.b as f32 + w2 * self.b as f32) as u8;
+        let r = (w1 * rgba.r as f32 + w2 * self.r as f32).round() as u8;
+        let g = (w1 * rgba.g as f32 + w2 * self.g as f32).round() as u8;
+        let b = (w1 * rgba.b as f32 + w2 * self.b as f32).round() as u8;
         let a = rgba.a * p + self.a * (1.0 - p);
         let rgb: RGB = (r, g, b).try_into().unwrap();
         Self { rgb, a }
     }
 
+    /// Mixes the color toward white by the given ratio(0~1) and keeps the alpha.
+    /// ``` rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+    /// assert_eq!(rgba.tint(0.3).to_string(), "rgba(107,214,173,0.50)");
+    /// ```
+    pub fn tint(&self, ratio: f32) -> Self {
+        self.mix(Self::new(255, 255, 255, self.a), Some(ratio))
+    }
+
+    /// Mixes the color toward black by the given ratio(0~1) and keeps the alpha.
+    /// ``` rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+    /// assert_eq!(rgba.shade(0.3).to_string(), "rgba(30,137,97,0.50)");
+    /// ```
+    pub fn shade(&self, ratio: f32) -> Self {
+        self.mix(Self::new(0, 0, 0, self.a), Some(ratio))
+    }
+
+    /// Mixes the color toward mid-gray rgb(128,128,128) by the given ratio(0~1) and keeps the alpha.
+    /// ``` rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+    /// assert_eq!(rgba.tone(0.3).to_string(), "rgba(69,176,135,0.50)");
+    /// ```
+    pub fn tone(&self, ratio: f32) -> Self {
+        self.mix(Self::new(128, 128, 128, self.a), Some(ratio))
+    }
+
     /// fade color
     /// * ratio:f32 - the ratio of fading, a value between 0.0 and 1.0
     ///
@@ -197,7 +655,7 @@ impl RGBA {
     /// assert_eq!(rgba.to_string(), "rgba(255,255,255,0.40)");
     /// ```
     pub fn fade(&mut self, ratio: f32) -> &mut Self {
-        self.a = (self.a - self.a * ratio).max(0.0).min(1.0);
+        self.a = (self.a - self.a * ratio).clamp(0.0, 1.0);
         self
     }
 
@@ -212,11 +670,11 @@ impl RGBA {
     /// assert_eq!(rgba.to_string(), "rgba(255,255,255,0.96)");
     /// ```
     pub fn opaquer(&mut self, ratio: f32) -> &mut Self {
-        self.a = (self.a + self.a * ratio).max(0.0).min(1.0);
+        self.a = (self.a + self.a * ratio).clamp(0.0, 1.0);
         self
     }
 
-    /// Returns the grayscale mode of the color
+    /// Returns the grayscale mode of the color, the gray is the BT.601 luma of the color.
     /// ``` rust
     /// use easy_color::RGBA;
     /// let rgba:RGBA = (95,45,155,0.8).try_into().unwrap();
@@ -224,8 +682,21 @@ impl RGBA {
     /// assert_eq!(gray.to_string(), "rgba(72,72,72,0.80)");
     /// ```
     pub fn grayscale(&self) -> Self {
-        let v = (self.r as f32 * 0.3 + self.g as f32 * 0.59 + self.b as f32 * 0.11) as u8;
-        (v, v, v, self.a).try_into().unwrap()
+        self.grayscale_with(YCbCrStandard::BT601)
+    }
+
+    /// Returns the grayscale mode of the color with the luma weights of the given standard.
+    /// ``` rust
+    /// use easy_color::{RGBA, YCbCrStandard};
+    /// let rgba:RGBA = (95,45,155,0.8).try_into().unwrap();
+    /// let gray = rgba.grayscale_with(YCbCrStandard::BT709);
+    /// assert_eq!(gray.to_string(), "rgba(64,64,64,0.80)");
+    /// ```
+    pub fn grayscale_with(&self, standard: YCbCrStandard) -> Self {
+        let (kr, kb) = ycbcr_coefficients(standard == YCbCrStandard::BT709);
+        let kg = 1.0 - kr - kb;
+        let v = (self.r as f32 * kr + self.g as f32 * kg + self.b as f32 * kb).round() as u8;
+        Self::new(v, v, v, self.a)
     }
 
     /// Invert color
@@ -248,6 +719,28 @@ impl RGBA {
         Self { rgb, a: self.a }
     }
 
+    /// Rotates the hue by the given degrees in HSL and keeps the alpha.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (43, 196, 138, 0.5).try_into().unwrap();
+    /// assert_eq!(rgba.rotate_hue(90).to_string(), "rgba(61,43,196,0.50)");
+    /// ```
+    pub fn rotate_hue(&self, degrees: i32) -> Self {
+        let mut hsla: HSLA = (*self).into();
+        hsla.hsl.rotate(degrees);
+        hsla.into()
+    }
+
+    /// Same as `negate`, every channel becomes 255 - channel and the alpha is kept.
+    /// ```rust
+    /// use easy_color::RGBA;
+    /// let rgba:RGBA = (95,45,155,0.8).try_into().unwrap();
+    /// assert_eq!(rgba.invert().to_string(), "rgba(160,210,100,0.80)");
+    /// ```
+    pub fn invert(&self) -> Self {
+        self.negate()
+    }
+
     // pub fn random() -> Self {
     //     let rgb = RGB::random();
     //     let a = (rand::random::<f32>() * 100.0_f32).round() / 100.0;
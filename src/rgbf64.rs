@@ -0,0 +1,132 @@
+use crate::common::{
+    linear_to_srgb_f64, mul_matrix3_f64, parse_fn_args, parse_number_f64, srgb_to_linear_f64,
+    XYZ_TO_SRGB_F64,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, LabF64, RGB, XYZF64};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// RGBF64 is sRGB with f64 channels, for conversion chains where the f32 types lose too much precision.
+/// Together with `XYZF64` and `LabF64` it converts without leaving double precision, `RGB` is only needed at the ends.
+/// RGBF64 can be parsed from a string in the format "color(srgb r g b)" or from a tuple (r,g,b).
+/// * r:f64 - red(0~1), can also be written as a percentage in strings
+/// * g:f64 - green(0~1), can also be written as a percentage in strings
+/// * b:f64 - blue(0~1), can also be written as a percentage in strings
+///
+/// ### example
+/// ```rust
+/// use easy_color::{LabF64, RGB, RGBF64, XYZF64};
+/// let rgb:RGBF64 = RGB::new(43, 196, 138).into();
+/// assert_eq!(rgb.to_string(), "color(srgb 0.168627 0.768627 0.541176)");
+///
+/// // a thousand trips through XYZ and Lab don't move the color
+/// let mut color = rgb;
+/// for _ in 0..1000 {
+///     let lab:LabF64 = XYZF64::from(color).into();
+///     color = XYZF64::from(lab).into();
+/// }
+/// assert!((color.r - rgb.r).abs() < 1e-12);
+/// assert_eq!(RGB::from(color), RGB::new(43, 196, 138));
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct RGBF64 {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl TryFrom<&str> for RGBF64 {
+    type Error = ColorError;
+    fn try_from(rgb_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(rgb_str, "color") {
+            if args.len() == 4 && args[0] == "srgb" {
+                let r = parse_number_f64(&args[1], 1.0);
+                let g = parse_number_f64(&args[2], 1.0);
+                let b = parse_number_f64(&args[3], 1.0);
+                if let (Some(r), Some(g), Some(b)) = (r, g, b) {
+                    return (r, g, b).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "RGBF64: {} format error!",
+            rgb_str
+        )))
+    }
+}
+
+impl FromStr for RGBF64 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for RGBF64 {
+    type Error = ColorError;
+    fn try_from(value: (f64, f64, f64)) -> Result<Self, Self::Error> {
+        let range = 0.0..=1.0;
+        if !range.contains(&value.0) || !range.contains(&value.1) || !range.contains(&value.2) {
+            Err(ColorError::ValueErr(format!(
+                "RGBF64: args ({},{},{}) value error, all value must between 0~1!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                r: value.0,
+                g: value.1,
+                b: value.2,
+            })
+        }
+    }
+}
+
+impl From<RGB> for RGBF64 {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        Self {
+            r: r as f64 / 255.0,
+            g: g as f64 / 255.0,
+            b: b as f64 / 255.0,
+        }
+    }
+}
+
+impl From<XYZF64> for RGBF64 {
+    fn from(xyz: XYZF64) -> Self {
+        let XYZF64 { x, y, z } = xyz;
+        let (r, g, b) = mul_matrix3_f64(&XYZ_TO_SRGB_F64, (x, y, z));
+        let encode = |v: f64| linear_to_srgb_f64(v).clamp(0.0, 1.0);
+        Self {
+            r: encode(r),
+            g: encode(g),
+            b: encode(b),
+        }
+    }
+}
+
+impl From<LabF64> for RGBF64 {
+    fn from(lab: LabF64) -> Self {
+        let xyz: XYZF64 = lab.into();
+        xyz.into()
+    }
+}
+
+impl Display for RGBF64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "color(srgb {:.6} {:.6} {:.6})", self.r, self.g, self.b)
+    }
+}
+
+impl RGBF64 {
+    /// The channels without the sRGB gamma encoding.
+    pub fn to_linear(&self) -> (f64, f64, f64) {
+        (
+            srgb_to_linear_f64(self.r),
+            srgb_to_linear_f64(self.g),
+            srgb_to_linear_f64(self.b),
+        )
+    }
+}
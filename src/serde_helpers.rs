@@ -0,0 +1,346 @@
+//! `Serialize` and `Deserialize` for every color type, enabled by the `serde` feature.
+//! Every type with a float channel, and `YCbCr` and `Cam16` which carry a setting, is serialized as a struct of its
+//! exact fields, so it round-trips without the rounding of its string form. The integer types, `Hex`, `RGB`, `HWB`,
+//! `HSI`, `YCoCg`, `Gray` and `Kelvin`, are serialized as their canonical string, the same as `to_string()`.
+//! `Hex` stays a string since that is its format, its alpha is stored in 8 bits like `#RRGGBBAA`.
+//! `AnyColor` is tagged with the name of its variant. Every type still deserializes from its string form as well.
+//! ```rust
+//! use easy_color::{AnyColor, Hex, HSL, RGB};
+//! let hex:Hex = "#2BC48A".try_into().unwrap();
+//! assert_eq!(serde_json::to_string(&hex).unwrap(), r##""#2BC48A""##);
+//!
+//! let hsl:HSL = serde_json::from_str(r#""hsl(157 64% 47%)""#).unwrap();
+//! assert_eq!(hsl.to_string(), "hsl(157,64%,47%)");
+//! assert!(serde_json::from_str::<HSL>(r#""hsl(0,200%,0%)""#).is_err());
+//!
+//! let hsl = HSL { h: 157.5, s: 64.25, l: 47.0 };
+//! assert_eq!(serde_json::to_string(&hsl).unwrap(), r#"{"h":157.5,"s":64.25,"l":47.0}"#);
+//! assert!(serde_json::from_str::<HSL>(r#"{"h":0.0,"s":200.0,"l":0.0}"#).is_err());
+//!
+//! let color = AnyColor::RGB(RGB::new(255, 0, 0));
+//! assert_eq!(serde_json::to_string(&color).unwrap(), r#"{"RGB":"rgb(255,0,0)"}"#);
+//! let color:AnyColor = serde_json::from_str(r#""tomato""#).unwrap();
+//! assert_eq!(color, AnyColor::RGB(RGB::new(255, 99, 71)));
+//! ```
+use crate::{
+    AdobeRGB, Cam16, ColorError, ColorFunction, Din99, DisplayP3, Gray, HPLuv, HSLuv, Hex,
+    HunterLab, ICtCp, Jzazbz, Kelvin, LCHuv, LCh, Lab, LabF64, LinearRGB, Luv, Okhsl, Okhsv, Oklab,
+    Oklch, ProPhotoRGB, Rec2020, ViewingConditions, XyY, YCbCr, YCbCrStandard, YCoCg, CMYK, HSI,
+    HSL, HSLA, HSV, HSVA, HWB, RGB, RGBA, RGBF64, XYZ, XYZF64, YIQ, YUV,
+};
+use ::serde::de::Error;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+
+macro_rules! impl_serde {
+    ($($t:ident),* $(,)?) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let s = Cow::<str>::deserialize(deserializer)?;
+                    $t::try_from(s.as_ref()).map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+/// Serializes the fields of a type as a struct, deserializing accepts the struct or the string form.
+/// `$build` turns the deserialized fields into the type and checks their ranges.
+macro_rules! impl_serde_fields {
+    ($($t:ident as $repr:ident { $($field:ident: $ty:ty),* $(,)? } => |$r:ident| $build:expr;)*) => {
+        $(
+            #[derive(Serialize, Deserialize)]
+            struct $repr {
+                $($field: $ty,)*
+            }
+
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    $repr { $($field: self.$field,)* }.serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    #[derive(Deserialize)]
+                    #[serde(untagged)]
+                    enum Repr {
+                        Str(String),
+                        Fields($repr),
+                    }
+                    let color: Result<$t, ColorError> = match Repr::deserialize(deserializer)? {
+                        Repr::Str(s) => $t::try_from(s.as_str()),
+                        Repr::Fields($r) => $build,
+                    };
+                    color.map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+fn check_ranges<const N: usize>(
+    name: &str,
+    values: [f32; N],
+    max: [f32; N],
+) -> Result<(), ColorError> {
+    if values
+        .iter()
+        .zip(max)
+        .all(|(v, max)| (0.0..=max).contains(v))
+    {
+        Ok(())
+    } else {
+        Err(ColorError::ValueErr(format!(
+            "{}: args {:?} value error, the values must between 0~{:?}!",
+            name, values, max
+        )))
+    }
+}
+
+impl_serde_fields!(
+    HSL as HSLFields { h: f32, s: f32, l: f32 } => |r| {
+        check_ranges("HSL", [r.h, r.s, r.l], [360.0, 100.0, 100.0]).map(|_| HSL { h: r.h, s: r.s, l: r.l })
+    };
+    HSLA as HSLAFields { hsl: HSL, a: f32 } => |r| {
+        check_ranges("HSLA", [r.a], [1.0]).map(|_| HSLA { hsl: r.hsl, a: r.a })
+    };
+    HSV as HSVFields { h: f32, s: f32, v: f32 } => |r| {
+        check_ranges("HSV", [r.h, r.s, r.v], [360.0, 100.0, 100.0]).map(|_| HSV { h: r.h, s: r.s, v: r.v })
+    };
+    HSVA as HSVAFields { hsv: HSV, a: f32 } => |r| {
+        check_ranges("HSVA", [r.a], [1.0]).map(|_| HSVA { hsv: r.hsv, a: r.a })
+    };
+    CMYK as CMYKFields { c: f32, m: f32, y: f32, k: f32 } => |r| {
+        check_ranges("CMYK", [r.c, r.m, r.y, r.k], [100.0; 4]).map(|_| CMYK { c: r.c, m: r.m, y: r.y, k: r.k })
+    };
+    YCbCr as YCbCrFields { y: u8, cb: u8, cr: u8, standard: YCbCrStandard } => |r| {
+        Ok(YCbCr { y: r.y, cb: r.cb, cr: r.cr, standard: r.standard })
+    };
+    Cam16 as Cam16Fields { j: f32, c: f32, h: f32, conditions: ViewingConditions } => |r| {
+        Cam16::try_from((r.j, r.c, r.h)).map(|_| Cam16::from_jch(r.j, r.c, r.h, r.conditions))
+    };
+    RGBF64 as RGBF64Fields { r: f64, g: f64, b: f64 } => |r| RGBF64::try_from((r.r, r.g, r.b));
+    XYZF64 as XYZF64Fields { x: f64, y: f64, z: f64 } => |r| XYZF64::try_from((r.x, r.y, r.z));
+    LabF64 as LabF64Fields { l: f64, a: f64, b: f64 } => |r| LabF64::try_from((r.l, r.a, r.b));
+    RGBA as RGBAFields { rgb: RGB, a: f32 } => |r| RGBA::try_from((r.rgb.r, r.rgb.g, r.rgb.b, r.a));
+    Lab as LabFields { l: f32, a: f32, b: f32 } => |r| Lab::try_from((r.l, r.a, r.b));
+    LCh as LChFields { l: f32, c: f32, h: f32 } => |r| LCh::try_from((r.l, r.c, r.h));
+    Oklab as OklabFields { l: f32, a: f32, b: f32 } => |r| Oklab::try_from((r.l, r.a, r.b));
+    Oklch as OklchFields { l: f32, c: f32, h: f32 } => |r| Oklch::try_from((r.l, r.c, r.h));
+    Okhsl as OkhslFields { h: f32, s: f32, l: f32 } => |r| Okhsl::try_from((r.h, r.s, r.l));
+    Okhsv as OkhsvFields { h: f32, s: f32, v: f32 } => |r| Okhsv::try_from((r.h, r.s, r.v));
+    XYZ as XYZFields { x: f32, y: f32, z: f32 } => |r| XYZ::try_from((r.x, r.y, r.z));
+    XyY as XyYFields { x: f32, y: f32, luminance: f32 } => |r| XyY::try_from((r.x, r.y, r.luminance));
+    Luv as LuvFields { l: f32, u: f32, v: f32 } => |r| Luv::try_from((r.l, r.u, r.v));
+    LCHuv as LCHuvFields { l: f32, c: f32, h: f32 } => |r| LCHuv::try_from((r.l, r.c, r.h));
+    HSLuv as HSLuvFields { h: f32, s: f32, l: f32 } => |r| HSLuv::try_from((r.h, r.s, r.l));
+    HPLuv as HPLuvFields { h: f32, p: f32, l: f32 } => |r| HPLuv::try_from((r.h, r.p, r.l));
+    YUV as YUVFields { y: f32, u: f32, v: f32 } => |r| YUV::try_from((r.y, r.u, r.v));
+    YIQ as YIQFields { y: f32, i: f32, q: f32 } => |r| YIQ::try_from((r.y, r.i, r.q));
+    Jzazbz as JzazbzFields { jz: f32, az: f32, bz: f32 } => |r| Jzazbz::try_from((r.jz, r.az, r.bz));
+    ICtCp as ICtCpFields { i: f32, ct: f32, cp: f32 } => |r| ICtCp::try_from((r.i, r.ct, r.cp));
+    Din99 as Din99Fields { l: f32, a: f32, b: f32 } => |r| Din99::try_from((r.l, r.a, r.b));
+    HunterLab as HunterLabFields { l: f32, a: f32, b: f32 } => |r| HunterLab::try_from((r.l, r.a, r.b));
+    LinearRGB as LinearRGBFields { r: f32, g: f32, b: f32 } => |r| LinearRGB::try_from((r.r, r.g, r.b));
+    DisplayP3 as DisplayP3Fields { r: f32, g: f32, b: f32 } => |r| DisplayP3::try_from((r.r, r.g, r.b));
+    AdobeRGB as AdobeRGBFields { r: f32, g: f32, b: f32 } => |r| AdobeRGB::try_from((r.r, r.g, r.b));
+    Rec2020 as Rec2020Fields { r: f32, g: f32, b: f32 } => |r| Rec2020::try_from((r.r, r.g, r.b));
+    ProPhotoRGB as ProPhotoRGBFields { r: f32, g: f32, b: f32 } => |r| ProPhotoRGB::try_from((r.r, r.g, r.b));
+);
+
+/// `ColorFunction` keeps the exact channels of its space and its alpha, as an externally tagged enum.
+#[derive(Serialize, Deserialize)]
+enum ColorFunctionFields {
+    Srgb(RGB, f32),
+    SrgbLinear(LinearRGB, f32),
+    DisplayP3(DisplayP3, f32),
+    A98Rgb(AdobeRGB, f32),
+    ProPhotoRgb(ProPhotoRGB, f32),
+    Rec2020(Rec2020, f32),
+    Xyz(XYZ, f32),
+}
+
+impl Serialize for ColorFunction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Self::Srgb(c, a) => ColorFunctionFields::Srgb(c, a),
+            Self::SrgbLinear(c, a) => ColorFunctionFields::SrgbLinear(c, a),
+            Self::DisplayP3(c, a) => ColorFunctionFields::DisplayP3(c, a),
+            Self::A98Rgb(c, a) => ColorFunctionFields::A98Rgb(c, a),
+            Self::ProPhotoRgb(c, a) => ColorFunctionFields::ProPhotoRgb(c, a),
+            Self::Rec2020(c, a) => ColorFunctionFields::Rec2020(c, a),
+            Self::Xyz(c, a) => ColorFunctionFields::Xyz(c, a),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorFunction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Str(String),
+            Fields(ColorFunctionFields),
+        }
+        let color = match Repr::deserialize(deserializer)? {
+            Repr::Str(s) => ColorFunction::try_from(s.as_str()),
+            Repr::Fields(f) => {
+                let color = match f {
+                    ColorFunctionFields::Srgb(c, a) => ColorFunction::Srgb(c, a),
+                    ColorFunctionFields::SrgbLinear(c, a) => ColorFunction::SrgbLinear(c, a),
+                    ColorFunctionFields::DisplayP3(c, a) => ColorFunction::DisplayP3(c, a),
+                    ColorFunctionFields::A98Rgb(c, a) => ColorFunction::A98Rgb(c, a),
+                    ColorFunctionFields::ProPhotoRgb(c, a) => ColorFunction::ProPhotoRgb(c, a),
+                    ColorFunctionFields::Rec2020(c, a) => ColorFunction::Rec2020(c, a),
+                    ColorFunctionFields::Xyz(c, a) => ColorFunction::Xyz(c, a),
+                };
+                check_ranges("ColorFunction", [color.alpha()], [1.0]).map(|_| color)
+            }
+        };
+        color.map_err(D::Error::custom)
+    }
+}
+
+impl_serde!(Hex, RGB, HWB, HSI, YCoCg, Gray, Kelvin,);
+
+/// Serializes any color as a hex string like `"#FF0000"`, use it with `#[serde(with = "easy_color::serde_helpers::as_hex")]`.
+/// The alpha is kept as an 8-digit hex.
+/// ```rust
+/// use easy_color::RGB;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Theme {
+///     #[serde(with = "easy_color::serde_helpers::as_hex")]
+///     primary: RGB,
+/// }
+/// let theme:Theme = serde_json::from_str(r##"{"primary":"#2BC48A"}"##).unwrap();
+/// assert_eq!(theme.primary.to_string(), "rgb(43,196,138)");
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), r##"{"primary":"#2BC48A"}"##);
+/// ```
+pub mod as_hex {
+    use crate::Hex;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Into<Hex> + Copy,
+        S: Serializer,
+    {
+        let hex: Hex = (*color).into();
+        hex.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<Hex>,
+        D: Deserializer<'de>,
+    {
+        Hex::deserialize(deserializer).map(C::from)
+    }
+}
+
+/// Serializes any color as a `[r, g, b]` array, use it with `#[serde(with = "easy_color::serde_helpers::as_rgb_tuple")]`.
+/// ```rust
+/// use easy_color::HSL;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Theme {
+///     #[serde(with = "easy_color::serde_helpers::as_rgb_tuple")]
+///     primary: HSL,
+/// }
+/// let theme:Theme = serde_json::from_str(r#"{"primary":[255,0,0]}"#).unwrap();
+/// assert_eq!(theme.primary.to_string(), "hsl(0,100%,50%)");
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), r#"{"primary":[255,0,0]}"#);
+/// ```
+pub mod as_rgb_tuple {
+    use crate::RGB;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Into<RGB> + Copy,
+        S: Serializer,
+    {
+        let rgb: RGB = (*color).into();
+        (rgb.r, rgb.g, rgb.b).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<RGB>,
+        D: Deserializer<'de>,
+    {
+        let (r, g, b) = <(u8, u8, u8)>::deserialize(deserializer)?;
+        Ok(RGB { r, g, b }.into())
+    }
+}
+
+/// Serializes any color as a `{"r":255,"g":0,"b":0}` object, use it with `#[serde(with = "easy_color::serde_helpers::as_struct")]`.
+/// The `a` field is only written for translucent colors, and defaults to 1 when it is missing.
+/// ```rust
+/// use easy_color::{Hex, RGBA};
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Theme {
+///     #[serde(with = "easy_color::serde_helpers::as_struct")]
+///     primary: Hex,
+///     #[serde(with = "easy_color::serde_helpers::as_struct")]
+///     overlay: RGBA,
+/// }
+/// let json = r#"{"primary":{"r":43,"g":196,"b":138},"overlay":{"r":0,"g":0,"b":0,"a":0.5}}"#;
+/// let theme:Theme = serde_json::from_str(json).unwrap();
+/// assert_eq!(theme.primary.to_string(), "#2BC48A");
+/// assert_eq!(theme.overlay.to_string(), "rgba(0,0,0,0.50)");
+/// assert_eq!(serde_json::to_string(&theme).unwrap(), json);
+/// ```
+pub mod as_struct {
+    use crate::{RGB, RGBA};
+    use ::serde::de::Error;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Channels {
+        r: u8,
+        g: u8,
+        b: u8,
+        #[serde(default = "opaque", skip_serializing_if = "is_opaque")]
+        a: f32,
+    }
+
+    fn opaque() -> f32 {
+        1.0
+    }
+
+    fn is_opaque(a: &f32) -> bool {
+        *a == 1.0
+    }
+
+    pub fn serialize<C, S>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Into<RGBA> + Copy,
+        S: Serializer,
+    {
+        let rgba: RGBA = (*color).into();
+        let RGB { r, g, b } = rgba.rgb;
+        Channels { r, g, b, a: rgba.a }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: From<RGBA>,
+        D: Deserializer<'de>,
+    {
+        let Channels { r, g, b, a } = Channels::deserialize(deserializer)?;
+        RGBA::try_from((r, g, b, a))
+            .map(C::from)
+            .map_err(D::Error::custom)
+    }
+}
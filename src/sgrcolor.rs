@@ -0,0 +1,191 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, RGB};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// SgrColor is a color set by the SGR(Select Graphic Rendition) parameters of a terminal escape sequence.
+/// It can be parsed from the parameters of one color, with or without the "\x1b[" prefix and the "m" suffix:
+/// * 38;2;r;g;b and 48;2;r;g;b - truecolor
+/// * 38;5;n and 48;5;n - an index of the 256 color palette, see `RGB::from_ansi256`
+/// * 30~37, 90~97, 40~47 and 100~107 - the 16 standard and bright colors, see `RGB::from_ansi16`
+///
+/// `SgrColor::parse_all` reads every color of a longer sequence and skips the other parameters, like bold, reset
+/// or the underline color(58;2;r;g;b and 58;5;n).
+///
+/// ### example
+/// ```rust
+/// use easy_color::{SgrColor, RGB};
+/// let color:SgrColor = "38;2;43;196;138".try_into().unwrap();
+/// assert_eq!(color, SgrColor::Foreground((43, 196, 138).try_into().unwrap()));
+/// let color:SgrColor = "\x1b[48;5;208m".try_into().unwrap();
+/// assert_eq!(color.to_string(), "48;2;255;135;0");
+/// let color:SgrColor = "91".try_into().unwrap();
+/// let rgb:RGB = color.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+/// assert!(SgrColor::try_from("1").is_err());
+///
+/// let colors = SgrColor::parse_all("\x1b[1;38;5;21;43m").unwrap();
+/// assert_eq!(colors, [SgrColor::Foreground(RGB::from_ansi256(21)), SgrColor::Background(RGB::from_ansi16(3).unwrap())]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SgrColor {
+    Foreground(RGB),
+    Background(RGB),
+}
+
+impl SgrColor {
+    pub fn rgb(&self) -> RGB {
+        match *self {
+            Self::Foreground(rgb) | Self::Background(rgb) => rgb,
+        }
+    }
+
+    /// Parses every color of a SGR sequence such as "\x1b[1;38;2;255;0;0;48;5;21m" in order,
+    /// the parameters that are not colors are skipped. A malformed color is an error.
+    /// The colon separated form of ITU T.416 is read as one group, "38:2::255:0:0" and "38:2:255:0:0" are both red,
+    /// the color space id of a six field group is ignored.
+    /// ```rust
+    /// use easy_color::{SgrColor, RGB};
+    /// let colors = SgrColor::parse_all("\x1b[4:3;38:2::255:0:0;48:5:21m").unwrap();
+    /// assert_eq!(colors, [SgrColor::Foreground(RGB::new(255, 0, 0)), SgrColor::Background(RGB::from_ansi256(21))]);
+    /// let red:SgrColor = "\x1b[38:2::255:0:0m".parse().unwrap();
+    /// assert_eq!(red, SgrColor::Foreground(RGB::new(255, 0, 0)));
+    /// assert!(SgrColor::parse_all("38:2:255").is_err());
+    ///
+    /// // underline colors are skipped with their arguments
+    /// assert_eq!(SgrColor::parse_all("\x1b[58;5;31m").unwrap(), []);
+    /// assert_eq!(SgrColor::parse_all("58;2;30;40;50;31").unwrap(), [SgrColor::Foreground(RGB::from_ansi16(1).unwrap())]);
+    /// assert_eq!(SgrColor::parse_all("\x1b[4:3;58:2::30:40:50m").unwrap(), []);
+    /// ```
+    pub fn parse_all(sequence: &str) -> Result<Vec<Self>, ColorError> {
+        let number = |p: &str| {
+            if p.is_empty() {
+                Ok(0)
+            } else {
+                p.parse::<u8>().map_err(|_| format_err(sequence))
+            }
+        };
+        let mut colors = Vec::new();
+        // consecutive semicolon separated parameters, a color can span several of them
+        let mut run = Vec::new();
+        for param in trim_sequence(sequence).split(';') {
+            if !param.contains(':') {
+                run.push(number(param)?);
+                continue;
+            }
+            if !parse_run(&run, &mut colors) {
+                return Err(format_err(sequence));
+            }
+            run.clear();
+            let group = param
+                .split(':')
+                .map(number)
+                .collect::<Result<Vec<_>, _>>()?;
+            let without_id;
+            let group = match *group.as_slice() {
+                [code, 2, _, r, g, b] => {
+                    without_id = [code, 2, r, g, b];
+                    &without_id[..]
+                }
+                _ => group.as_slice(),
+            };
+            match parse_params(group) {
+                Some((color, len)) if len == group.len() => colors.push(color),
+                _ if underline_len(group) == Some(group.len()) => {}
+                _ if matches!(group[0], 38 | 48 | 58) => return Err(format_err(sequence)),
+                _ => {}
+            }
+        }
+        if !parse_run(&run, &mut colors) {
+            return Err(format_err(sequence));
+        }
+        Ok(colors)
+    }
+}
+
+/// Reads the colors of semicolon separated parameters, returns false for a malformed 38, 48 or 58 color.
+fn parse_run(params: &[u8], colors: &mut Vec<SgrColor>) -> bool {
+    let mut rest = params;
+    while let Some(&code) = rest.first() {
+        match parse_params(rest) {
+            Some((color, len)) => {
+                colors.push(color);
+                rest = &rest[len..];
+            }
+            None => match underline_len(rest) {
+                Some(len) => rest = &rest[len..],
+                None if matches!(code, 38 | 48 | 58) => return false,
+                None => rest = &rest[1..],
+            },
+        }
+    }
+    true
+}
+
+/// The number of parameters of an underline color(58) at the start of the parameters,
+/// it is skipped as a whole so that its arguments are not read as colors.
+fn underline_len(params: &[u8]) -> Option<usize> {
+    match *params {
+        [58, 2, _, _, _, ..] => Some(5),
+        [58, 5, _, ..] => Some(3),
+        _ => None,
+    }
+}
+
+fn format_err(sgr_str: &str) -> ColorError {
+    ColorError::FormatErr(format!("SgrColor: {:?} format error!", sgr_str))
+}
+
+fn trim_sequence(sequence: &str) -> &str {
+    let s = sequence.trim();
+    let s = s.strip_prefix("\x1b[").unwrap_or(s);
+    s.strip_suffix('m').unwrap_or(s)
+}
+
+/// Parses the color at the start of the parameters, returns it and the number of parameters it takes.
+fn parse_params(params: &[u8]) -> Option<(SgrColor, usize)> {
+    let layer = |code: u8, rgb: RGB| {
+        if code == 38 || (30..=37).contains(&code) || (90..=97).contains(&code) {
+            SgrColor::Foreground(rgb)
+        } else {
+            SgrColor::Background(rgb)
+        }
+    };
+    match *params {
+        [code @ (38 | 48), 2, r, g, b, ..] => Some((layer(code, RGB { r, g, b }), 5)),
+        [code @ (38 | 48), 5, n, ..] => Some((layer(code, RGB::from_ansi256(n)), 3)),
+        [code @ (30..=37 | 40..=47), ..] => Some((layer(code, RGB::from_ansi256(code % 10)), 1)),
+        [code @ (90..=97 | 100..=107), ..] => {
+            Some((layer(code, RGB::from_ansi256(code % 10 + 8)), 1))
+        }
+        _ => None,
+    }
+}
+
+impl TryFrom<&str> for SgrColor {
+    type Error = ColorError;
+    fn try_from(sgr_str: &str) -> Result<Self, Self::Error> {
+        match Self::parse_all(sgr_str)?.as_slice() {
+            [color] => Ok(*color),
+            _ => Err(format_err(sgr_str)),
+        }
+    }
+}
+
+impl FromStr for SgrColor {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl Display for SgrColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (code, RGB { r, g, b }) = match *self {
+            Self::Foreground(rgb) => (38, rgb),
+            Self::Background(rgb) => (48, rgb),
+        };
+        write!(f, "{};2;{};{};{}", code, r, g, b)
+    }
+}
@@ -1,8 +1,24 @@
-use crate::{RGB, RGBA, HSL, Hex, HSLA, CMYK, HSV};
+use crate::{ANSI_RESET, BlendMode, Cie94Application, Deficiency, Easing, InterpolationSpace, RGB, RGBA, HSL, Hex, HSLA, CMYK, HSI, HSV, HWB, LCh, Lab, Okhsl, Okhsv, Oklab, Oklch, TextSize, WcagLevel, XyY, XYZ, YCbCrStandard};
+use crate::common::{apca_contrast, relative_luminance};
+use crate::ansi::{nearest_ansi16, nearest_ansi256};
+use crate::named::nearest_named;
+use core::fmt::Display;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 
+/// Tells dark colors from light ones by their WCAG relative luminance, the default threshold is 0.179
+/// where black and white text have the same contrast. `is_dark_with` and `is_light_with` take a custom threshold(0~1).
+/// ```rust
+/// use easy_color::{Color, Hex};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert!(hex.is_light());
+/// assert!(hex.is_dark_with(0.5));
+/// ```
 pub trait Color {
     fn is_dark(&self) -> bool;
     fn is_light(&self) -> bool;
+    fn is_dark_with(&self, threshold:f32) -> bool;
+    fn is_light_with(&self, threshold:f32) -> bool;
 }
 
 impl<T:Into<RGB>+Copy> Color for T {
@@ -15,10 +31,369 @@ impl<T:Into<RGB>+Copy> Color for T {
         !self.is_dark()
     }
 
+    fn is_dark_with(&self, threshold:f32) -> bool {
+        let rgb:RGB=(*self).into();
+        rgb.is_dark_with(threshold)
+    }
+
+    fn is_light_with(&self, threshold:f32) -> bool {
+        !self.is_dark_with(threshold)
+    }
+}
+
+/// The WCAG 2.1 relative luminance(0~1) of any color, the sRGB channels are linearized before they are weighted.
+/// A translucent color is composited onto white first, as `RGBA` does when it converts into `RGB`.
+/// ```rust
+/// use easy_color::{Hex, Luminance, HSL, RGB, RGBA};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(format!("{:.4}", hex.relative_luminance()), "0.4183");
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// assert_eq!(white.relative_luminance(), 1.0);
+/// let hsl:HSL = (0, 0, 50).try_into().unwrap();
+/// assert_eq!(format!("{:.4}", hsl.relative_luminance()), "0.2159");
+/// let clear:RGBA = (0, 0, 0, 0.0).try_into().unwrap();
+/// assert_eq!(clear.relative_luminance(), 1.0);
+/// ```
+pub trait Luminance {
+    fn relative_luminance(&self) -> f32;
+}
+
+impl<T:Into<RGB> + Copy> Luminance for T {
+    fn relative_luminance(&self) -> f32 {
+        let rgb:RGB = (*self).into();
+        relative_luminance(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+/// The WCAG 2.1 contrast ratio(1~21) between any two colors, `(L1 + 0.05) / (L2 + 0.05)` where L1 is the relative luminance
+/// of the lighter color. The order of the colors does not matter.
+/// A translucent color is measured as it looks composited onto white, the way `RGBA` converts into `RGB`.
+/// ```rust
+/// use easy_color::{Contrast, Hex, TextSize, WcagLevel, RGB, RGBA};
+/// let black:RGB = (0, 0, 0).try_into().unwrap();
+/// let white:Hex = "#FFFFFF".try_into().unwrap();
+/// assert_eq!(format!("{:.2}", black.contrast_ratio(white)), "21.00");
+///
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(format!("{:.2}", hex.contrast_ratio(white)), "2.24");
+/// assert_eq!(format!("{:.2}", hex.contrast_ratio(black)), "9.37");
+///
+/// // the thresholds of the WCAG levels are built in
+/// assert!(hex.meets_wcag(black, WcagLevel::AAA, TextSize::Normal));
+/// assert!(!hex.meets_wcag(white, WcagLevel::AA, TextSize::Normal));
+/// assert!(!hex.meets_wcag(white, WcagLevel::AA, TextSize::Large));
+///
+/// // APCA Lc of text on a background
+/// let gray:Hex = "#888888".try_into().unwrap();
+/// assert_eq!(format!("{:.2}", black.apca_contrast(white)), "106.04");
+/// assert_eq!(format!("{:.2}", white.apca_contrast(black)), "-107.88");
+/// assert_eq!(format!("{:.2}", gray.apca_contrast(white)), "63.06");
+/// assert_eq!(format!("{:.2}", white.apca_contrast(gray)), "-68.54");
+///
+/// let half_black:RGBA = (0, 0, 0, 0.5).try_into().unwrap();
+/// assert_eq!(format!("{:.2}", half_black.contrast_ratio(white)), "4.00");
+/// ```
+pub trait Contrast<T> {
+    fn contrast_ratio(&self, other:T) -> f32;
+    /// Whether the contrast ratio of text in `self` on `other`, or the other way around, reaches the WCAG level.
+    fn meets_wcag(&self, other:T, level:WcagLevel, size:TextSize) -> bool;
+    /// The APCA lightness contrast(Lc, about -108~106) of text in `self` on the `background`, as drafted for WCAG 3.
+    /// It is positive for dark text on a light background and negative for light text on a dark one,
+    /// unlike the WCAG 2.1 ratio the order of the colors matters.
+    fn apca_contrast(&self, background:T) -> f32;
+}
+
+impl<T:Into<RGB> + Copy, U:Into<RGB> + Copy> Contrast<T> for U {
+    fn contrast_ratio(&self, other:T) -> f32 {
+        let (l1, l2) = (self.relative_luminance(), other.relative_luminance());
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    fn meets_wcag(&self, other:T, level:WcagLevel, size:TextSize) -> bool {
+        self.contrast_ratio(other) >= level.min_contrast(size)
+    }
+
+    fn apca_contrast(&self, background:T) -> f32 {
+        let (text, background):(RGB, RGB) = ((*self).into(), background.into());
+        apca_contrast((text.r, text.g, text.b), (background.r, background.g, background.b))
+    }
+}
+
+/// Picks a text color for `self` as the background, the one with the highest WCAG contrast ratio on it.
+/// `readable_on` chooses between black and white, `most_readable` between the given candidates.
+/// ```rust
+/// use easy_color::{Hex, Readable, RGB};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.readable_on().to_string(), "rgb(0,0,0)");
+/// let navy:RGB = (0, 0, 128).try_into().unwrap();
+/// assert_eq!(navy.readable_on().to_string(), "rgb(255,255,255)");
+///
+/// let candidates:[Hex; 3] = ["#FFD700".try_into().unwrap(), "#FF69B4".try_into().unwrap(), "#87CEEB".try_into().unwrap()];
+/// assert_eq!(navy.most_readable(&candidates).unwrap().to_string(), "#FFD700");
+/// assert_eq!(navy.most_readable::<Hex>(&[]), None);
+/// ```
+pub trait Readable {
+    fn readable_on(&self) -> RGB;
+    fn most_readable<T:Into<RGB> + Copy>(&self, candidates:&[T]) -> Option<T>;
+}
+
+impl<U:Into<RGB> + Copy> Readable for U {
+    fn readable_on(&self) -> RGB {
+        let black = RGB::new(0, 0, 0);
+        let white = RGB::new(255, 255, 255);
+        if self.contrast_ratio(white) > self.contrast_ratio(black) { white } else { black }
+    }
+
+    fn most_readable<T:Into<RGB> + Copy>(&self, candidates:&[T]) -> Option<T> {
+        candidates.iter().copied().max_by(|a, b| self.contrast_ratio(*a).total_cmp(&self.contrast_ratio(*b)))
+    }
+}
+
+/// Color differences between any two colors, both are converted to Lab first.
+/// Like the `RGBA` to `Lab` conversion, a translucent color is composited onto white before it is compared.
+/// ```rust
+/// use easy_color::{Cie94Application, DeltaE, Hex, RGB, RGBA};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// let rgb:RGB = (45, 196, 138).try_into().unwrap();
+/// assert_eq!(format!("{:.2}", hex.delta_e76(rgb)), "0.24");
+/// assert_eq!(hex.delta_e76(hex), 0.0);
+/// assert_eq!(format!("{:.2}", hex.delta_e94(rgb, Cie94Application::GraphicArts)), "0.09");
+/// assert_eq!(format!("{:.2}", hex.delta_e2000(rgb)), "0.09");
+///
+/// // fully transparent black looks like the white under it
+/// let clear:RGBA = (0, 0, 0, 0.0).try_into().unwrap();
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// assert_eq!(clear.delta_e2000(white), 0.0);
+/// ```
+pub trait DeltaE<T> {
+    /// The CIE76 difference, the euclidean distance in Lab.
+    fn delta_e76(&self, other:T) -> f32;
+    /// The CIE94 difference with the weights of the given application, `self` is the reference color.
+    fn delta_e94(&self, other:T, application:Cie94Application) -> f32;
+    /// The CIEDE2000 difference, the most perceptually uniform of the three.
+    fn delta_e2000(&self, other:T) -> f32;
+}
+
+impl<T:Into<RGBA> + Copy, U:Into<RGBA> + Copy> DeltaE<T> for U {
+    fn delta_e76(&self, other:T) -> f32 {
+        let (rgba, other):(RGBA, RGBA) = ((*self).into(), other.into());
+        Lab::from(rgba).delta_e76(other)
+    }
+
+    fn delta_e94(&self, other:T, application:Cie94Application) -> f32 {
+        let (rgba, other):(RGBA, RGBA) = ((*self).into(), other.into());
+        Lab::from(rgba).delta_e94(other, application)
+    }
+
+    fn delta_e2000(&self, other:T) -> f32 {
+        let (rgba, other):(RGBA, RGBA) = ((*self).into(), other.into());
+        Lab::from(rgba).delta_e2000(other)
+    }
+}
+
+/// Cheap color distances in sRGB without any conversion to Lab, for lookups over many pixels.
+/// Both colors go through `RGB`, so a translucent `RGBA` is composited onto white before it is measured.
+/// `distance` is the "redmean" approximation(0~765) of perceptual difference,
+/// `weighted_distance` is the euclidean distance with custom weights of the red, green and blue channels.
+/// ```rust
+/// use easy_color::{Distance, Hex, RGB, RGBA};
+/// let black:RGB = (0, 0, 0).try_into().unwrap();
+/// let white:Hex = "#FFFFFF".try_into().unwrap();
+/// assert_eq!(format!("{:.2}", black.distance(white)), "764.83");
+///
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// let rgb:RGB = (45, 196, 138).try_into().unwrap();
+/// assert_eq!(format!("{:.2}", hex.distance(rgb)), "2.95");
+/// assert_eq!(hex.weighted_distance(rgb, (1.0, 1.0, 1.0)), 2.0);
+///
+/// let clear:RGBA = (0, 0, 0, 0.0).try_into().unwrap();
+/// assert_eq!(clear.distance(white), 0.0);
+/// ```
+pub trait Distance<T> {
+    fn distance(&self, other:T) -> f32;
+    fn weighted_distance(&self, other:T, weights:(f32, f32, f32)) -> f32;
+}
+
+impl<T:Into<RGB> + Copy, U:Into<RGB> + Copy> Distance<T> for U {
+    fn distance(&self, other:T) -> f32 {
+        let (rgb, other):(RGB, RGB) = ((*self).into(), other.into());
+        let r_mean = (rgb.r as f32 + other.r as f32) / 2.0;
+        self.weighted_distance(other, (2.0 + r_mean / 256.0, 4.0, 2.0 + (255.0 - r_mean) / 256.0))
+    }
+
+    fn weighted_distance(&self, other:T, weights:(f32, f32, f32)) -> f32 {
+        let (rgb, other):(RGB, RGB) = ((*self).into(), other.into());
+        let (dr, dg, db) = (rgb.r as f32 - other.r as f32, rgb.g as f32 - other.g as f32, rgb.b as f32 - other.b as f32);
+        (weights.0 * dr * dr + weights.1 * dg * dg + weights.2 * db * db).sqrt()
+    }
+}
+
+/// Simulates how any color is perceived with a color vision deficiency, `severity` is 0~1 where 1 is the full dichromacy.
+/// The alpha is kept. See `Deficiency` for the model.
+/// ```rust
+/// use easy_color::{ColorVision, Deficiency, Hex};
+/// let red:Hex = "#FF0000".try_into().unwrap();
+/// let green:Hex = "#00FF00".try_into().unwrap();
+/// assert_eq!(red.simulate_cvd(Deficiency::Deuteranopia, 1.0).to_string(), "#A39000");
+/// assert_eq!(green.simulate_cvd(Deficiency::Deuteranopia, 1.0).to_string(), "#EFD63A");
+/// assert_eq!(red.simulate_cvd(Deficiency::Protanopia, 1.0).to_string(), "#6D5F00");
+/// assert_eq!(red.simulate_cvd(Deficiency::Tritanopia, 1.0).to_string(), "#FF000F");
+/// assert_eq!(red.simulate_cvd(Deficiency::Protanopia, 0.0), red);
+///
+/// // daltonizing moves the lost red-green difference into the green and blue channels
+/// assert_eq!(red.daltonize(Deficiency::Deuteranopia).to_string(), "#FF70B4");
+/// let gray:Hex = "#808080".try_into().unwrap();
+/// assert_eq!(gray.daltonize(Deficiency::Deuteranopia), gray);
+/// ```
+pub trait ColorVision {
+    fn simulate_cvd(&self, deficiency:Deficiency, severity:f32) -> Self;
+    /// Corrects the color for the deficiency, so colors that look alike with it are told apart again.
+    fn daltonize(&self, deficiency:Deficiency) -> Self;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> ColorVision for T {
+    fn simulate_cvd(&self, deficiency:Deficiency, severity:f32) -> Self {
+        deficiency.simulate((*self).into(), severity).into()
+    }
+
+    fn daltonize(&self, deficiency:Deficiency) -> Self {
+        deficiency.daltonize((*self).into()).into()
+    }
+}
+
+/// Estimates the correlated color temperature(CCT) in kelvin of any color, see `XyY::cct`.
+/// It is meaningful for whites and near whites only, like the white of a photo of a lighting setup.
+/// ```rust
+/// use easy_color::{ColorTemperature, RGB};
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// assert_eq!(white.cct().round(), 6503.0);
+/// // "this white is ~3900K"
+/// let warm:RGB = (255, 209, 163).try_into().unwrap();
+/// assert_eq!((warm.cct() / 100.0).round() * 100.0, 3900.0);
+/// ```
+pub trait ColorTemperature {
+    fn cct(&self) -> f32;
+}
+
+impl<T:Into<RGB> + Copy> ColorTemperature for T {
+    fn cct(&self) -> f32 {
+        let rgb:RGB = (*self).into();
+        XyY::from(rgb).cct()
+    }
+}
+
+/// Maps any color onto the palettes of terminals, so CLI tools can degrade gracefully. The closest color is found in Oklab.
+/// ```rust
+/// use easy_color::{Ansi, Hex, ANSI_RESET, RGB};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.to_ansi16(), 6);
+/// let orange:RGB = (255, 140, 0).try_into().unwrap();
+/// assert_eq!(orange.to_ansi16(), 3);
+///
+/// assert_eq!(orange.to_ansi256(), 208);
+/// assert_eq!(hex.to_ansi256(), 42);
+/// let gray:RGB = (128, 128, 128).try_into().unwrap();
+/// assert_eq!(gray.to_ansi256(), 244);
+/// let gray:RGB = (100, 100, 100).try_into().unwrap();
+/// assert_eq!(gray.to_ansi256(), 241);
+///
+/// // truecolor escape sequences
+/// assert_eq!(hex.ansi_fg(), "\x1b[38;2;43;196;138m");
+/// assert_eq!(hex.ansi_bg(), "\x1b[48;2;43;196;138m");
+/// let line = format!("{}ok{}", hex.ansi_fg(), ANSI_RESET);
+/// assert_eq!(line, "\x1b[38;2;43;196;138mok\x1b[0m");
+/// ```
+pub trait Ansi {
+    /// The index(0~15) of the closest standard or bright terminal color, see `RGB::from_ansi16`.
+    fn to_ansi16(&self) -> u8;
+    /// The index(16~255) of the closest color of the 6x6x6 cube or the grayscale ramp of the 256 color palette,
+    /// see `RGB::from_ansi256`. The 16 system colors are skipped since terminals let users change them.
+    fn to_ansi256(&self) -> u8;
+    /// The truecolor escape sequence that sets the foreground(text) color, "\x1b[38;2;r;g;bm".
+    fn ansi_fg(&self) -> String;
+    /// The truecolor escape sequence that sets the background color, "\x1b[48;2;r;g;bm".
+    fn ansi_bg(&self) -> String;
+}
+
+impl<T:Into<RGB> + Copy> Ansi for T {
+    fn to_ansi16(&self) -> u8 {
+        let rgb:RGB = (*self).into();
+        nearest_ansi16(rgb.r, rgb.g, rgb.b)
+    }
+
+    fn to_ansi256(&self) -> u8 {
+        let rgb:RGB = (*self).into();
+        nearest_ansi256(rgb.r, rgb.g, rgb.b)
+    }
+
+    fn ansi_fg(&self) -> String {
+        let rgb:RGB = (*self).into();
+        format!("\x1b[38;2;{};{};{}m", rgb.r, rgb.g, rgb.b)
+    }
+
+    fn ansi_bg(&self) -> String {
+        let rgb:RGB = (*self).into();
+        format!("\x1b[48;2;{};{};{}m", rgb.r, rgb.g, rgb.b)
+    }
+}
+
+/// Prints colors in the terminal while debugging palette code. `swatch()` is a block of two spaces painted with
+/// the truecolor background escape and `preview()` follows the swatch with the string form of the color.
+/// ```rust
+/// use easy_color::{Hex, Preview, RGB};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.swatch(), "\x1b[48;2;43;196;138m  \x1b[0m");
+/// assert_eq!(hex.preview(), "\x1b[48;2;43;196;138m  \x1b[0m #2BC48A");
+/// let rgb:RGB = hex.into();
+/// println!("{}", rgb.preview());
+/// ```
+pub trait Preview {
+    fn swatch(&self) -> String;
+    fn preview(&self) -> String;
+}
+
+impl<T:Into<RGB> + Display + Copy> Preview for T {
+    fn swatch(&self) -> String {
+        format!("{}  {}", self.ansi_bg(), ANSI_RESET)
+    }
+
+    fn preview(&self) -> String {
+        format!("{} {}", self.swatch(), self)
+    }
+}
+
+/// Finds the closest CSS named color, the distance is measured in Oklab, so `0.0` is an exact match.
+/// ```rust
+/// use easy_color::{Hex, NearestNamed};
+/// let hex:Hex = "#FF6446".try_into().unwrap();
+/// let (name, distance) = hex.nearest_named();
+/// assert_eq!(name, "tomato");
+/// assert!(distance < 0.01);
+///
+/// let hex:Hex = "#00FFFF".try_into().unwrap();
+/// assert_eq!(hex.nearest_named(), ("aqua", 0.0));
+/// ```
+pub trait NearestNamed {
+    fn nearest_named(&self) -> (&'static str, f32);
 }
 
+impl<T:Into<RGB>+Copy> NearestNamed for T {
+    fn nearest_named(&self) -> (&'static str, f32) {
+        let rgb:RGB = (*self).into();
+        nearest_named(rgb.r, rgb.g, rgb.b)
+    }
+}
+
+/// Converts any color to gray with luma weights, `grayscale()` uses BT.601 like most image editors.
+/// ```rust
+/// use easy_color::{Grayscale, Hex, YCbCrStandard};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.grayscale().to_string(), "#909090");
+/// assert_eq!(hex.grayscale_with(YCbCrStandard::BT709).to_string(), "#9F9F9F");
+/// ```
 pub trait Grayscale {
     fn grayscale(&self) -> Self; 
+    fn grayscale_with(&self, standard:YCbCrStandard) -> Self;
 }
 
 impl<T: Into<RGBA> + From<RGBA> + Copy >  Grayscale for T {
@@ -26,10 +401,27 @@ impl<T: Into<RGBA> + From<RGBA> + Copy >  Grayscale for T {
         let rgba:RGBA=(*self).into();
         rgba.grayscale().into()
     }
+
+    fn grayscale_with(&self, standard:YCbCrStandard) -> Self {
+        let rgba:RGBA=(*self).into();
+        rgba.grayscale_with(standard).into()
+    }
 }
 
+/// Inverts any color in RGB, every channel becomes 255 - channel and the alpha is kept.
+/// `invert()` is the same as `negate()`.
+/// ```rust
+/// use easy_color::{Hex, Negate, HSL};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.invert().to_string(), "#D43B75");
+/// let hsl:HSL = hex.into();
+/// assert_eq!(hsl.invert().to_string(), "hsl(337,64%,53%)");
+/// ```
 pub trait Negate {
     fn negate(&self) -> Self;
+    fn invert(&self) -> Self where Self: Sized {
+        self.negate()
+    }
 }
 
 impl<T: Into<RGBA> + From<RGBA> + Copy >  Negate for T {
@@ -39,8 +431,31 @@ impl<T: Into<RGBA> + From<RGBA> + Copy >  Negate for T {
     }
 }
 
+/// Blends two colors of any type by a weight, the result has the type of `self`.
+/// `weight` is the share of `other`(0~1, `None` is an even mix), so `Some(0.3)` moves the color 30% toward `other`.
+/// The channels are mixed in RGBA and the alphas are taken into account like the Sass `mix()` function.
+/// ```rust
+/// use easy_color::{ColorMix, Hex, HSL, RGB};
+/// let brand:Hex = "#2BC48A".try_into().unwrap();
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// assert_eq!(brand.mix(white, Some(0.3)).to_string(), "#6BD6AD");
+///
+/// let other:HSL = (262, 85, 79).try_into().unwrap();
+/// assert_eq!(brand.mix(other, None).to_string(), "#74B0C1");
+/// ```
+/// `mix_eased` runs the progress `t`(0~1) through an `Easing` before using it as the weight.
+/// ```rust
+/// use easy_color::{ColorMix, Easing, RGB};
+/// let black:RGB = (0, 0, 0).try_into().unwrap();
+/// let white:RGB = (255, 255, 255).try_into().unwrap();
+/// assert_eq!(black.mix_eased(white, 0.25, Easing::Linear), black.mix(white, Some(0.25)));
+/// assert_eq!(black.mix_eased(white, 0.25, Easing::EaseInOut).to_string(), "rgb(33,33,33)");
+/// ```
 pub trait ColorMix<T> {
     fn mix(&self, other:T, weight:Option<f32>) -> Self;
+    fn mix_eased(&self, other:T, t:f32, easing:Easing) -> Self where Self: Sized {
+        self.mix(other, Some(easing.apply(t)))
+    }
 }
 impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> ColorMix<T> for U {
     fn mix(&self, other: T, weight: Option<f32>) -> Self {
@@ -50,6 +465,131 @@ impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> ColorMix<T> for U {
 }
 
 
+/// Interpolates between two colors in a chosen color space, `t` is the position from `self`(0) to `other`(1).
+/// The same two colors give very different midpoints in different spaces.
+/// ```rust
+/// use easy_color::{Interpolate, InterpolationSpace, RGB};
+/// let purple:RGB = (128, 0, 128).try_into().unwrap();
+/// let green:RGB = (0, 128, 0).try_into().unwrap();
+/// assert_eq!(purple.lerp_in(InterpolationSpace::Rgb, green, 0.5).to_string(), "rgb(64,64,64)");
+/// assert_eq!(purple.lerp_in(InterpolationSpace::Hsl, green, 0.5).to_string(), "rgb(0,64,128)");
+/// assert_eq!(purple.lerp_in(InterpolationSpace::Oklab, green, 0.5).to_string(), "rgb(98,88,89)");
+/// assert_eq!(purple.lerp_in(InterpolationSpace::Oklch, green, 0.5).to_string(), "rgb(162,44,0)");
+/// ```
+pub trait Interpolate<T> {
+    fn lerp_in(&self, space:InterpolationSpace, other:T, t:f32) -> Self;
+}
+
+impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> Interpolate<T> for U {
+    fn lerp_in(&self, space:InterpolationSpace, other:T, t:f32) -> Self {
+        space.lerp((*self).into(), other.into(), t).into()
+    }
+}
+
+/// Blends `other` as a layer on top of `self` with a Photoshop-style `BlendMode`, the result has the type of `self`.
+/// The alphas are composited with source-over, as in the W3C Compositing and Blending spec.
+/// ```rust
+/// use easy_color::{Blend, BlendMode, Hex};
+/// let base:Hex = "#2BC48A".try_into().unwrap();
+/// let layer:Hex = "#FF8040".try_into().unwrap();
+/// assert_eq!(base.blend(layer, BlendMode::Multiply).to_string(), "#2B6223");
+/// assert_eq!(base.blend(layer, BlendMode::Screen).to_string(), "#FFE2A7");
+/// assert_eq!(base.blend(layer, BlendMode::Overlay).to_string(), "#56C450");
+/// assert_eq!(base.blend(layer, BlendMode::Difference).to_string(), "#D4444A");
+/// assert_eq!(base.blend(layer, BlendMode::Hue).to_string(), "#DD7744");
+/// assert_eq!(base.blend(layer, BlendMode::Luminosity).to_string(), "#3AD399");
+/// ```
+pub trait Blend<T> {
+    fn blend(&self, other:T, mode:BlendMode) -> Self;
+}
+
+impl<T:Into<RGBA> + Copy, U: Into<RGBA> + From<RGBA> + Copy> Blend<T> for U {
+    fn blend(&self, other:T, mode:BlendMode) -> Self {
+        mode.blend((*self).into(), other.into()).into()
+    }
+}
+
+/// Mixes any color toward white by the given ratio(0~1) in RGB, unlike `lighten` the hue and the saturation drift to white as well.
+/// `tints(n)` returns n tints evenly spaced toward white.
+/// ```rust
+/// use easy_color::{Hex, Shade, Tint, HSL};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.tint(0.3).to_string(), "#6BD6AD");
+/// assert_eq!(hex.shade(0.3).to_string(), "#1E8961");
+/// let hsl:HSL = (157, 64, 47).try_into().unwrap();
+/// assert_eq!(hsl.tint(0.5).to_string(), "hsl(157,57%,74%)");
+///
+/// // a scale of 4 tints, evenly spaced between the color and white, both excluded
+/// let tints:Vec<String> = hex.tints(4).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(tints, ["#55D0A1", "#80DCB9", "#AAE7D0", "#D5F3E8"]);
+/// ```
+pub trait Tint {
+    fn tint(&self, ratio:f32) -> Self;
+    fn tints(&self, n:usize) -> Vec<Self> where Self: Sized;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> Tint for T {
+    fn tint(&self, ratio:f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.tint(ratio).into()
+    }
+
+    fn tints(&self, n:usize) -> Vec<Self> {
+        let rgba:RGBA = (*self).into();
+        (1..=n).map(|i| rgba.tint(i as f32 / (n + 1) as f32).into()).collect()
+    }
+}
+
+/// Mixes any color toward black by the given ratio(0~1) in RGB, `shades(n)` returns n shades evenly spaced toward black.
+/// ```rust
+/// use easy_color::{Hex, Shade};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// let shades:Vec<String> = hex.shades(4).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(shades, ["#229D6E", "#1A7653", "#114E37", "#09271C"]);
+/// ```
+pub trait Shade {
+    fn shade(&self, ratio:f32) -> Self;
+    fn shades(&self, n:usize) -> Vec<Self> where Self: Sized;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> Shade for T {
+    fn shade(&self, ratio:f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.shade(ratio).into()
+    }
+
+    fn shades(&self, n:usize) -> Vec<Self> {
+        let rgba:RGBA = (*self).into();
+        (1..=n).map(|i| rgba.shade(i as f32 / (n + 1) as f32).into()).collect()
+    }
+}
+
+/// Mixes any color toward mid-gray rgb(128,128,128) by the given ratio(0~1) in RGB, which mutes it without making it lighter or darker.
+/// `tones(n)` returns n tones evenly spaced toward the gray.
+/// ```rust
+/// use easy_color::{Hex, Tone};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.tone(0.3).to_string(), "#45B087");
+/// let tones:Vec<String> = hex.tones(4).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(tones, ["#3CB688", "#4DA986", "#5E9B84", "#6F8E82"]);
+/// ```
+pub trait Tone {
+    fn tone(&self, ratio:f32) -> Self;
+    fn tones(&self, n:usize) -> Vec<Self> where Self: Sized;
+}
+
+impl<T:Into<RGBA> + From<RGBA> + Copy> Tone for T {
+    fn tone(&self, ratio:f32) -> Self {
+        let rgba:RGBA = (*self).into();
+        rgba.tone(ratio).into()
+    }
+
+    fn tones(&self, n:usize) -> Vec<Self> {
+        let rgba:RGBA = (*self).into();
+        (1..=n).map(|i| rgba.tone(i as f32 / (n + 1) as f32).into()).collect()
+    }
+}
+
 pub trait Darken {
     fn darken(&mut self, ratio:f32) -> Self;
 }
@@ -72,6 +612,207 @@ impl<T:Into<HSL> + From<HSL> + Copy> Lighten for T {
     }
 }
 
+/// Saturates any color by the given ratio(0~1) of its HSL saturation, the result has the type of `self`.
+/// ```rust
+/// use easy_color::{Desaturate, Hex, RGB, RGBA, Saturate};
+/// let mut rgb:RGB = (153, 102, 102).try_into().unwrap();
+/// assert_eq!(rgb.saturate(0.5).to_string(), "rgb(166,89,89)");
+/// assert_eq!(rgb.desaturate(1.0).to_string(), "rgb(128,128,128)");
+///
+/// let mut hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.desaturate(0.5).to_string(), "#519E81");
+///
+/// // the alpha is kept
+/// let mut rgba:RGBA = (153, 102, 102, 0.5).try_into().unwrap();
+/// assert_eq!(rgba.saturate(0.5).to_string(), "rgba(166,89,89,0.50)");
+/// ```
+pub trait Saturate {
+    fn saturate(&mut self, ratio:f32) -> Self;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Saturate for T {
+    fn saturate(&mut self, ratio:f32) -> Self {
+        let mut hsla:HSLA = (*self).into();
+        hsla.hsl.saturate(ratio);
+        hsla.into()
+    }
+}
+
+pub trait Desaturate {
+    fn desaturate(&mut self, ratio:f32) -> Self;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Desaturate for T {
+    fn desaturate(&mut self, ratio:f32) -> Self {
+        let mut hsla:HSLA = (*self).into();
+        hsla.hsl.desaturate(ratio);
+        hsla.into()
+    }
+}
+
+/// Returns n colors with the hue and the saturation of `self`, their HSL lightness is evenly spaced from dark to light
+/// with black and white excluded. The alpha is kept.
+/// ```rust
+/// use easy_color::{Hex, Monochromatic};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// let colors:Vec<String> = hex.monochromatic(5).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(colors, ["#0F4631", "#1F8B62", "#2ED193", "#74E0B7", "#B9F0DB"]);
+/// ```
+pub trait Monochromatic {
+    fn monochromatic(&self, n:usize) -> Vec<Self> where Self: Sized;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Monochromatic for T {
+    fn monochromatic(&self, n:usize) -> Vec<Self> {
+        let hsla:HSLA = (*self).into();
+        (1..=n).map(|i| {
+            let mut c = hsla;
+            c.hsl.l = 100.0 * i as f32 / (n + 1) as f32;
+            c.into()
+        }).collect()
+    }
+}
+
+/// Returns the complementary color of any color, the hue is rotated by 180 degrees in HSL and the alpha is kept.
+/// ```rust
+/// use easy_color::{Complement, Hex, RGBA};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(hex.complement().to_string(), "#C42B65");
+/// let rgba:RGBA = (255, 0, 0, 0.5).try_into().unwrap();
+/// assert_eq!(rgba.complement().to_string(), "rgba(0,255,255,0.50)");
+/// ```
+pub trait Complement {
+    fn complement(&self) -> Self;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Complement for T {
+    fn complement(&self) -> Self {
+        let mut hsla:HSLA = (*self).into();
+        hsla.hsl.rotate(180);
+        hsla.into()
+    }
+}
+
+fn rotate_hues<T:Into<HSLA> + From<HSLA> + Copy, const N:usize>(color:T, degrees:[f32;N]) -> [T;N] {
+    let hsla:HSLA = color.into();
+    core::array::from_fn(|i| {
+        let mut c = hsla;
+        c.hsl.h = (c.hsl.h + degrees[i]).rem_euclid(360.0);
+        c.into()
+    })
+}
+
+/// Color harmonies of any color, built by rotating the HSL hue. The fixed-size harmonies start with `self`
+/// and the alpha is kept, so the result can be fed straight into theming code.
+/// ```rust
+/// use easy_color::{Harmony, Hex};
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// let [base, complement] = hex.complementary();
+/// assert_eq!(base, hex);
+/// assert_eq!(complement.to_string(), "#C42B65");
+///
+/// let triad:Vec<String> = hex.triadic().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(triad, ["#2BC48A", "#8A2BC4", "#C48A2B"]);
+///
+/// let split:Vec<String> = hex.split_complementary().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(split, ["#2BC48A", "#C42BB2", "#C43D2B"]);
+///
+/// let square:Vec<String> = hex.square().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(square, ["#2BC48A", "#3D2BC4", "#C42B65", "#B2C42B"]);
+///
+/// let analogous:Vec<String> = hex.analogous(3, 60.0).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(analogous, ["#2BC43D", "#2BC48A", "#2BB2C4"]);
+/// ```
+pub trait Harmony: Sized {
+    /// The color and its complement, 180 degrees away.
+    fn complementary(&self) -> [Self; 2];
+    /// The color and the two colors 120 degrees away on each side.
+    fn triadic(&self) -> [Self; 3];
+    /// The color and the three colors 90, 180 and 270 degrees away.
+    fn tetradic(&self) -> [Self; 4];
+    /// Same as `tetradic`.
+    fn square(&self) -> [Self; 4] {
+        self.tetradic()
+    }
+    /// The color and the two colors flanking its complement, 150 and 210 degrees away.
+    fn split_complementary(&self) -> [Self; 3];
+    /// n neighboring colors whose hues are evenly spread over `spread_degrees` centered on the color,
+    /// e.g. `analogous(3, 60.0)` gives the hues -30, 0 and +30 degrees away. This is the one harmony that does not start with `self`.
+    fn analogous(&self, n:usize, spread_degrees:f32) -> Vec<Self>;
+}
+
+impl<T:Into<HSLA> + From<HSLA> + Copy> Harmony for T {
+    fn complementary(&self) -> [Self; 2] {
+        let [complement] = rotate_hues(*self, [180.0]);
+        [*self, complement]
+    }
+
+    fn triadic(&self) -> [Self; 3] {
+        let [a, b] = rotate_hues(*self, [120.0, 240.0]);
+        [*self, a, b]
+    }
+
+    fn tetradic(&self) -> [Self; 4] {
+        let [a, b, c] = rotate_hues(*self, [90.0, 180.0, 270.0]);
+        [*self, a, b, c]
+    }
+
+    fn split_complementary(&self) -> [Self; 3] {
+        let [a, b] = rotate_hues(*self, [150.0, 210.0]);
+        [*self, a, b]
+    }
+
+    fn analogous(&self, n:usize, spread_degrees:f32) -> Vec<Self> {
+        let hsla:HSLA = (*self).into();
+        (0..n).map(|i| {
+            let offset = if n > 1 { spread_degrees * (i as f32 / (n - 1) as f32 - 0.5) } else { 0.0 };
+            if offset == 0.0 {
+                return *self;
+            }
+            let mut c = hsla;
+            c.hsl.h = (c.hsl.h + offset).rem_euclid(360.0);
+            c.into()
+        }).collect()
+    }
+}
+
+/// The operations shared by every color type, so a function can accept any of them.
+/// ```rust
+/// use easy_color::{ColorSpace, Hex, HSL, RGBA};
+/// fn badge<C: ColorSpace>(c: C) -> String {
+///     let text = if c.is_dark() { "white" } else { "black" };
+///     format!("{} on {}", text, c.to_hex_string())
+/// }
+/// let hsl:HSL = (157, 64, 47).try_into().unwrap();
+/// assert_eq!(badge(hsl), "black on #2BC58A");
+///
+/// // the methods of the super traits are available in generic code as well
+/// fn tint<C: ColorSpace>(c: C) -> C {
+///     let white:RGBA = (255, 255, 255, 1.0).try_into().unwrap();
+///     c.mix(white, Some(0.3))
+/// }
+/// let hex:Hex = "#2BC48A".try_into().unwrap();
+/// assert_eq!(tint(hex).to_string(), "#6BD6AD");
+/// assert_eq!(format!("{:.4}", hex.luminance()), "0.4183");
+/// ```
+pub trait ColorSpace: IntoRGB + IntoRGBA + IntoHex + Color + Luminance + ColorMix<RGBA> + Display + Copy {
+    /// Returns the color as a hex string, like "#2BC48A".
+    fn to_hex_string(&self) -> String;
+    /// Returns the WCAG relative luminance(0~1).
+    fn luminance(&self) -> f32;
+}
+
+impl<T: Into<RGB> + Into<RGBA> + Into<Hex> + From<RGBA> + Display + Copy> ColorSpace for T {
+    fn to_hex_string(&self) -> String {
+        let hex:Hex = (*self).into();
+        hex.to_string()
+    }
+
+    fn luminance(&self) -> f32 {
+        self.relative_luminance()
+    }
+}
+
 pub trait IntoHex {
     fn to_hex(&self) -> Hex;
 }
@@ -141,3 +882,103 @@ impl<T:Into<CMYK> + Copy> IntoCMYK for T {
         (*self).into()
     }
 }
+
+pub trait IntoOklab {
+    fn to_oklab(&self) -> Oklab;
+}
+
+impl<T:Into<Oklab> + Copy> IntoOklab for T {
+    fn to_oklab(&self) -> Oklab {
+        (*self).into()
+    }
+}
+
+pub trait IntoOklch {
+    fn to_oklch(&self) -> Oklch;
+}
+
+impl<T:Into<Oklch> + Copy> IntoOklch for T {
+    fn to_oklch(&self) -> Oklch {
+        (*self).into()
+    }
+}
+
+pub trait IntoLab {
+    fn to_lab(&self) -> Lab;
+}
+
+impl<T:Into<Lab> + Copy> IntoLab for T {
+    fn to_lab(&self) -> Lab {
+        (*self).into()
+    }
+}
+
+pub trait IntoLCh {
+    fn to_lch(&self) -> LCh;
+}
+
+impl<T:Into<LCh> + Copy> IntoLCh for T {
+    fn to_lch(&self) -> LCh {
+        (*self).into()
+    }
+}
+
+pub trait IntoXYZ {
+    fn to_xyz(&self) -> XYZ;
+}
+
+impl<T:Into<XYZ> + Copy> IntoXYZ for T {
+    fn to_xyz(&self) -> XYZ {
+        (*self).into()
+    }
+}
+
+pub trait IntoXyY {
+    fn to_xyy(&self) -> XyY;
+}
+
+impl<T:Into<XyY> + Copy> IntoXyY for T {
+    fn to_xyy(&self) -> XyY {
+        (*self).into()
+    }
+}
+
+pub trait IntoHWB {
+    fn to_hwb(&self) -> HWB;
+}
+
+impl<T:Into<HWB> + Copy> IntoHWB for T {
+    fn to_hwb(&self) -> HWB {
+        (*self).into()
+    }
+}
+
+pub trait IntoHSI {
+    fn to_hsi(&self) -> HSI;
+}
+
+impl<T:Into<HSI> + Copy> IntoHSI for T {
+    fn to_hsi(&self) -> HSI {
+        (*self).into()
+    }
+}
+
+pub trait IntoOkhsl {
+    fn to_okhsl(&self) -> Okhsl;
+}
+
+impl<T:Into<Okhsl> + Copy> IntoOkhsl for T {
+    fn to_okhsl(&self) -> Okhsl {
+        (*self).into()
+    }
+}
+
+pub trait IntoOkhsv {
+    fn to_okhsv(&self) -> Okhsv;
+}
+
+impl<T:Into<Okhsv> + Copy> IntoOkhsv for T {
+    fn to_okhsv(&self) -> Okhsv {
+        (*self).into()
+    }
+}
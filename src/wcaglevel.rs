@@ -0,0 +1,35 @@
+/// The WCAG 2.1 conformance levels of text contrast, used with `Contrast::meets_wcag`.
+/// ### example
+/// ```rust
+/// use easy_color::{TextSize, WcagLevel};
+/// assert_eq!(WcagLevel::AA.min_contrast(TextSize::Normal), 4.5);
+/// assert_eq!(WcagLevel::AA.min_contrast(TextSize::Large), 3.0);
+/// assert_eq!(WcagLevel::AAA.min_contrast(TextSize::Normal), 7.0);
+/// assert_eq!(WcagLevel::AAA.min_contrast(TextSize::Large), 4.5);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum WcagLevel {
+    #[default]
+    AA,
+    AAA,
+}
+
+/// The text size of a WCAG contrast check. Large text is at least 18pt, or 14pt bold.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    Large,
+}
+
+impl WcagLevel {
+    /// The minimum contrast ratio the level requires for text of the given size.
+    pub fn min_contrast(&self, size: TextSize) -> f32 {
+        match (self, size) {
+            (Self::AA, TextSize::Normal) => 4.5,
+            (Self::AA, TextSize::Large) => 3.0,
+            (Self::AAA, TextSize::Normal) => 7.0,
+            (Self::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
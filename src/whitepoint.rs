@@ -0,0 +1,87 @@
+use crate::common::{mul_matrix3, Matrix3};
+use crate::XYZ;
+
+/// The reference white of a color space, as CIE standard illuminants for the 2° observer.
+/// XYZ, Lab and the RGB spaces of this crate are relative to D65, except ProPhoto RGB which is relative to D50.
+/// Use `XYZ::adapt` to move a color between two white points with the Bradford transform.
+/// * A - incandescent light, 2856K
+/// * C - average daylight, obsolete
+/// * D50 - horizon light, the white of ICC profiles and printing
+/// * D55 - mid-morning daylight
+/// * D65 - noon daylight, the white of sRGB and most displays
+/// * D75 - north sky daylight
+/// * E - equal energy
+/// * F2 - cool white fluorescent
+/// * F7 - broadband daylight fluorescent
+/// * F11 - narrow band white fluorescent
+///
+/// ### example
+/// ```rust
+/// use easy_color::WhitePoint;
+/// assert_eq!(WhitePoint::D50.xyz().to_string(), "xyz(0.9642 1.0000 0.8252)");
+/// let m = WhitePoint::D65.adaptation_matrix(WhitePoint::D50);
+/// assert_eq!(format!("{:.4}", m[0][0]), "1.0478");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum WhitePoint {
+    A,
+    C,
+    D50,
+    D55,
+    #[default]
+    D65,
+    D75,
+    E,
+    F2,
+    F7,
+    F11,
+}
+
+const BRADFORD: Matrix3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const BRADFORD_INV: Matrix3 = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+impl WhitePoint {
+    /// The XYZ of the white, with Y normalized to 1.
+    pub fn xyz(&self) -> XYZ {
+        let (x, y, z) = match self {
+            Self::A => (1.09850, 1.0, 0.35585),
+            Self::C => (0.98074, 1.0, 1.18232),
+            Self::D50 => (0.96422, 1.0, 0.82521),
+            Self::D55 => (0.95682, 1.0, 0.92149),
+            Self::D65 => (0.95047, 1.0, 1.08883),
+            Self::D75 => (0.94972, 1.0, 1.22638),
+            Self::E => (1.0, 1.0, 1.0),
+            Self::F2 => (0.99187, 1.0, 0.67395),
+            Self::F7 => (0.95044, 1.0, 1.08755),
+            Self::F11 => (1.00966, 1.0, 0.64370),
+        };
+        XYZ { x, y, z }
+    }
+
+    /// The Bradford chromatic adaptation matrix from this white point to `to`, it maps XYZ relative to one white
+    /// onto XYZ relative to the other, so the same surface looks the same under both lights.
+    pub fn adaptation_matrix(&self, to: WhitePoint) -> Matrix3 {
+        let (src, dst) = (self.xyz(), to.xyz());
+        let (rs, gs, bs) = mul_matrix3(&BRADFORD, (src.x, src.y, src.z));
+        let (rd, gd, bd) = mul_matrix3(&BRADFORD, (dst.x, dst.y, dst.z));
+        let scale = [rd / rs, gd / gs, bd / bs];
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (0..3)
+                    .map(|k| BRADFORD_INV[i][k] * scale[k] * BRADFORD[k][j])
+                    .sum();
+            }
+        }
+        m
+    }
+}
@@ -0,0 +1,157 @@
+use crate::common::{parse_fn_args, parse_number, D65};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// XyY describes a color by its chromaticity coordinates and its luminance, which is how display calibration data is usually given.
+/// XyY can be parsed from a string in the format "xyy(x y Y)" or from a tuple (x,y,Y).
+/// * x:f32 - x chromaticity coordinate(0~1)
+/// * y:f32 - y chromaticity coordinate(0~1)
+/// * luminance:f32 - the Y tristimulus value(0~1)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, XyY};
+/// let rgb:RGB = (255, 255, 255).try_into().unwrap();
+/// let xyy:XyY = rgb.into();
+/// assert_eq!(xyy.to_string(), "xyy(0.3127 0.3290 1.0000)");
+///
+/// let xyy:XyY = "xyy(0.64 0.33 0.2126)".try_into().unwrap();
+/// let rgb:RGB = xyy.into();
+/// assert_eq!(rgb.to_string(), "rgb(255,0,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XyY {
+    pub x: f32,
+    pub y: f32,
+    pub luminance: f32,
+}
+
+impl TryFrom<&str> for XyY {
+    type Error = ColorError;
+    fn try_from(xyy_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(xyy_str, "xyy") {
+            if args.len() == 3 {
+                let x = parse_number(&args[0], 1.0);
+                let y = parse_number(&args[1], 1.0);
+                let luminance = parse_number(&args[2], 1.0);
+                if let (Some(x), Some(y), Some(luminance)) = (x, y, luminance) {
+                    return (x, y, luminance).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "XyY: {} format error!",
+            xyy_str
+        )))
+    }
+}
+
+impl FromStr for XyY {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for XyY {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(0.0..=1.0).contains(&value.1)
+            || !(0.0..).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("XyY: args ({},{},{}) value error, the chromaticity coordinates must between 0~1 and the luminance must not be negative!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                x: value.0,
+                y: value.1,
+                luminance: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for XyY {
+    fn from(hex: Hex) -> Self {
+        let xyz: XYZ = hex.into();
+        xyz.into()
+    }
+}
+
+impl From<RGB> for XyY {
+    fn from(rgb: RGB) -> Self {
+        let xyz: XYZ = rgb.into();
+        xyz.into()
+    }
+}
+
+impl From<RGBA> for XyY {
+    fn from(rgba: RGBA) -> Self {
+        let xyz: XYZ = rgba.into();
+        xyz.into()
+    }
+}
+
+impl From<XYZ> for XyY {
+    fn from(xyz: XYZ) -> Self {
+        let XYZ { x, y, z } = xyz;
+        let sum = x + y + z;
+        if sum == 0.0 {
+            // black has no chromaticity, use the one of the white point instead
+            let (wx, wy, wz) = D65;
+            let w_sum = wx + wy + wz;
+            return Self {
+                x: wx / w_sum,
+                y: wy / w_sum,
+                luminance: 0.0,
+            };
+        }
+        Self {
+            x: x / sum,
+            y: y / sum,
+            luminance: y,
+        }
+    }
+}
+
+impl Display for XyY {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "xyy({:.4} {:.4} {:.4})", self.x, self.y, self.luminance)
+    }
+}
+
+impl XyY {
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn luminance(&self) -> f32 {
+        self.luminance
+    }
+
+    pub fn set_luminance(&mut self, luminance: f32) -> &mut Self {
+        self.luminance = luminance.max(0.0);
+        self
+    }
+
+    /// Estimates the correlated color temperature in kelvin with the approximation of McCamy(1992),
+    /// which is accurate to a few kelvin from 2856K to 6504K and usable up to about 13000K.
+    /// ```rust
+    /// use easy_color::{WhitePoint, XyY};
+    /// let d65:XyY = WhitePoint::D65.xyz().into();
+    /// assert_eq!(d65.cct().round(), 6503.0);
+    /// let a:XyY = WhitePoint::A.xyz().into();
+    /// assert_eq!(a.cct().round(), 2857.0);
+    /// ```
+    pub fn cct(&self) -> f32 {
+        let n = (self.x - 0.3320) / (0.1858 - self.y);
+        449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33
+    }
+}
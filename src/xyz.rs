@@ -0,0 +1,323 @@
+use crate::common::{
+    hunter_lab_to_xyz, ictcp_to_linear_rec2020, jzazbz_to_xyz, lab_to_xyz, linear_rgb, luv_to_xyz,
+    mul_matrix3, parse_fn_args, parse_number, D50, D50_TO_D65, D65, PROPHOTO_TO_XYZ,
+    REC2020_TO_XYZ, SRGB_TO_XYZ, XYZ_TO_SRGB,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+    AdobeRGB, Cam16, ColorError, DisplayP3, Hex, HunterLab, ICtCp, Jzazbz, LCHuv, Lab, LinearRGB,
+    Luv, ProPhotoRGB, Rec2020, WhitePoint, XyY, CMYK, HSL, HSLA, HSV, RGB, RGBA, XYZF64,
+};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// XYZ is the CIE 1931 tristimulus color space, it connects the sRGB based types with the CIE based types like `Lab`.
+/// The values are relative to the D65 white point and Y is normalized to 0~1.
+/// XYZ can be parsed from a string in the format "xyz(x y z)" or from a tuple (x,y,z).
+/// * x:f32 - X tristimulus value(0~about 0.95)
+/// * y:f32 - Y tristimulus value, the luminance(0~1)
+/// * z:f32 - Z tristimulus value(0~about 1.09)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, XYZ};
+/// let rgb:RGB = (255, 255, 255).try_into().unwrap();
+/// let xyz:XYZ = rgb.into();
+/// assert_eq!(xyz.to_string(), "xyz(0.9505 1.0000 1.0888)");
+///
+/// let xyz:XYZ = "xyz(0.2532 0.4183 0.3078)".try_into().unwrap();
+/// let rgb:RGB = xyz.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XYZ {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl TryFrom<&str> for XYZ {
+    type Error = ColorError;
+    fn try_from(xyz_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(xyz_str, "xyz") {
+            if args.len() == 3 {
+                let x = parse_number(&args[0], 1.0);
+                let y = parse_number(&args[1], 1.0);
+                let z = parse_number(&args[2], 1.0);
+                if let (Some(x), Some(y), Some(z)) = (x, y, z) {
+                    return (x, y, z).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "XYZ: {} format error!",
+            xyz_str
+        )))
+    }
+}
+
+impl FromStr for XYZ {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for XYZ {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..).contains(&value.0) || !(0.0..).contains(&value.1) || !(0.0..).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!(
+                "XYZ: args ({},{},{}) value error, all value must not be negative!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                x: value.0,
+                y: value.1,
+                z: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for XYZ {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for XYZ {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        Self::from_linear_rgb(linear_rgb(r, g, b), &SRGB_TO_XYZ)
+    }
+}
+
+impl From<RGBA> for XYZ {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<HSL> for XYZ {
+    fn from(hsl: HSL) -> Self {
+        let rgb: RGB = hsl.into();
+        rgb.into()
+    }
+}
+
+impl From<HSLA> for XYZ {
+    fn from(hsla: HSLA) -> Self {
+        let rgb: RGB = hsla.into();
+        rgb.into()
+    }
+}
+
+impl From<HSV> for XYZ {
+    fn from(hsv: HSV) -> Self {
+        let rgb: RGB = hsv.into();
+        rgb.into()
+    }
+}
+
+impl From<CMYK> for XYZ {
+    fn from(cmyk: CMYK) -> Self {
+        let rgb: RGB = cmyk.into();
+        rgb.into()
+    }
+}
+
+impl From<Lab> for XYZ {
+    fn from(lab: Lab) -> Self {
+        let Lab { l, a, b } = lab;
+        let (x, y, z) = lab_to_xyz(l, a, b);
+        Self { x, y, z }
+    }
+}
+
+impl From<XyY> for XYZ {
+    fn from(xyy: XyY) -> Self {
+        let XyY { x, y, luminance } = xyy;
+        if y == 0.0 {
+            return Self::default();
+        }
+        Self {
+            x: x * luminance / y,
+            y: luminance,
+            z: (1.0 - x - y) * luminance / y,
+        }
+    }
+}
+
+impl From<Luv> for XYZ {
+    fn from(luv: Luv) -> Self {
+        let Luv { l, u, v } = luv;
+        let (x, y, z) = luv_to_xyz(l, u, v);
+        Self { x, y, z }
+    }
+}
+
+impl From<LCHuv> for XYZ {
+    fn from(lchuv: LCHuv) -> Self {
+        let luv: Luv = lchuv.into();
+        luv.into()
+    }
+}
+
+impl From<LinearRGB> for XYZ {
+    fn from(linear: LinearRGB) -> Self {
+        let LinearRGB { r, g, b } = linear;
+        Self::from_linear_rgb((r, g, b), &SRGB_TO_XYZ)
+    }
+}
+
+impl From<Cam16> for XYZ {
+    fn from(cam16: Cam16) -> Self {
+        cam16.to_xyz()
+    }
+}
+
+impl From<DisplayP3> for XYZ {
+    fn from(p3: DisplayP3) -> Self {
+        Self::from_linear_rgb(p3.to_linear(), &DisplayP3::TO_XYZ)
+    }
+}
+
+impl From<AdobeRGB> for XYZ {
+    fn from(adobe: AdobeRGB) -> Self {
+        Self::from_linear_rgb(adobe.to_linear(), &AdobeRGB::TO_XYZ)
+    }
+}
+
+impl From<Rec2020> for XYZ {
+    fn from(rec2020: Rec2020) -> Self {
+        Self::from_linear_rgb(rec2020.to_linear(), &Rec2020::TO_XYZ)
+    }
+}
+
+impl From<ProPhotoRGB> for XYZ {
+    fn from(prophoto: ProPhotoRGB) -> Self {
+        let d50 = mul_matrix3(&PROPHOTO_TO_XYZ, prophoto.to_linear());
+        let (x, y, z) = mul_matrix3(&D50_TO_D65, d50);
+        Self { x, y, z }
+    }
+}
+
+impl From<Jzazbz> for XYZ {
+    fn from(jzazbz: Jzazbz) -> Self {
+        let Jzazbz { jz, az, bz } = jzazbz;
+        let (x, y, z) = jzazbz_to_xyz(jz, az, bz);
+        Self { x, y, z }
+    }
+}
+
+impl From<ICtCp> for XYZ {
+    fn from(ictcp: ICtCp) -> Self {
+        let ICtCp { i, ct, cp } = ictcp;
+        Self::from_linear_rgb(ictcp_to_linear_rec2020(i, ct, cp), &REC2020_TO_XYZ)
+    }
+}
+
+impl From<HunterLab> for XYZ {
+    fn from(lab: HunterLab) -> Self {
+        let HunterLab { l, a, b } = lab;
+        let (x, y, z) = hunter_lab_to_xyz(l, a, b);
+        Self {
+            x: x.max(0.0),
+            y,
+            z: z.max(0.0),
+        }
+    }
+}
+
+impl From<XYZF64> for XYZ {
+    fn from(xyz: XYZF64) -> Self {
+        Self {
+            x: xyz.x as f32,
+            y: xyz.y as f32,
+            z: xyz.z as f32,
+        }
+    }
+}
+
+impl Display for XYZ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "xyz({:.4} {:.4} {:.4})", self.x, self.y, self.z)
+    }
+}
+
+impl XYZ {
+    /// The D65 reference white, used by sRGB and most of the types in this crate.
+    pub const D65: XYZ = XYZ {
+        x: D65.0,
+        y: D65.1,
+        z: D65.2,
+    };
+
+    /// The D50 reference white, used by ICC profiles and ProPhoto RGB.
+    pub const D50: XYZ = XYZ {
+        x: D50.0,
+        y: D50.1,
+        z: D50.2,
+    };
+
+    /// The matrix converting linear sRGB into XYZ.
+    pub const SRGB_TO_XYZ: [[f32; 3]; 3] = SRGB_TO_XYZ;
+
+    /// The matrix converting XYZ into linear sRGB.
+    pub const XYZ_TO_SRGB: [[f32; 3]; 3] = XYZ_TO_SRGB;
+
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+
+    /// Converts linear(not gamma encoded) rgb values into XYZ with the given matrix,
+    /// so that other RGB spaces can be converted by supplying their own matrix.
+    /// ```rust
+    /// use easy_color::XYZ;
+    /// let xyz = XYZ::from_linear_rgb((1.0, 1.0, 1.0), &XYZ::SRGB_TO_XYZ);
+    /// assert_eq!(xyz.to_string(), "xyz(0.9505 1.0000 1.0888)");
+    /// ```
+    pub fn from_linear_rgb(rgb: (f32, f32, f32), matrix: &[[f32; 3]; 3]) -> Self {
+        let (x, y, z) = mul_matrix3(matrix, rgb);
+        Self { x, y, z }
+    }
+
+    /// Converts XYZ into linear(not gamma encoded) rgb values with the given matrix.
+    /// ```rust
+    /// use easy_color::XYZ;
+    /// let (r, g, b) = XYZ::D65.to_linear_rgb(&XYZ::XYZ_TO_SRGB);
+    /// assert!((r - 1.0).abs() < 0.001 && (g - 1.0).abs() < 0.001 && (b - 1.0).abs() < 0.001);
+    /// ```
+    pub fn to_linear_rgb(&self, matrix: &[[f32; 3]; 3]) -> (f32, f32, f32) {
+        mul_matrix3(matrix, (self.x, self.y, self.z))
+    }
+
+    /// Adapts the color from the `from` white point to the `to` white point with the Bradford transform.
+    /// ```rust
+    /// use easy_color::{WhitePoint, XYZ};
+    /// let d50 = XYZ::D65.adapt(WhitePoint::D65, WhitePoint::D50);
+    /// assert_eq!(d50.to_string(), "xyz(0.9642 1.0000 0.8252)");
+    ///
+    /// let xyz:XYZ = (0.4, 0.35, 0.3).try_into().unwrap();
+    /// let a = xyz.adapt(WhitePoint::D65, WhitePoint::A);
+    /// assert_eq!(a.to_string(), "xyz(0.4789 0.3649 0.0974)");
+    /// ```
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint) -> Self {
+        let (x, y, z) = mul_matrix3(&from.adaptation_matrix(to), (self.x, self.y, self.z));
+        Self { x, y, z }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::common::{
+    lab_to_xyz_f64, mul_matrix3_f64, parse_fn_args, parse_number_f64, SRGB_TO_XYZ_F64,
+};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, LabF64, RGB, RGBF64, XYZ};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// XYZF64 is `XYZ` with f64 values, relative to the D65 white point with Y normalized to 0~1.
+/// XYZF64 can be parsed from a string in the format "xyz(x y z)" or from a tuple (x,y,z).
+/// * x:f64 - X tristimulus value(0~about 0.95)
+/// * y:f64 - Y tristimulus value, the luminance(0~1)
+/// * z:f64 - Z tristimulus value(0~about 1.09)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, XYZ, XYZF64};
+/// let xyz:XYZF64 = RGB::new(255, 255, 255).into();
+/// assert_eq!(xyz.to_string(), "xyz(0.950456 1.000000 1.089058)");
+///
+/// let xyz:XYZ = xyz.into();
+/// assert_eq!(xyz.to_string(), "xyz(0.9505 1.0000 1.0891)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XYZF64 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl TryFrom<&str> for XYZF64 {
+    type Error = ColorError;
+    fn try_from(xyz_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(xyz_str, "xyz") {
+            if args.len() == 3 {
+                let x = parse_number_f64(&args[0], 1.0);
+                let y = parse_number_f64(&args[1], 1.0);
+                let z = parse_number_f64(&args[2], 1.0);
+                if let (Some(x), Some(y), Some(z)) = (x, y, z) {
+                    return (x, y, z).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "XYZF64: {} format error!",
+            xyz_str
+        )))
+    }
+}
+
+impl FromStr for XYZF64 {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for XYZF64 {
+    type Error = ColorError;
+    fn try_from(value: (f64, f64, f64)) -> Result<Self, Self::Error> {
+        if !(0.0..).contains(&value.0) || !(0.0..).contains(&value.1) || !(0.0..).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!(
+                "XYZF64: args ({},{},{}) value error, all value must not be negative!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                x: value.0,
+                y: value.1,
+                z: value.2,
+            })
+        }
+    }
+}
+
+impl From<RGB> for XYZF64 {
+    fn from(rgb: RGB) -> Self {
+        let rgb: RGBF64 = rgb.into();
+        rgb.into()
+    }
+}
+
+impl From<RGBF64> for XYZF64 {
+    fn from(rgb: RGBF64) -> Self {
+        let (x, y, z) = mul_matrix3_f64(&SRGB_TO_XYZ_F64, rgb.to_linear());
+        Self { x, y, z }
+    }
+}
+
+impl From<XYZ> for XYZF64 {
+    fn from(xyz: XYZ) -> Self {
+        Self {
+            x: xyz.x as f64,
+            y: xyz.y as f64,
+            z: xyz.z as f64,
+        }
+    }
+}
+
+impl From<LabF64> for XYZF64 {
+    fn from(lab: LabF64) -> Self {
+        let LabF64 { l, a, b } = lab;
+        let (x, y, z) = lab_to_xyz_f64(l, a, b);
+        Self { x, y, z }
+    }
+}
+
+impl Display for XYZF64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "xyz({:.6} {:.6} {:.6})", self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,180 @@
+use crate::common::{parse_fn_args, rgb_to_ycbcr};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA, YUV};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// The standard which defines the luma coefficients of a `YCbCr` color.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum YCbCrStandard {
+    /// ITU-R BT.601, used by SD video and JPEG.
+    #[default]
+    BT601,
+    /// ITU-R BT.709, used by HD video.
+    BT709,
+}
+
+/// YCbCr is the luma/chroma encoding used by video and JPEG, the values use the full 0~255 range.
+/// YCbCr can be parsed from a string in the format "ycbcr(y,cb,cr)" or from a tuple (y,cb,cr), both of them use BT.601.
+/// * y:u8 - luma(0~255)
+/// * cb:u8 - blue-difference chroma(0~255)
+/// * cr:u8 - red-difference chroma(0~255)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{Hex, RGB, YCbCr, YCbCrStandard};
+/// let ycbcr:YCbCr = "ycbcr(76,85,255)".try_into().unwrap();
+/// let hex:Hex = ycbcr.into();
+/// assert_eq!(hex.to_string(), "#FE0000");
+///
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let ycbcr = YCbCr::from_rgb(rgb, YCbCrStandard::BT709);
+/// assert_eq!(ycbcr.to_string(), "ycbcr(159,117,54)");
+/// // the channels are rounded to 8 bits, so a round trip may be off by one
+/// let rgb:RGB = ycbcr.into();
+/// assert_eq!(rgb.to_string(), "rgb(42,196,139)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YCbCr {
+    pub y: u8,
+    pub cb: u8,
+    pub cr: u8,
+    pub standard: YCbCrStandard,
+}
+
+impl TryFrom<&str> for YCbCr {
+    type Error = ColorError;
+    fn try_from(ycbcr_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(ycbcr_str, "ycbcr") {
+            if args.len() == 3 {
+                let val = args
+                    .iter()
+                    .map(|s| s.parse::<u8>())
+                    .filter_map(|v| v.ok())
+                    .collect::<Vec<_>>();
+                if val.len() == 3 {
+                    return (val[0], val[1], val[2]).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "YCbCr: {} format error!",
+            ycbcr_str
+        )))
+    }
+}
+
+impl FromStr for YCbCr {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(u8, u8, u8)> for YCbCr {
+    type Error = ColorError;
+    fn try_from(value: (u8, u8, u8)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            y: value.0,
+            cb: value.1,
+            cr: value.2,
+            standard: YCbCrStandard::BT601,
+        })
+    }
+}
+
+impl From<Hex> for YCbCr {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for YCbCr {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, YCbCrStandard::BT601)
+    }
+}
+
+impl From<RGBA> for YCbCr {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<YUV> for YCbCr {
+    fn from(yuv: YUV) -> Self {
+        let rgb: RGB = yuv.into();
+        rgb.into()
+    }
+}
+
+impl Display for YCbCr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ycbcr({},{},{})", self.y, self.cb, self.cr)
+    }
+}
+
+impl YCbCr {
+    /// Converts a rgb color with the luma coefficients of the given standard.
+    pub fn from_rgb(rgb: RGB, standard: YCbCrStandard) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (y, cb, cr) = rgb_to_ycbcr(r, g, b, standard == YCbCrStandard::BT709);
+        Self {
+            y,
+            cb,
+            cr,
+            standard,
+        }
+    }
+
+    /// Creates a color from samples that were encoded with the given standard.
+    /// ```rust
+    /// use easy_color::{RGB, YCbCr, YCbCrStandard};
+    /// let ycbcr = YCbCr::new(54, 99, 255, YCbCrStandard::BT709);
+    /// let rgb:RGB = ycbcr.into();
+    /// assert_eq!(rgb.to_string(), "rgb(254,0,0)");
+    /// ```
+    pub fn new(y: u8, cb: u8, cr: u8, standard: YCbCrStandard) -> Self {
+        Self {
+            y,
+            cb,
+            cr,
+            standard,
+        }
+    }
+
+    pub fn luma(&self) -> u8 {
+        self.y
+    }
+
+    pub fn set_luma(&mut self, luma: u8) -> &mut Self {
+        self.y = luma;
+        self
+    }
+
+    pub fn cb(&self) -> u8 {
+        self.cb
+    }
+
+    pub fn set_cb(&mut self, cb: u8) -> &mut Self {
+        self.cb = cb;
+        self
+    }
+
+    pub fn cr(&self) -> u8 {
+        self.cr
+    }
+
+    pub fn set_cr(&mut self, cr: u8) -> &mut Self {
+        self.cr = cr;
+        self
+    }
+
+    pub fn standard(&self) -> YCbCrStandard {
+        self.standard
+    }
+}
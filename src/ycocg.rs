@@ -0,0 +1,122 @@
+use crate::common::{parse_fn_args, rgb_to_ycocg, ycocg_to_rgb};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// YCoCg is the luma/chroma transform used by texture compression and screen content coding.
+/// It uses the reversible YCoCg-R lifting scheme, so the conversion from and back to `RGB` is lossless.
+/// YCoCg can be parsed from a string in the format "ycocg(y,co,cg)" or from a tuple (y,co,cg).
+/// * y:u8 - luma(0~255)
+/// * co:i16 - orange chroma(-255~255)
+/// * cg:i16 - green chroma(-255~255)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, YCoCg};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let ycocg:YCoCg = rgb.into();
+/// assert_eq!(ycocg.to_string(), "ycocg(143,-95,106)");
+///
+/// let rgb:RGB = ycocg.into();
+/// assert_eq!(rgb.to_string(), "rgb(43,196,138)");
+///
+/// assert!(YCoCg::try_from((0, 255, 255)).is_err());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct YCoCg {
+    pub y: u8,
+    pub co: i16,
+    pub cg: i16,
+}
+
+impl TryFrom<&str> for YCoCg {
+    type Error = ColorError;
+    fn try_from(ycocg_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(ycocg_str, "ycocg") {
+            if args.len() == 3 {
+                if let (Ok(y), Ok(co), Ok(cg)) = (
+                    args[0].parse::<u8>(),
+                    args[1].parse::<i16>(),
+                    args[2].parse::<i16>(),
+                ) {
+                    return (y, co, cg).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "YCoCg: {} format error!",
+            ycocg_str
+        )))
+    }
+}
+
+impl FromStr for YCoCg {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(u8, i16, i16)> for YCoCg {
+    type Error = ColorError;
+    fn try_from(value: (u8, i16, i16)) -> Result<Self, Self::Error> {
+        let (r, g, b) = ycocg_to_rgb(value.0, value.1, value.2);
+        let range = 0..=255;
+        if !range.contains(&r) || !range.contains(&g) || !range.contains(&b) {
+            Err(ColorError::ValueErr(format!(
+                "YCoCg: args ({},{},{}) value error, the values must come from a valid rgb color!",
+                value.0, value.1, value.2
+            )))
+        } else {
+            Ok(Self {
+                y: value.0,
+                co: value.1,
+                cg: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for YCoCg {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for YCoCg {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (y, co, cg) = rgb_to_ycocg(r, g, b);
+        Self { y, co, cg }
+    }
+}
+
+impl From<RGBA> for YCoCg {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for YCoCg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ycocg({},{},{})", self.y, self.co, self.cg)
+    }
+}
+
+impl YCoCg {
+    pub fn luma(&self) -> u8 {
+        self.y
+    }
+
+    pub fn co(&self) -> i16 {
+        self.co
+    }
+
+    pub fn cg(&self) -> i16 {
+        self.cg
+    }
+}
@@ -0,0 +1,133 @@
+use crate::common::{parse_fn_args, parse_number, rgb_to_yiq};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// YIQ is the color space of the NTSC television system, I is the orange/blue axis and Q is the purple/green axis.
+/// YIQ can be parsed from a string in the format "yiq(y i q)" or from a tuple (y,i,q).
+/// * y:f32 - luma(0~1)
+/// * i:f32 - in-phase chroma(-0.5957~0.5957)
+/// * q:f32 - quadrature chroma(-0.5226~0.5226)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{RGB, YIQ};
+/// let rgb:RGB = (43, 196, 138).try_into().unwrap();
+/// let mut yiq:YIQ = rgb.into();
+/// assert_eq!(yiq.to_string(), "yiq(0.5633 -0.2845 -0.1977)");
+///
+/// // drop the chroma axes to get the black and white picture
+/// yiq.set_i(0.0).set_q(0.0);
+/// let rgb:RGB = yiq.into();
+/// assert_eq!(rgb.to_string(), "rgb(144,144,144)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YIQ {
+    pub y: f32,
+    pub i: f32,
+    pub q: f32,
+}
+
+impl TryFrom<&str> for YIQ {
+    type Error = ColorError;
+    fn try_from(yiq_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(yiq_str, "yiq") {
+            if args.len() == 3 {
+                let y = parse_number(&args[0], 1.0);
+                let i = parse_number(&args[1], 0.5957);
+                let q = parse_number(&args[2], 0.5226);
+                if let (Some(y), Some(i), Some(q)) = (y, i, q) {
+                    return (y, i, q).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "YIQ: {} format error!",
+            yiq_str
+        )))
+    }
+}
+
+impl FromStr for YIQ {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for YIQ {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(-0.5957..=0.5957).contains(&value.1)
+            || !(-0.5226..=0.5226).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("YIQ: args ({},{},{}) value error, y must between 0~1, i must between -0.5957~0.5957 and q must between -0.5226~0.5226!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                y: value.0,
+                i: value.1,
+                q: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for YIQ {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for YIQ {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (y, i, q) = rgb_to_yiq(r, g, b);
+        Self { y, i, q }
+    }
+}
+
+impl From<RGBA> for YIQ {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl Display for YIQ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "yiq({:.4} {:.4} {:.4})", self.y, self.i, self.q)
+    }
+}
+
+impl YIQ {
+    pub fn luma(&self) -> f32 {
+        self.y
+    }
+
+    pub fn set_luma(&mut self, luma: f32) -> &mut Self {
+        self.y = luma.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn i(&self) -> f32 {
+        self.i
+    }
+
+    pub fn set_i(&mut self, i: f32) -> &mut Self {
+        self.i = i.clamp(-0.5957, 0.5957);
+        self
+    }
+
+    pub fn q(&self) -> f32 {
+        self.q
+    }
+
+    pub fn set_q(&mut self, q: f32) -> &mut Self {
+        self.q = q.clamp(-0.5226, 0.5226);
+        self
+    }
+}
@@ -0,0 +1,138 @@
+use crate::common::{parse_fn_args, parse_number, rgb_to_yuv};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorError, Hex, YCbCr, RGB, RGBA};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// YUV is the analog luma/chroma encoding used by PAL broadcast(BT.601 luma coefficients).
+/// YUV can be parsed from a string in the format "yuv(y u v)" or from a tuple (y,u,v).
+/// * y:f32 - luma(0~1)
+/// * u:f32 - blue-difference chroma(-0.436~0.436)
+/// * v:f32 - red-difference chroma(-0.615~0.615)
+///
+/// ### example
+/// ```rust
+/// use easy_color::{IntoRGB, RGB, YUV};
+/// let rgb:RGB = (255, 0, 0).try_into().unwrap();
+/// let yuv:YUV = rgb.into();
+/// assert_eq!(yuv.to_string(), "yuv(0.2990 -0.1471 0.6148)");
+///
+/// let yuv:YUV = "yuv(0.2990 -0.1471 0.6148)".try_into().unwrap();
+/// assert_eq!(yuv.to_rgb().to_string(), "rgb(255,0,0)");
+/// ```
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YUV {
+    pub y: f32,
+    pub u: f32,
+    pub v: f32,
+}
+
+impl TryFrom<&str> for YUV {
+    type Error = ColorError;
+    fn try_from(yuv_str: &str) -> Result<Self, Self::Error> {
+        if let Some(args) = parse_fn_args(yuv_str, "yuv") {
+            if args.len() == 3 {
+                let y = parse_number(&args[0], 1.0);
+                let u = parse_number(&args[1], 0.436);
+                let v = parse_number(&args[2], 0.615);
+                if let (Some(y), Some(u), Some(v)) = (y, u, v) {
+                    return (y, u, v).try_into();
+                }
+            }
+        }
+        Err(ColorError::FormatErr(format!(
+            "YUV: {} format error!",
+            yuv_str
+        )))
+    }
+}
+
+impl FromStr for YUV {
+    type Err = ColorError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<(f32, f32, f32)> for YUV {
+    type Error = ColorError;
+    fn try_from(value: (f32, f32, f32)) -> Result<Self, Self::Error> {
+        if !(0.0..=1.0).contains(&value.0)
+            || !(-0.436..=0.436).contains(&value.1)
+            || !(-0.615..=0.615).contains(&value.2)
+        {
+            Err(ColorError::ValueErr(format!("YUV: args ({},{},{}) value error, y must between 0~1, u must between -0.436~0.436 and v must between -0.615~0.615!", value.0, value.1, value.2)))
+        } else {
+            Ok(Self {
+                y: value.0,
+                u: value.1,
+                v: value.2,
+            })
+        }
+    }
+}
+
+impl From<Hex> for YUV {
+    fn from(hex: Hex) -> Self {
+        let rgb: RGB = hex.into();
+        rgb.into()
+    }
+}
+
+impl From<RGB> for YUV {
+    fn from(rgb: RGB) -> Self {
+        let RGB { r, g, b } = rgb;
+        let (y, u, v) = rgb_to_yuv(r, g, b);
+        Self { y, u, v }
+    }
+}
+
+impl From<RGBA> for YUV {
+    fn from(rgba: RGBA) -> Self {
+        let rgb: RGB = rgba.into();
+        rgb.into()
+    }
+}
+
+impl From<YCbCr> for YUV {
+    fn from(ycbcr: YCbCr) -> Self {
+        let rgb: RGB = ycbcr.into();
+        rgb.into()
+    }
+}
+
+impl Display for YUV {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "yuv({:.4} {:.4} {:.4})", self.y, self.u, self.v)
+    }
+}
+
+impl YUV {
+    pub fn luma(&self) -> f32 {
+        self.y
+    }
+
+    pub fn set_luma(&mut self, luma: f32) -> &mut Self {
+        self.y = luma.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn u(&self) -> f32 {
+        self.u
+    }
+
+    pub fn set_u(&mut self, u: f32) -> &mut Self {
+        self.u = u.clamp(-0.436, 0.436);
+        self
+    }
+
+    pub fn v(&self) -> f32 {
+        self.v
+    }
+
+    pub fn set_v(&mut self, v: f32) -> &mut Self {
+        self.v = v.clamp(-0.615, 0.615);
+        self
+    }
+}